@@ -4,7 +4,6 @@ use sdml_errors::{diagnostics::StandardStreamReporter, Error};
 use sdml_generate::convert::doc::{
     org_mode::DocumentationGenerator, BookConfig, DocumentationWriter,
 };
-use sdml_parse::load::FsModuleLoader;
 use std::{path::PathBuf, process::ExitCode};
 
 // ------------------------------------------------------------------------------------------------
@@ -31,7 +30,7 @@ impl super::Command for Command {
         let mut generator = DocumentationGenerator::default();
 
         let reporter = StandardStreamReporter::default();
-        let mut loader = FsModuleLoader::default().with_reporter(Box::new(reporter));
+        let mut loader = super::new_loader(Box::new(reporter));
         let mut cache = InMemoryModuleCache::default().with_stdlib();
 
         generator.write_book(&mut loader, &mut cache, config)?;
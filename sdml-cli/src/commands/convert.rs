@@ -2,13 +2,20 @@ use std::process::ExitCode;
 
 use clap::{Args, ValueEnum};
 use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
 use sdml_core::{
     load::ModuleLoader,
     store::{InMemoryModuleCache, ModuleStore},
 };
+use sdml_errors::diagnostics::StandardStreamReporter;
 use sdml_errors::Error;
-use sdml_generate::convert::{json, rdf, sexpr};
+use sdml_generate::convert::{
+    avro, graphql, json, json_schema, protobuf, python, rdf, rust, sexpr, sql, typescript,
+};
 use sdml_generate::Generator;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -26,9 +33,78 @@ use sdml_generate::Generator;
 ///   `sdml_core` in JSON. This mapping is non-normative and may change according to any model
 ///   structure change.
 ///
+/// - JSON Schema :: This is a JSON Schema (draft 2020-12) describing the instance data of the
+///   module's definitions, each definition is added to `$defs` and references to definitions in
+///   other modules refer to the schema file `{module}.schema.json`.
+///
+/// - Avro :: This is an array of Apache Avro schemas for the module's events and structures, or
+///   with the `record` option the single schema for one of them. Namespaces are derived from the
+///   module's base URI, and dates, date-times, and decimals use Avro logical types.
+///
+/// ```text
+/// ❯ sdml convert -f avro --record VehicleRented -i rentals.sdm -o vehicle-rented.avsc
+/// ```
+///
+/// - GraphQL :: This is a GraphQL schema (SDL) with object types for entities, structures, and
+///   events, and enum and union types. Simple types are mapped to built-in or custom scalars, and
+///   the `scalar` option replaces the scalar for a type.
+///
+/// ```text
+/// ❯ sdml convert -f graphql --scalar xsd:long=BigInt --scalar rentals:Vin=VIN -i rentals.sdm
+/// ```
+///
+/// - Protocol Buffers :: This is a proto3 schema with messages for entities, structures, and
+///   events, enums with an `UNSPECIFIED` zero value, and unions as messages with a `oneof`. Field
+///   numbers are taken from any `sdml:fieldNumber` annotation, or the `field-numbers` file, which
+///   is updated with the numbers of new fields so that regenerating never renumbers a field.
+///
+/// ```text
+/// ❯ sdml convert -f protobuf --field-numbers rentals.numbers -i rentals.sdm -o rentals.proto
+/// ```
+///
+/// - Python :: This is a Python module with Pydantic (v2) models for entities, structures, and
+///   events, `Enum` classes for enums, discriminated unions for unions, and `Annotated` types with
+///   `Field` constraints for datatypes. The `dataclasses` option generates standard library
+///   dataclasses, and `NewType` datatypes, instead.
+///
+/// ```text
+/// ❯ sdml convert -f python --dataclasses -i rentals.sdm -o rentals.py
+/// ```
+///
+/// - Rust :: This is a Rust module with `serde` types for the module's definitions; structs for
+///   entities, structures, and events, enums for enums and unions, and newtypes for datatypes.
+///   The `rust-type` option replaces the Rust type used for a library type or datatype.
+///
+/// ```text
+/// ❯ sdml convert -f rust --rust-type xsd:dateTime=chrono::NaiveDateTime -i rentals.sdm
+/// ```
+///
+/// - SQL :: This is the PostgreSQL DDL for tables storing the instance data of the module's
+///   entities, with structures embedded as columns, enums as enumerated types, and entity
+///   references as foreign keys.
+///
+/// - TypeScript :: This is a TypeScript module with interfaces for entities, structures, and
+///   events, string literal unions for enums, discriminated unions for unions, and branded types
+///   for datatypes. The `type-guards` option adds a runtime guard for each type that checks
+///   datatype facets and member cardinalities.
+///
+/// ```text
+/// ❯ sdml convert -f typescript --type-guards -i rentals.sdm -o rentals.ts
+/// ```
+///
 /// - S-Expression :: This is a debugging representation, and supported as the underlying
 ///   tree-sitter library uses s-expressions as a parse-tree visualization.
 ///
+/// The options above that apply to a single format, such as `type-guards`, are an error with any
+/// other format.
+///
+/// The `workspace` option converts every module in a workspace, each is written to its own file,
+/// named for the module, in the directory `output-dir`. The `output-dir` option may only be
+/// omitted if the workspace contains a single module.
+///
+/// ```text
+/// ❯ sdml convert -f rdf --workspace models --output-dir rdf
+/// ```
 ///
 #[derive(Args, Debug)]
 pub(crate) struct Command {
@@ -36,21 +112,77 @@ pub(crate) struct Command {
     #[arg(value_enum)]
     output_format: ConvertFormat,
 
+    /// Directory to write one file per module into, used with `--workspace`
+    #[arg(long, requires = "workspace")]
+    output_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    options: ConvertOptions,
+
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
     #[command(flatten)]
     files: super::FileArgs,
 }
 
+/// Options that apply to a single output format
+#[derive(Args, Debug, Default)]
+pub(crate) struct ConvertOptions {
+    /// Name of the single event or structure to convert, used with the `avro` format
+    #[arg(long)]
+    record: Option<String>,
+
+    /// Map a type to a GraphQL scalar, as `TYPE=SCALAR`, used with the `graphql` format
+    #[arg(long, value_parser = parse_type_mapping)]
+    scalar: Vec<(String, String)>,
+
+    /// Map a type to a Rust type, as `TYPE=RUST_TYPE`, used with the `rust` format
+    #[arg(long, value_parser = parse_type_mapping)]
+    rust_type: Vec<(String, String)>,
+
+    /// File of Protocol Buffers field numbers, read and updated by the `protobuf` format
+    #[arg(long)]
+    field_numbers: Option<PathBuf>,
+
+    /// Generate dataclasses rather than Pydantic models, used with the `python` format
+    #[arg(long)]
+    dataclasses: bool,
+
+    /// Generate runtime type guards, used with the `typescript` format
+    #[arg(long)]
+    type_guards: bool,
+}
+
 /// Module representation to convert into
 #[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum ConvertFormat {
+    /// Apache Avro schema
+    Avro,
     /// JSON
     Json,
     /// Pretty-printed JSON
     JsonPretty,
+    /// GraphQL SDL
+    #[value(name = "graphql")]
+    GraphQl,
+    /// JSON Schema (2020-12)
+    JsonSchema,
+    /// Protocol Buffers (proto3)
+    Protobuf,
+    /// Python Pydantic models or dataclasses
+    Python,
     /// RDF Abstract Model
     Rdf,
+    /// Rust types with serde
+    Rust,
     /// S-Expressions
     SExpr,
+    /// PostgreSQL DDL
+    Sql,
+    /// TypeScript types
+    #[value(name = "typescript")]
+    TypeScript,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -59,29 +191,221 @@ pub(crate) enum ConvertFormat {
 
 impl super::Command for Command {
     fn execute(&self) -> Result<ExitCode, Error> {
+        if let Some((option, format)) = self.options.unused_option(self.output_format) {
+            eprintln!(
+                "Error: the option `{option}` is only used with the `{}` format.",
+                format.name()
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+
+        if self.workspace.is_workspace() {
+            let reporter = StandardStreamReporter::default();
+            let workspace = self.workspace.load(super::new_loader(Box::new(reporter)))?;
+            if self.output_dir.is_none() && workspace.len() > 1 {
+                // the output of most formats is not valid when documents are concatenated.
+                eprintln!(
+                    "Error: converting {} modules requires an output directory, use `--output-dir`.",
+                    workspace.len()
+                );
+                return Ok(ExitCode::FAILURE);
+            }
+            for module in workspace.modules() {
+                if let Some(output_dir) = &self.output_dir {
+                    std::fs::create_dir_all(output_dir)?;
+                    let path = output_dir.join(format!(
+                        "{}.{}",
+                        module.name(),
+                        self.output_format.file_extension()
+                    ));
+                    let mut writer = File::create(path)?;
+                    self.output_format.convert(
+                        module,
+                        workspace.store(),
+                        &self.options,
+                        &mut writer,
+                    )?;
+                } else {
+                    let mut output = self.files.output.clone();
+                    let mut writer = output.lock();
+                    self.output_format.convert(
+                        module,
+                        workspace.store(),
+                        &self.options,
+                        &mut writer,
+                    )?;
+                }
+            }
+            eprintln!("Converted {} modules.", workspace.len());
+            return Ok(super::workspace_exit_code(&workspace));
+        }
+
         call_with_module!(self, |module: &Module, cache: &InMemoryModuleCache, _| {
             let mut output = self.files.output.clone();
             let mut writer = output.lock();
 
-            match self.output_format {
-                ConvertFormat::Rdf => {
-                    let mut generator = rdf::RdfModelGenerator::default();
-                    generator.generate(module, cache, None, &mut writer)?;
-                }
-                ConvertFormat::Json | ConvertFormat::JsonPretty => {
-                    let options = json::JsonGeneratorOptions::default()
-                        .pretty_print(self.output_format == ConvertFormat::JsonPretty);
-                    let mut generator = json::JsonGenerator::default();
-                    generator.generate_with_options(module, cache, options, None, &mut writer)?;
+            self.output_format
+                .convert(module, cache, &self.options, &mut writer)?;
+
+            Ok(ExitCode::SUCCESS)
+        });
+    }
+}
+
+impl ConvertOptions {
+    ///
+    /// Return the first option given that is not used by `format`, with the format that does
+    /// use it.
+    ///
+    fn unused_option(&self, format: ConvertFormat) -> Option<(&'static str, ConvertFormat)> {
+        [
+            ("--record", ConvertFormat::Avro, self.record.is_some()),
+            ("--scalar", ConvertFormat::GraphQl, !self.scalar.is_empty()),
+            (
+                "--rust-type",
+                ConvertFormat::Rust,
+                !self.rust_type.is_empty(),
+            ),
+            (
+                "--field-numbers",
+                ConvertFormat::Protobuf,
+                self.field_numbers.is_some(),
+            ),
+            ("--dataclasses", ConvertFormat::Python, self.dataclasses),
+            ("--type-guards", ConvertFormat::TypeScript, self.type_guards),
+        ]
+        .into_iter()
+        .find(|(_, used_by, given)| *given && *used_by != format)
+        .map(|(option, used_by, _)| (option, used_by))
+    }
+}
+
+impl ConvertFormat {
+    pub(crate) fn convert<W>(
+        &self,
+        module: &Module,
+        cache: &InMemoryModuleCache,
+        options: &ConvertOptions,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        match self {
+            Self::Avro => {
+                let options = match &options.record {
+                    Some(record) => avro::AvroGeneratorOptions::default().with_record(record),
+                    None => avro::AvroGeneratorOptions::default(),
                 }
-                ConvertFormat::SExpr => {
-                    let options = sexpr::SExpressionOptions::default();
-                    let mut generator = sexpr::SExpressionGenerator::default();
-                    generator.generate_with_options(module, cache, options, None, &mut writer)?;
+                .pretty_print(true);
+                let mut generator = avro::AvroGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::Rdf => {
+                let mut generator = rdf::RdfModelGenerator::default();
+                generator.generate(module, cache, None, writer)?;
+            }
+            Self::Json | Self::JsonPretty => {
+                let options =
+                    json::JsonGeneratorOptions::default().pretty_print(*self == Self::JsonPretty);
+                let mut generator = json::JsonGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::JsonSchema => {
+                let options = json_schema::JsonSchemaGeneratorOptions::default().pretty_print(true);
+                let mut generator = json_schema::JsonSchemaGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::GraphQl => {
+                let options = options.scalar.iter().fold(
+                    graphql::GraphQlGeneratorOptions::default(),
+                    |options, (type_name, scalar)| options.with_scalar(type_name, scalar),
+                );
+                let mut generator = graphql::GraphQlGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::Python => {
+                let options =
+                    python::PythonGeneratorOptions::default().with_style(if options.dataclasses {
+                        python::PythonStyle::Dataclass
+                    } else {
+                        python::PythonStyle::Pydantic
+                    });
+                let mut generator = python::PythonGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::Rust => {
+                let options = options.rust_type.iter().fold(
+                    rust::RustGeneratorOptions::default(),
+                    |options, (type_name, rust_type)| options.with_type(type_name, rust_type),
+                );
+                let mut generator = rust::RustGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::SExpr => {
+                let options = sexpr::SExpressionOptions::default();
+                let mut generator = sexpr::SExpressionGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+            Self::Protobuf => {
+                let field_numbers: protobuf::FieldNumbers = match &options.field_numbers {
+                    Some(path) if path.exists() => std::fs::read_to_string(path)?.parse()?,
+                    _ => Default::default(),
+                };
+                let generator_options =
+                    protobuf::ProtobufGeneratorOptions::default().with_field_numbers(field_numbers);
+                let mut generator = protobuf::ProtobufGenerator::default();
+                generator.generate_with_options(module, cache, generator_options, None, writer)?;
+                if let Some(path) = &options.field_numbers {
+                    std::fs::write(path, generator.field_numbers().to_string())?;
                 }
             }
+            Self::Sql => {
+                let mut generator = sql::SqlGenerator::default();
+                generator.generate(module, cache, None, writer)?;
+            }
+            Self::TypeScript => {
+                let options = typescript::TypeScriptGeneratorOptions::default()
+                    .type_guards(options.type_guards);
+                let mut generator = typescript::TypeScriptGenerator::default();
+                generator.generate_with_options(module, cache, options, None, writer)?;
+            }
+        }
+        Ok(())
+    }
 
-            Ok(ExitCode::SUCCESS)
-        });
+    fn name(&self) -> String {
+        self.to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default()
+    }
+
+    fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Avro => "avsc",
+            Self::Json | Self::JsonPretty => "json",
+            Self::JsonSchema => json_schema::JSON_SCHEMA_FILE_EXTENSION,
+            Self::GraphQl => "graphql",
+            Self::Protobuf => "proto",
+            Self::Python => "py",
+            Self::Rdf => "ttl",
+            Self::Rust => "rs",
+            Self::SExpr => "lisp",
+            Self::Sql => "sql",
+            Self::TypeScript => "ts",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn parse_type_mapping(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((type_name, scalar)) if !type_name.is_empty() && !scalar.is_empty() => {
+            Ok((type_name.to_string(), scalar.to_string()))
+        }
+        _ => Err(format!("expected TYPE=VALUE, not `{value}`")),
     }
 }
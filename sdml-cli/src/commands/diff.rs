@@ -0,0 +1,147 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::diagnostics::StandardStreamReporter;
+use sdml_errors::Error;
+use sdml_generate::actions::diff::{diff_modules, write_diff, write_diff_json};
+use sdml_generate::convert::sql::migration::write_migration;
+use sdml_generate::convert::sql::SqlGeneratorOptions;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Show the structural differences between two versions of a module.
+///
+/// This command compares two module files by identity rather than by text, reporting added,
+/// removed, and changed definitions, members, cardinalities, types, variants, and annotations.
+/// Each change is classified as breaking or compatible.
+///
+/// ```text
+/// ❯ sdml diff old/rentals.sdm rentals.sdm
+/// - Customer.email: removed member `{1..1} string` (breaking)
+/// ~ Customer.rentals: changed cardinality from `{0..}` to `{1..}` (breaking)
+/// + Vehicle: added definition `structure` (compatible)
+/// 3 changes, 2 breaking.
+/// ```
+///
+/// The `sql` output format writes a PostgreSQL migration script instead, the statements that
+/// change the tables generated by `sdml convert -f sql` for the old module into those for the
+/// new. Any statement that may delete data, or fail against existing rows, is preceded by a
+/// warning comment.
+///
+/// ```text
+/// ❯ sdml diff -f sql old/rentals.sdm rentals.sdm
+/// -- Migration: rentals
+///
+/// -- WARNING: dropping the column customer.email permanently deletes its data.
+/// ALTER TABLE customer DROP COLUMN email;
+/// ```
+///
+/// The `fail-on-breaking` option returns a failure exit code if any change is breaking.
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    #[arg(short = 'f', long)]
+    #[arg(value_enum)]
+    #[arg(default_value_t = DiffFormat::Text)]
+    output_format: DiffFormat,
+
+    /// Return a failure exit code if any change is breaking
+    #[arg(long)]
+    fail_on_breaking: bool,
+
+    /// File name to write to, or '-' to write to stdout
+    #[arg(short, long)]
+    #[clap(value_parser, default_value = "-")]
+    output: clio::Output,
+
+    /// The old version of the module
+    old: PathBuf,
+
+    /// The new version of the module
+    new: PathBuf,
+}
+
+/// The output format of the calculated differences
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum DiffFormat {
+    /// One change per line
+    Text,
+    /// JSON
+    Json,
+    /// Pretty-printed JSON
+    JsonPretty,
+    /// PostgreSQL migration script
+    Sql,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Load the single module in `file`, without its imports, reporting any diagnostics. Returns
+/// `None` if the module could not be loaded.
+///
+pub(crate) fn load_module_file(file: &Path) -> Result<Option<Module>, Error> {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = super::new_loader(Box::new(StandardStreamReporter::default()));
+    match loader.load_from_file(file.to_path_buf(), &mut cache, false) {
+        Ok(name) => Ok(cache.get(&name).cloned()),
+        Err(Error::LanguageValidationError { source: _ }) => {
+            loader.reporter_done(None)?;
+            Ok(None)
+        }
+        Err(err @ Error::IoError { source: _ }) => {
+            println!(
+                "Error: the input file `{}` could not be found, or read.",
+                file.display()
+            );
+            Err(err)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        let (Some(old), Some(new)) = (load_module_file(&self.old)?, load_module_file(&self.new)?)
+        else {
+            return Ok(ExitCode::FAILURE);
+        };
+
+        let diff = diff_modules(&old, &new);
+        let mut output = self.output.clone();
+        let mut writer = output.lock();
+        match self.output_format {
+            DiffFormat::Text => write_diff(&diff, &mut writer)?,
+            DiffFormat::Json | DiffFormat::JsonPretty => write_diff_json(
+                &diff,
+                self.output_format == DiffFormat::JsonPretty,
+                &mut writer,
+            )?,
+            DiffFormat::Sql => write_migration(
+                &old,
+                &new,
+                &InMemoryModuleCache::default(),
+                SqlGeneratorOptions::default(),
+                &mut writer,
+            )?,
+        }
+
+        if self.fail_on_breaking && diff.is_breaking() {
+            Ok(ExitCode::FAILURE)
+        } else {
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
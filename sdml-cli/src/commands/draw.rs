@@ -60,8 +60,9 @@ pub(crate) enum DrawDiagram {
     UmlClass,
 }
 
-#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub(crate) enum OutputFormat {
+    #[default]
     Source,
     Jpeg,
     Png,
@@ -127,12 +128,6 @@ impl super::Command for Command {
 
 // ------------------------------------------------------------------------------------------------
 
-impl Default for OutputFormat {
-    fn default() -> Self {
-        Self::Source
-    }
-}
-
 impl From<OutputFormat> for sdml_generate::draw::OutputFormat {
     fn from(v: OutputFormat) -> Self {
         match v {
@@ -0,0 +1,79 @@
+use std::process::ExitCode;
+
+use clap::Args;
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::Error;
+use sdml_generate::actions::flatten::flatten_module;
+use sdml_generate::convert::source::SourceGenerator;
+use sdml_generate::Generator;
+
+use super::convert::ConvertFormat;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Flatten a module, and the definitions it uses from its imports, into one module.
+///
+/// This command creates a single self-contained module with the same name as the input module,
+/// containing its definitions and every user definition, transitively, referenced by them from
+/// other modules. Standard library modules are not inlined and remain imported. An inlined
+/// definition whose name is already used is renamed with its module's name as a prefix, and all
+/// references to it are rewritten.
+///
+/// ```text
+/// ❯ sdml flatten rentals
+/// module rentals <https://example.com/rentals#> is
+///
+///   import xsd
+///
+///   structure Vehicle is
+///     vin -> VehicleIdentifier
+///   end
+///
+///   datatype VehicleIdentifier <- xsd:string
+///
+/// end
+/// ```
+///
+/// By default the flattened module is written as SDML source; the `output-format` option writes
+/// it in any of the `convert` command's formats instead.
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// Write the flattened module in this format rather than as SDML source
+    #[arg(short = 'f', long)]
+    #[arg(value_enum)]
+    output_format: Option<ConvertFormat>,
+
+    #[command(flatten)]
+    files: super::FileArgs,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        call_with_module!(self, |module: &Module, cache: &InMemoryModuleCache, _| {
+            let flattened = flatten_module(module, cache)?;
+            let mut output = self.files.output.clone();
+            let mut writer = output.lock();
+
+            match self.output_format {
+                Some(format) => {
+                    format.convert(&flattened, cache, &Default::default(), &mut writer)?
+                }
+                None => {
+                    let mut generator = SourceGenerator::default();
+                    generator.generate(&flattened, cache, None, &mut writer)?;
+                }
+            }
+
+            Ok(ExitCode::SUCCESS)
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Args;
+use sdml_errors::diagnostics::StandardStreamReporter;
+use sdml_errors::{Error, Reporter, Source, SourceFiles};
+use sdml_generate::actions::format::{format_source, FormatOptions};
+use sdml_parse::load::{SDML_FILE_EXTENSION, SDML_FILE_EXTENSION_LONG};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Format module source files.
+///
+/// This command formats module source, keeping comments and blank lines, but applying a
+/// canonical indentation, sorting import statements, and aligning the values of consecutive
+/// annotations. Each file is rewritten in place; a directory is searched for module files. With
+/// no files the source is read from stdin and the formatted source written to stdout.
+///
+/// ```text
+/// ❯ sdml fmt models
+/// Formatted 2 of 5 files.
+/// ```
+///
+/// The `check` option does not change any file, but lists those that are not formatted and
+/// exits with a failure status if there are any.
+///
+/// ```text
+/// ❯ sdml fmt --check models
+/// models/rentals.sdm
+/// 1 of 5 files are not formatted.
+/// ```
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// List the files that are not formatted, without changing them
+    #[arg(long)]
+    check: bool,
+
+    /// The number of spaces used for each level of indentation
+    #[arg(long, default_value_t = 2)]
+    indentation: usize,
+
+    /// Keep import statements in the order they are written
+    #[arg(long)]
+    no_sort_imports: bool,
+
+    /// Do not align the values of consecutive annotations
+    #[arg(long)]
+    no_align_annotations: bool,
+
+    /// Module files, or directories containing module files, to format
+    files: Vec<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        let options = FormatOptions::default()
+            .with_indentation(self.indentation)
+            .with_sort_imports(!self.no_sort_imports)
+            .with_align_annotations(!self.no_align_annotations);
+        let reporter = StandardStreamReporter::default();
+
+        if self.files.is_empty() {
+            let mut source = String::new();
+            std::io::stdin().lock().read_to_string(&mut source)?;
+            let Some(formatted) = format(&reporter, "<stdin>", &source, &options)? else {
+                return Ok(ExitCode::FAILURE);
+            };
+            if self.check {
+                if formatted != source {
+                    println!("<stdin>");
+                    return Ok(ExitCode::FAILURE);
+                }
+            } else {
+                std::io::stdout().lock().write_all(formatted.as_bytes())?;
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let mut files = Vec::default();
+        for path in &self.files {
+            if path.is_dir() {
+                find_source_files(path, &mut files)?;
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        let mut changed = 0;
+        let mut failed = 0;
+        for file in &files {
+            let source = fs::read_to_string(file)?;
+            match format(&reporter, &file.display().to_string(), &source, &options)? {
+                None => failed += 1,
+                Some(formatted) if formatted != source => {
+                    changed += 1;
+                    if self.check {
+                        println!("{}", file.display());
+                    } else {
+                        fs::write(file, formatted)?;
+                    }
+                }
+                Some(_) => (),
+            }
+        }
+
+        if self.check {
+            eprintln!("{changed} of {} files are not formatted.", files.len());
+        } else {
+            eprintln!("Formatted {changed} of {} files.", files.len());
+        }
+        if failed > 0 {
+            eprintln!("Error: {failed} files could not be parsed.");
+        }
+
+        if failed > 0 || (self.check && changed > 0) {
+            Ok(ExitCode::FAILURE)
+        } else {
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format `source`, reporting any syntax error with the source context and returning `None`.
+///
+fn format(
+    reporter: &StandardStreamReporter,
+    name: &str,
+    source: &str,
+    options: &FormatOptions,
+) -> Result<Option<String>, Error> {
+    let mut sources = SourceFiles::new();
+    let file_id = sources.add(name.to_string(), Source::from(source.to_string()));
+    match format_source(file_id, source, options) {
+        Ok(formatted) => Ok(Some(formatted)),
+        Err(Error::LanguageValidationError { source }) => {
+            reporter.emit(&source, &sources)?;
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn find_source_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    let mut entries = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            find_source_files(&path, files)?;
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext == SDML_FILE_EXTENSION || ext == SDML_FILE_EXTENSION_LONG)
+            .unwrap_or_default()
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use clap::{Args, ValueEnum};
-use sdml_core::load::{ModuleLoader, ModuleResolver};
+use sdml_core::load::ModuleLoader;
 use sdml_core::stdlib;
 use sdml_errors::Error;
 use sdml_parse::load::FsModuleLoader;
@@ -30,9 +30,10 @@ pub(crate) struct Command {
 }
 
 /// Format to convert into
-#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub(crate) enum OutputFormat {
     /// ANSI escape for console
+    #[default]
     Ansi,
     /// HTML pre-formatted element
     Html,
@@ -47,17 +48,15 @@ pub(crate) enum OutputFormat {
 impl super::Command for Command {
     fn execute(&self) -> Result<ExitCode, Error> {
         let loader = FsModuleLoader::default();
-        let resolver = loader.resolver();
 
         let source = if let Some(module_name) = &self.files.module {
+            // no module store is used to highlight source, so only the standard library
+            // modules, which have no source file, can be recognized here.
             if stdlib::is_library_module(module_name) {
                 println!("Sorry, can't currently highlight stdlib modules");
                 return Ok(ExitCode::FAILURE);
             } else {
-                let resource =
-                    resolver.name_to_resource(module_name, loader.get_file_id(module_name))?;
-                let file_path = resource.to_file_path().unwrap();
-                std::fs::read_to_string(file_path)?
+                loader.read_module_source(module_name, loader.get_file_id(module_name))?
             }
         } else {
             let mut input = self.files.input.clone();
@@ -98,9 +97,3 @@ impl super::Command for Command {
 }
 
 // ------------------------------------------------------------------------------------------------
-
-impl Default for OutputFormat {
-    fn default() -> Self {
-        Self::Ansi
-    }
-}
@@ -1,8 +1,14 @@
 use clap::{Args, Subcommand};
 use sdml_core::model::identifiers::Identifier;
-use sdml_errors::Error;
+use sdml_errors::diagnostics::reporter::ReportCounters;
+use sdml_errors::{Error, Reporter};
+use sdml_parse::cache::ParsedModuleCache;
+use sdml_parse::load::FsModuleLoader;
+use sdml_parse::Workspace;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{process::ExitCode, str::FromStr};
-use tracing::trace;
+use tracing::{trace, warn};
 
 // ------------------------------------------------------------------------------------------------
 // Public-ish Macros
@@ -16,7 +22,7 @@ macro_rules! call_with_module {
     ($cmd: expr, $reporter:expr, $callback_fn: expr) => {
         let (module_name, cache, mut loader) = {
             let mut cache = ::sdml_core::store::InMemoryModuleCache::default().with_stdlib();
-            let mut loader = ::sdml_parse::load::FsModuleLoader::default().with_reporter($reporter);
+            let mut loader = $crate::commands::new_loader($reporter);
             let module_name = if let Some(module_name) = &$cmd.files.module {
                 loader.load(
                     module_name,
@@ -70,13 +76,21 @@ pub(crate) trait Command {
 pub(crate) enum Commands {
     Convert(convert::Command),
     Deps(deps::Command),
+    Diff(diff::Command),
     Doc(doc::Command),
     DocBook(book::Command),
     Draw(draw::Command),
+    Flatten(flatten::Command),
+    Fmt(fmt::Command),
     Generate(generate::Command),
     Highlight(highlight::Command),
+    Move(relocate::Command),
+    Package(package::Command),
+    Refs(refs::Command),
+    Rename(rename::Command),
     Tags(tags::Command),
     Validate(validate::Command),
+    VersionCheck(version_check::Command),
     Versions(versions::Command),
     View(view::Command),
 }
@@ -101,10 +115,90 @@ pub(crate) struct FileArgs {
     input: clio::Input,
 }
 
+#[derive(Args, Debug)]
+pub(crate) struct WorkspaceArgs {
+    /// Run over every module in a workspace; each value is either a root directory or a catalog
+    /// file, the default is the current directory
+    #[arg(
+        short,
+        long,
+        value_name = "ROOT",
+        num_args = 0..,
+        default_missing_value = ".",
+        conflicts_with_all = ["resolver", "module", "input"],
+    )]
+    workspace: Option<Vec<PathBuf>>,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Enable, or disable, the use of the on-disk cache of parsed modules by loaders created with
+/// [`new_loader`].
+///
+pub(crate) fn set_use_parsed_cache(use_cache: bool) {
+    USE_PARSED_CACHE.store(use_cache, Ordering::Relaxed);
+}
+
+///
+/// Create a new loader with `reporter`, using the parsed module cache unless it has been disabled.
+///
+pub(crate) fn new_loader(reporter: Box<dyn Reporter>) -> FsModuleLoader {
+    let loader = FsModuleLoader::default().with_reporter(reporter);
+    if USE_PARSED_CACHE.load(Ordering::Relaxed) {
+        match ParsedModuleCache::user_default() {
+            Some(parsed_cache) => loader.with_parsed_cache(parsed_cache),
+            None => {
+                warn!("Could not determine a cache directory, parsed modules will not be cached");
+                loader
+            }
+        }
+    } else {
+        loader
+    }
+}
+
+///
+/// Return a failure exit code if any file in `workspace` could not be loaded.
+///
+pub(crate) fn workspace_exit_code(workspace: &Workspace) -> ExitCode {
+    for file in workspace.failed_files() {
+        eprintln!("Error: the file `{}` could not be loaded.", file.display());
+    }
+    if workspace.failed_files().next().is_none() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+///
+/// Print a combined summary of the diagnostics reported for all modules in `workspace`.
+///
+pub(crate) fn workspace_summary(workspace: &Workspace, counters: ReportCounters) -> ExitCode {
+    let failed = workspace.failed_files().count();
+    for file in workspace.failed_files() {
+        eprintln!("Error: the file `{}` could not be loaded.", file.display());
+    }
+    if counters.total() == 0 && failed == 0 {
+        println!("No issues found in {} modules.", workspace.len());
+        ExitCode::SUCCESS
+    } else {
+        println!(
+            "Checked {} modules: {} bugs, {} errors, {} warnings, {} notes; {} files could not be loaded.",
+            workspace.len(),
+            counters.bugs(),
+            counters.errors(),
+            counters.warnings(),
+            counters.info(),
+            failed,
+        );
+        ExitCode::FAILURE
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Macros
 // ------------------------------------------------------------------------------------------------
@@ -113,24 +207,58 @@ pub(crate) struct FileArgs {
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+static USE_PARSED_CACHE: AtomicBool = AtomicBool::new(true);
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl WorkspaceArgs {
+    pub(crate) fn is_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    ///
+    /// Create and load a workspace from the roots and catalog files provided, using `loader`.
+    ///
+    pub(crate) fn load(&self, loader: FsModuleLoader) -> Result<Workspace, Error> {
+        let mut workspace = Workspace::new(loader);
+        for root in self.workspace.iter().flatten() {
+            if root.is_file() {
+                workspace.add_catalog_file(root)?;
+            } else {
+                workspace.add_root(root);
+            }
+        }
+        workspace.load()?;
+        Ok(workspace)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
 impl Command for Commands {
     fn execute(&self) -> Result<ExitCode, Error> {
         trace!("Commands::execute self: {self:?}");
         match self {
             Commands::Highlight(cmd) => cmd.execute(),
+            Commands::Move(cmd) => cmd.execute(),
+            Commands::Package(cmd) => cmd.execute(),
+            Commands::Refs(cmd) => cmd.execute(),
+            Commands::Rename(cmd) => cmd.execute(),
             Commands::Generate(cmd) => cmd.execute(),
             Commands::Doc(cmd) => cmd.execute(),
             Commands::DocBook(cmd) => cmd.execute(),
             Commands::Deps(cmd) => cmd.execute(),
+            Commands::Diff(cmd) => cmd.execute(),
             Commands::Tags(cmd) => cmd.execute(),
             Commands::Convert(cmd) => cmd.execute(),
             Commands::Draw(cmd) => cmd.execute(),
+            Commands::Flatten(cmd) => cmd.execute(),
+            Commands::Fmt(cmd) => cmd.execute(),
             Commands::View(cmd) => cmd.execute(),
             Commands::Validate(cmd) => cmd.execute(),
+            Commands::VersionCheck(cmd) => cmd.execute(),
             Commands::Versions(cmd) => cmd.execute(),
         }
     }
@@ -143,11 +271,19 @@ impl Command for Commands {
 mod book;
 mod convert;
 mod deps;
+mod diff;
 mod doc;
 mod draw;
+mod flatten;
+mod fmt;
 mod generate;
 mod highlight;
+mod package;
+mod refs;
+mod relocate;
+mod rename;
 mod tags;
 mod validate;
+mod version_check;
 mod versions;
 mod view;
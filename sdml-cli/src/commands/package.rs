@@ -0,0 +1,110 @@
+use clap::{Args, ValueEnum};
+use sdml_core::model::{modules::Module, HasName};
+use sdml_core::{
+    load::ModuleLoader,
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_errors::Error;
+use sdml_parse::load::FsModuleLoader;
+use sdml_parse::package::{PackageBuilder, PackageFormat};
+use std::process::ExitCode;
+use url::Url;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Build a package archive from a module and its imports.
+///
+/// This command creates a package, an archive that contains the source of a module, the source of
+/// all of its transitively imported modules (excluding the standard library), and a catalog file
+/// `sdml-catalog.json` that maps each module name to its file and base URI.
+///
+/// The archive format is determined by the extension of the output file (`.zip`, `.tar`,
+/// `.tar.gz`, or `.tgz`), or it may be set explicitly which is required when writing to stdout or
+/// to a file with any other extension.
+///
+/// ```text
+/// ❯ sdml package -o rentals.zip rentals
+/// ```
+///
+/// Packages can then be used by adding them to the `SDML_PATH` search path, modules are loaded
+/// directly from the archive.
+///
+/// ```text
+/// ❯ SDML_PATH=./rentals.zip sdml validate rentals
+/// ```
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// Archive format of the package
+    #[arg(short = 'f', long)]
+    #[arg(value_enum)]
+    package_format: Option<ArchiveFormat>,
+
+    #[command(flatten)]
+    files: super::FileArgs,
+}
+
+/// The archive format of the package
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum ArchiveFormat {
+    /// Zip archive
+    Zip,
+    /// Uncompressed tar archive
+    Tar,
+    /// Gzip compressed tar archive
+    TarGz,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        let output = &self.files.output;
+        let format = match self.package_format {
+            Some(format) => Some(format.into()),
+            None if output.is_local() => PackageFormat::from_path(output.path()),
+            None => None,
+        };
+        let Some(format) = format else {
+            eprintln!(
+                "Error: the package format cannot be determined from the output {}, use `--package-format` to set it.",
+                output.path()
+            );
+            return Ok(ExitCode::FAILURE);
+        };
+
+        call_with_module!(
+            self,
+            |module: &Module, cache: &InMemoryModuleCache, loader: &mut FsModuleLoader| {
+                let base = match module.base_uri() {
+                    Some(base) => base.value().clone(),
+                    None => Url::from_directory_path(std::env::current_dir()?)
+                        .map_err(|_| Error::UrlParseError { source: None })?,
+                };
+
+                let mut builder = PackageBuilder::new(base);
+                builder.add_module_closure(module.name(), cache, loader)?;
+
+                let mut output = self.files.output.clone();
+                let mut writer = output.lock();
+                builder.write(&mut writer, format)?;
+
+                Ok(ExitCode::SUCCESS)
+            }
+        );
+    }
+}
+
+impl From<ArchiveFormat> for PackageFormat {
+    fn from(value: ArchiveFormat) -> Self {
+        match value {
+            ArchiveFormat::Zip => PackageFormat::Zip,
+            ArchiveFormat::Tar => PackageFormat::Tar,
+            ArchiveFormat::TarGz => PackageFormat::TarGz,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::Write;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::Args;
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::modules::Module;
+use sdml_core::{
+    load::ModuleLoader,
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_errors::diagnostics::StandardStreamReporter;
+use sdml_errors::Error;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Find all references to a definition.
+///
+/// This command loads a module, and its imports, or a workspace and lists every place that
+/// references the named definition. Each reference is listed with its source location, the
+/// kind of element making the reference, and the name of that element.
+///
+/// ```text
+/// ❯ sdml refs rentals:Vehicle rentals
+/// examples/rentals.sdm:14:5: member rentals:Booking.vehicle
+/// examples/rentals.sdm:21:3: variant rentals:Rentable.Vehicle
+/// ```
+///
+/// The `workspace` option searches all the modules of a workspace.
+///
+/// ```text
+/// ❯ sdml refs rentals:Vehicle --workspace models
+/// ```
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// The qualified name of the definition to find references to
+    #[arg(value_parser = QualifiedIdentifier::from_str)]
+    name: QualifiedIdentifier,
+
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
+    #[command(flatten)]
+    files: super::FileArgs,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        if self.workspace.is_workspace() {
+            let reporter = StandardStreamReporter::default();
+            let workspace = self.workspace.load(super::new_loader(Box::new(reporter)))?;
+            let mut output = self.files.output.clone();
+            let mut writer = output.lock();
+            self.write_references(workspace.store(), &mut writer)?;
+            return Ok(super::workspace_exit_code(&workspace));
+        }
+
+        call_with_module!(self, |_: &Module, cache: &InMemoryModuleCache, _| {
+            let mut output = self.files.output.clone();
+            let mut writer = output.lock();
+            self.write_references(cache, &mut writer)?;
+            Ok(ExitCode::SUCCESS)
+        });
+    }
+}
+
+impl Command {
+    fn write_references<W>(&self, cache: &InMemoryModuleCache, writer: &mut W) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        if cache.resolve(&self.name).is_none() {
+            eprintln!("Warning: no definition named `{}` was loaded.", self.name);
+        }
+
+        let mut count = 0;
+        for site in cache.references_to(&self.name) {
+            let file = cache
+                .get(site.module())
+                .and_then(|module| module.source_file());
+            match (file, site.source_span()) {
+                (Some(file), Some(span)) => writeln!(
+                    writer,
+                    "{}:{}:{}: {} {site}",
+                    file.display(),
+                    span.start().line(),
+                    span.start().column(),
+                    site.kind(),
+                )?,
+                _ => writeln!(writer, "{} {site}", site.kind())?,
+            }
+            count += 1;
+        }
+
+        if count == 0 {
+            eprintln!("No references to `{}` found.", self.name);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::Args;
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::modules::Module;
+use sdml_core::refactor::relocate::move_definitions;
+use sdml_core::{
+    load::ModuleLoader,
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_errors::diagnostics::StandardStreamReporter;
+use sdml_errors::Error;
+use sdml_parse::load::FsModuleLoader;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Move definitions from one module to another.
+///
+/// This command loads a module, and its imports, or a workspace and moves the named definitions
+/// from the module `from` to the end of the module named by `to`. Member imports and qualified
+/// references to the moved definitions are rewritten in every loaded module, and import
+/// statements are added where a module now needs them.
+///
+/// ```text
+/// ❯ sdml move rentals --to fleet -d Vehicle -d VehicleClass --workspace models
+/// models/rentals.sdm: 3 edits
+/// models/fleet.sdm: 2 edits
+/// ```
+///
+/// If the target module does not exist the `new-file` option names the file to create it in.
+/// The `dry-run` option lists the files that would be changed without changing them.
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// The module containing the definitions to move
+    #[arg(value_parser = Identifier::from_str)]
+    from: Identifier,
+
+    /// The module to move the definitions into
+    #[arg(short, long, value_parser = Identifier::from_str)]
+    to: Identifier,
+
+    /// The name of a definition to move, may be repeated
+    #[arg(short, long = "definition", required = true, value_parser = Identifier::from_str)]
+    definitions: Vec<Identifier>,
+
+    /// The file to create the target module in, if it does not already exist
+    #[arg(long)]
+    new_file: Option<PathBuf>,
+
+    /// List the edits that would be made without changing any file
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
+    #[command(flatten)]
+    files: super::FileArgs,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        if self.workspace.is_workspace() {
+            let reporter = StandardStreamReporter::default();
+            let workspace = self.workspace.load(super::new_loader(Box::new(reporter)))?;
+            if workspace.failed_files().next().is_some() {
+                return Ok(super::workspace_exit_code(&workspace));
+            }
+            return self.relocate(workspace.store(), workspace.loader());
+        }
+
+        call_with_module!(
+            self,
+            |_: &Module, cache: &InMemoryModuleCache, loader: &mut FsModuleLoader| {
+                self.relocate(cache, loader)
+            }
+        );
+    }
+}
+
+impl Command {
+    fn relocate(
+        &self,
+        cache: &InMemoryModuleCache,
+        loader: &impl ModuleLoader,
+    ) -> Result<ExitCode, Error> {
+        let edits = match move_definitions(
+            cache,
+            &self.from,
+            &self.definitions,
+            &self.to,
+            self.new_file.as_deref(),
+        ) {
+            Ok(edits) => edits,
+            Err(Error::LanguageValidationError { source }) => {
+                loader.report(&source)?;
+                loader.reporter_done(None)?;
+                return Ok(ExitCode::FAILURE);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut output = self.files.output.clone();
+        let mut writer = output.lock();
+        for (file, file_edits) in edits.files() {
+            writeln!(writer, "{}: {} edits", file.display(), file_edits.len())?;
+        }
+        for (file, _) in edits.new_files() {
+            writeln!(writer, "{}: new file", file.display())?;
+        }
+
+        if edits.is_empty() {
+            eprintln!("Nothing to move.");
+        } else if !self.dry_run {
+            let count = edits.apply()?;
+            eprintln!(
+                "Moved {} definitions from `{}` to `{}`, changing {count} files.",
+                self.definitions.len(),
+                self.from,
+                self.to
+            );
+        }
+        Ok(ExitCode::SUCCESS)
+    }
+}
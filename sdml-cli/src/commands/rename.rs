@@ -0,0 +1,115 @@
+use std::io::Write;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::Args;
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::model::modules::Module;
+use sdml_core::refactor::rename::rename_definition;
+use sdml_core::{
+    load::ModuleLoader,
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_errors::diagnostics::StandardStreamReporter;
+use sdml_errors::Error;
+use sdml_parse::load::FsModuleLoader;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Rename a definition, and all references to it.
+///
+/// This command loads a module, and its imports, or a workspace and renames the named definition
+/// and every reference to it, including member imports and qualified references, in every
+/// loaded module. The new name is checked for collisions with existing definitions, and
+/// imports, before any file is changed.
+///
+/// ```text
+/// ❯ sdml rename rentals:Vehicle Car --workspace models
+/// models/rentals.sdm: 4 edits
+/// models/fleet.sdm: 2 edits
+/// ```
+///
+/// The `dry-run` option lists the files that would be changed without changing them.
+///
+#[derive(Args, Debug)]
+pub(crate) struct Command {
+    /// The qualified name of the definition to rename
+    #[arg(value_parser = QualifiedIdentifier::from_str)]
+    name: QualifiedIdentifier,
+
+    /// The new name for the definition
+    #[arg(value_parser = Identifier::from_str)]
+    new_name: Identifier,
+
+    /// List the edits that would be made without changing any file
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
+    #[command(flatten)]
+    files: super::FileArgs,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        if self.workspace.is_workspace() {
+            let reporter = StandardStreamReporter::default();
+            let workspace = self.workspace.load(super::new_loader(Box::new(reporter)))?;
+            if workspace.failed_files().next().is_some() {
+                return Ok(super::workspace_exit_code(&workspace));
+            }
+            return self.rename(workspace.store(), workspace.loader());
+        }
+
+        call_with_module!(
+            self,
+            |_: &Module, cache: &InMemoryModuleCache, loader: &mut FsModuleLoader| {
+                self.rename(cache, loader)
+            }
+        );
+    }
+}
+
+impl Command {
+    fn rename(
+        &self,
+        cache: &InMemoryModuleCache,
+        loader: &impl ModuleLoader,
+    ) -> Result<ExitCode, Error> {
+        let edits = match rename_definition(cache, &self.name, &self.new_name) {
+            Ok(edits) => edits,
+            Err(Error::LanguageValidationError { source }) => {
+                // report collisions with the source context of the affected module.
+                loader.report(&source)?;
+                loader.reporter_done(None)?;
+                return Ok(ExitCode::FAILURE);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut output = self.files.output.clone();
+        let mut writer = output.lock();
+        for (file, file_edits) in edits.files() {
+            writeln!(writer, "{}: {} edits", file.display(), file_edits.len())?;
+        }
+
+        if edits.is_empty() {
+            eprintln!("Nothing to rename.");
+        } else if !self.dry_run {
+            let count = edits.apply()?;
+            eprintln!(
+                "Renamed `{}` to `{}` in {count} files.",
+                self.name, self.new_name
+            );
+        }
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -3,8 +3,9 @@ use std::process::ExitCode;
 use clap::{Args, ValueEnum};
 use sdml_core::model::modules::Module;
 use sdml_core::{load::ModuleLoader, store::ModuleStore};
+use sdml_errors::diagnostics::StandardStreamReporter;
 use sdml_errors::Error;
-use sdml_generate::actions::tags::write_ctags;
+use sdml_generate::actions::tags::{write_ctags, write_ctags_for_modules};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -16,6 +17,8 @@ use sdml_generate::actions::tags::write_ctags;
 ///
 /// - CTags ::
 ///
+/// The `workspace` option writes a single tag file for every module in a workspace.
+///
 #[derive(Args, Debug)]
 pub(crate) struct Command {
     #[arg(short = 'f', long)]
@@ -23,6 +26,9 @@ pub(crate) struct Command {
     #[arg(default_value_t = OutputFormat::CTags)]
     output_format: OutputFormat,
 
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
     #[command(flatten)]
     files: super::FileArgs,
 }
@@ -40,6 +46,22 @@ pub(crate) enum OutputFormat {
 
 impl super::Command for Command {
     fn execute(&self) -> Result<ExitCode, Error> {
+        if self.workspace.is_workspace() {
+            let reporter = StandardStreamReporter::default();
+            let workspace = self.workspace.load(super::new_loader(Box::new(reporter)))?;
+            let modules = workspace.modules().collect::<Vec<_>>();
+
+            let mut output = self.files.output.clone();
+            let mut writer = output.lock();
+
+            match self.output_format {
+                OutputFormat::CTags => write_ctags_for_modules(&modules, &mut writer)?,
+            }
+
+            eprintln!("Generated tags for {} modules.", modules.len());
+            return Ok(super::workspace_exit_code(&workspace));
+        }
+
         call_with_module!(self, |module: &Module, _, _| {
             let mut output = self.files.output.clone();
             let mut writer = output.lock();
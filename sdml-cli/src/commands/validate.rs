@@ -58,6 +58,14 @@ use std::process::ExitCode;
 /// note,examples/errors/i0506.sdm,3,13,3,26,I0506,identifier not using preferred casing
 /// ```
 ///
+/// The `workspace` option validates every module found under the given directories, or listed
+/// in the given catalog files, and prints a combined summary.
+///
+/// ```text
+/// ❯ sdml validate --workspace models
+/// No issues found in 12 modules.
+/// ```
+///
 #[derive(Args, Debug)]
 pub(crate) struct Command {
     #[arg(short = 'l', long)]
@@ -73,6 +81,9 @@ pub(crate) struct Command {
     #[arg(short = 's', long, default_value = "false")]
     short_form: bool,
 
+    #[command(flatten)]
+    workspace: super::WorkspaceArgs,
+
     #[command(flatten)]
     files: super::FileArgs,
 }
@@ -107,6 +118,22 @@ impl super::Command for Command {
         } else {
             Box::<StandardStreamReporter>::default()
         };
+
+        if self.workspace.is_workspace() {
+            let mut loader = super::new_loader(reporter);
+            loader.set_severity_filter(self.level.into());
+            let workspace = self.workspace.load(loader)?;
+            workspace.validate(self.check_constraints)?;
+
+            let term_set = default_term_set()?;
+            for module in workspace.modules() {
+                validate_module_terms(module, &term_set, workspace.loader());
+            }
+
+            let counters = workspace.loader().reporter_counters();
+            return Ok(super::workspace_summary(&workspace, counters));
+        }
+
         call_with_module!(
             self,
             reporter,
@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{ArgGroup, Args};
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasSourceSpan;
+use sdml_core::refactor::{SourceEdits, TextEdit};
+use sdml_errors::Error;
+use sdml_generate::actions::diff::{diff_modules, VersionBump};
+use semver::Version;
+use tempfile::TempDir;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Check that a module's version has advanced enough since a previous release.
+///
+/// This command compares a module against a previous release, given either as a file or as a git
+/// reference, and computes the semantic version increment required by the changes between them;
+/// breaking changes require a major version, additions a minor version, and any other change a
+/// patch version. The command fails if the module's `version_info` header has not advanced
+/// enough from that of the previous release.
+///
+/// ```text
+/// ❯ sdml version-check --git-ref v1.2.0 rentals.sdm
+/// 3 changes since version 1.2.0, 2 breaking; requires a major version increment.
+/// Error: version 1.3.0 has not advanced enough, expected at least 2.0.0.
+/// ```
+///
+/// The `update` option rewrites the module's version header, `version_info` and any occurrence
+/// of the version in `version_uri`, to the smallest sufficient version.
+///
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("release").required(true).args(["previous", "git_ref"])))]
+pub(crate) struct Command {
+    /// A file containing the previous release of the module
+    #[arg(short, long)]
+    previous: Option<PathBuf>,
+
+    /// A git reference, such as a tag, for the previous release of the module
+    #[arg(short, long)]
+    git_ref: Option<String>,
+
+    /// Update the module's version header if it has not advanced enough
+    #[arg(short, long)]
+    update: bool,
+
+    /// The module file to check
+    file: PathBuf,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl super::Command for Command {
+    fn execute(&self) -> Result<ExitCode, Error> {
+        // the temporary directory is kept until the previous release has been loaded.
+        let (_checkout, previous_file) = match (&self.previous, &self.git_ref) {
+            (Some(file), _) => (None, file.clone()),
+            (None, Some(git_ref)) => {
+                let (checkout, file) = checkout_git_ref(&self.file, git_ref)?;
+                (Some(checkout), file)
+            }
+            (None, None) => unreachable!(),
+        };
+        let (Some(previous), Some(current)) = (
+            super::diff::load_module_file(&previous_file)?,
+            super::diff::load_module_file(&self.file)?,
+        ) else {
+            return Ok(ExitCode::FAILURE);
+        };
+
+        let Some(previous_version) = module_version(&previous) else {
+            eprintln!("Error: the previous release does not declare a semantic version in `version_info`.");
+            return Ok(ExitCode::FAILURE);
+        };
+        let diff = diff_modules(&previous, &current);
+        let required = diff.required_bump();
+        println!(
+            "{} changes since version {previous_version}, {} breaking; requires {} version increment.",
+            diff.len(),
+            diff.breaking_changes().count(),
+            match required {
+                VersionBump::None => "no".to_string(),
+                bump => format!("a {bump}"),
+            }
+        );
+
+        let current_version = module_version(&current);
+        let provided = current_version
+            .as_ref()
+            .map(|version| VersionBump::between(&previous_version, version))
+            .unwrap_or_default();
+        if provided >= required {
+            if let Some(current_version) = current_version {
+                println!("Version {current_version} is sufficient.");
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let expected = required.apply(&previous_version);
+        match &current_version {
+            Some(current_version) => eprintln!(
+                "Error: version {current_version} has not advanced enough, expected at least {expected}."
+            ),
+            None => eprintln!(
+                "Error: the module does not declare a semantic version in `version_info`, expected at least {expected}."
+            ),
+        }
+        if self.update {
+            let mut old_versions = vec![previous_version];
+            old_versions.extend(current_version);
+            if update_version(&current, &self.file, &old_versions, &expected)? {
+                println!("Updated version to {expected}.");
+                return Ok(ExitCode::SUCCESS);
+            }
+            eprintln!("Error: the module has no `version` clause to update.");
+        }
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn module_version(module: &Module) -> Option<Version> {
+    module
+        .version_info()
+        .and_then(|info| Version::parse(info.value().trim().trim_start_matches('v')).ok())
+}
+
+///
+/// Extract the tree at `git_ref`, from the repository containing `file`, into a temporary
+/// directory, returning the directory and the path of `file` within it.
+///
+fn checkout_git_ref(file: &Path, git_ref: &str) -> Result<(TempDir, PathBuf), Error> {
+    let file = file.canonicalize()?;
+    let directory = file.parent().unwrap_or(Path::new("."));
+    let top_level = git(directory, &["rev-parse", "--show-toplevel"])?;
+    let top_level = PathBuf::from(String::from_utf8_lossy(&top_level).trim()).canonicalize()?;
+    let relative = file
+        .strip_prefix(&top_level)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+    let archive = git(&top_level, &["archive", "--format=tar", git_ref])?;
+    let checkout = tempfile::tempdir()?;
+    tar::Archive::new(archive.as_slice()).unpack(checkout.path())?;
+    let previous = checkout.path().join(relative);
+    Ok((checkout, previous))
+}
+
+fn git(directory: &Path, args: &[&str]) -> Result<Vec<u8>, Error> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(directory)
+        .args(args)
+        .output()?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(std::io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+        .into())
+    }
+}
+
+///
+/// Rewrite the version header of `module`, in `file`, to `new_version`; the first of
+/// `old_versions` found in the version URI is also replaced. Returns `false` if the module has no
+/// version clause.
+///
+fn update_version(
+    module: &Module,
+    file: &Path,
+    old_versions: &[Version],
+    new_version: &Version,
+) -> Result<bool, Error> {
+    let Some(version_uri) = module.version_uri() else {
+        return Ok(false);
+    };
+    let source = std::fs::read_to_string(file)?;
+    let mut edits = SourceEdits::default();
+    let file = file.canonicalize()?;
+
+    match module.version_info().and_then(|info| info.source_span()) {
+        Some(span) => edits.add(
+            file.clone(),
+            TextEdit::replace_span(span, format!("{:?}", new_version.to_string())),
+        ),
+        None => {
+            if let Some(span) = version_uri.source_span() {
+                edits.add(
+                    file.clone(),
+                    TextEdit::insert(
+                        span.start().byte(),
+                        format!("{:?} ", new_version.to_string()),
+                    ),
+                );
+            }
+        }
+    }
+    if let Some(span) = version_uri.source_span() {
+        let old_text = &source[span.byte_range()];
+        let new_text = old_versions
+            .iter()
+            .map(|old_version| old_text.replace(&old_version.to_string(), &new_version.to_string()))
+            .find(|new_text| new_text != old_text);
+        if let Some(new_text) = new_text {
+            edits.add(file, TextEdit::replace_span(span, new_text));
+        }
+    }
+    edits.apply()?;
+    Ok(true)
+}
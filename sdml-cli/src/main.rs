@@ -3,6 +3,7 @@ use clap::{Parser, ValueEnum};
 use sdml_errors::diagnostics::UseColor;
 use sdml_errors::Error;
 use sdml_generate::color::set_colorize;
+use sdml_parse::cache::ParsedModuleCache;
 use std::process::ExitCode;
 use tracing::{error, info};
 use tracing_subscriber::filter::EnvFilter;
@@ -41,6 +42,19 @@ struct Cli {
     )]
     no_color: bool,
 
+    /// Turn off the on-disk cache of parsed modules
+    #[arg(
+        long,
+        action = clap::ArgAction::SetTrue,
+        env = "SDML_NO_CACHE",
+        value_parser = FalseyValueParser::new(),
+    )]
+    no_cache: bool,
+
+    /// Remove all entries from the on-disk cache of parsed modules before running the command
+    #[arg(long)]
+    clear_cache: bool,
+
     #[command(subcommand)]
     command: commands::Commands,
 }
@@ -76,6 +90,10 @@ fn main() -> ExitCode {
         eprintln!("Could not initialize tracing; error: {e}");
         error!("init_logging failed, exiting. error: {e:?}");
         ExitCode::FAILURE
+    } else if let Err(e) = init_cache(cli.no_cache, cli.clear_cache) {
+        eprintln!("Could not clear the module cache; error: {e}");
+        error!("init_cache failed, exiting. error: {e:?}");
+        ExitCode::FAILURE
     } else {
         match cli.command.execute() {
             Ok(v) => v,
@@ -118,6 +136,25 @@ fn init_logging(log_filter: LogFilter) -> Result<(), Error> {
 
 // ------------------------------------------------------------------------------------------------
 
+fn init_cache(no_cache: bool, clear_cache: bool) -> Result<(), Error> {
+    if clear_cache {
+        if let Some(parsed_cache) = ParsedModuleCache::user_default() {
+            let removed = parsed_cache.clear()?;
+            info!(
+                "Removed {removed} entries from module cache {:?}",
+                parsed_cache.directory()
+            );
+        }
+    }
+    if no_cache {
+        info!("Turning off module cache");
+    }
+    commands::set_use_parsed_cache(!no_cache);
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+
 fn init_color(no_color: bool) {
     if no_color {
         info!("Turning off color");
@@ -56,6 +56,8 @@ pub mod load;
 
 pub mod model;
 
+pub mod refactor;
+
 pub mod stdlib;
 
 pub mod syntax;
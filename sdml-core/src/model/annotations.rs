@@ -312,7 +312,7 @@ impl<A: HasAnnotations> AnnotationBuilder for A {
 // ------------------------------------------------------------------------------------------------
 
 /// Corresponds to the grammar rule `annotation`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(clippy::large_enum_variant)] // TODO: why is this reported as an issue?
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Annotation {
@@ -409,6 +409,8 @@ impl Annotation {
 // Implementations ❱ Annotations ❱ Annotation Properties
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(AnnotationProperty => name_reference, value);
+
 impl_has_source_span_for!(AnnotationProperty);
 
 impl_has_name_reference_for!(AnnotationProperty);
@@ -440,16 +442,23 @@ impl AnnotationProperty {
     // AnnotationProperty :: Fields
     // --------------------------------------------------------------------------------------------
 
+    getter!(pub name_reference => mut name_reference_mut, IdentifierReference);
+
     get_and_set!(pub value, set_value => Value);
+    getter!(pub value => mut value_mut, Value);
 
     // --------------------------------------------------------------------------------------------
     // AnnotationProperty :: Helpers
     // --------------------------------------------------------------------------------------------
 
+    ///
+    /// Returns `true` if this property is defined in a library module, as determined by
+    /// [`ModuleStore::is_library_module`].
+    ///
     #[inline(always)]
-    pub fn is_stdlib_property(&self) -> bool {
+    pub fn is_library_property(&self, cache: &impl ModuleStore) -> bool {
         if let IdentifierReference::QualifiedIdentifier(name) = self.name_reference() {
-            stdlib::is_library_module(name.module())
+            cache.is_library_module(name.module())
         } else {
             false
         }
@@ -468,6 +477,8 @@ impl AnnotationProperty {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(AnnotationOnlyBody => annotations);
+
 impl_has_source_span_for!(AnnotationOnlyBody);
 
 impl_has_annotations_for!(AnnotationOnlyBody);
@@ -0,0 +1,1046 @@
+/*!
+Provide a fluent API for building a [`Module`] in code.
+
+Rather than calling the individual constructors for modules, definitions, members and
+cardinalities, a [`ModuleBuilder`] accepts names and type references as strings, builds each
+definition using a closure over a definition-specific builder, and returns a validated module
+from [`ModuleBuilder::build`]. The following checks are made as the module is built.
+
+1. Every name and reference is a valid identifier, or qualified identifier.
+2. Definition names are unique within the module, member names unique within their definition,
+   and variant names unique within their enum or union. Duplicate imports are also reported.
+3. Every type, property, and annotation reference resolves to a definition in the module, a
+   member import, a builtin type, or a qualified name whose module is imported.
+
+The first problem found is returned from `build` as an [`Error`], usually carrying the same
+diagnostic as the parser would produce for the equivalent source.
+
+# Example
+
+```rust
+use sdml_core::model::builder::ModuleBuilder;
+use sdml_core::model::values::LanguageString;
+use sdml_core::model::{HasBody, HasName};
+
+let module = ModuleBuilder::new("rentals")
+    .with_base_uri("https://example.com/rentals#")
+    .import_module("skos")
+    .datatype("vin", "string", |datatype| datatype)
+    .entity("Vehicle", |entity| {
+        entity
+            .identity("vin", "vin")
+            .member_with("tags", "string", |member| member.many().unique())
+            .with_annotation("skos:prefLabel", LanguageString::from("Vehicle"))
+    })
+    .enumeration("VehicleKind", |kind| kind.variant("Car").variant("Van"))
+    .build()
+    .unwrap();
+
+assert_eq!(module.name().as_ref(), "rentals");
+assert_eq!(module.body().definitions_len(), 3);
+```
+
+Duplicate names are reported when the module is built.
+
+```rust
+use sdml_core::model::builder::ModuleBuilder;
+
+let result = ModuleBuilder::new("rentals")
+    .structure("Address", |address| address.member("street", "string"))
+    .structure("Address", |address| address)
+    .build();
+
+assert!(result.is_err());
+```
+*/
+
+use crate::model::annotations::{
+    Annotation, AnnotationOnlyBody, AnnotationProperty, HasAnnotations,
+};
+use crate::model::definitions::{
+    DatatypeDef, Definition, EntityBody, EntityDef, EnumBody, EnumDef, EventDef, HasMembers,
+    HasVariants, PropertyDef, StructureBody, StructureDef, TypeVariant, UnionBody, UnionDef,
+    ValueVariant,
+};
+use crate::model::identifiers::{Identifier, IdentifierReference, QualifiedIdentifier};
+use crate::model::members::{Cardinality, Member, MemberDef, Ordering, TypeReference, Uniqueness};
+use crate::model::modules::{ImportStatement, Module};
+use crate::model::values::Value;
+use crate::model::{HasBody, HasName, HasOptionalBody};
+use crate::stdlib::{is_builtin_type_name, sdml};
+use sdml_errors::diagnostics::functions::{
+    duplicate_definition, duplicate_definition_import, duplicate_member, duplicate_module_import,
+    duplicate_variant, imported_module_not_found, type_definition_not_found,
+};
+use sdml_errors::{Diagnostic, Error, FileId, Span};
+use std::collections::HashSet;
+use std::str::FromStr;
+use url::Url;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Builds a [`Module`], its imports, annotations and definitions.
+///
+#[derive(Debug)]
+pub struct ModuleBuilder {
+    module: Module,
+    checks: Checks,
+    definitions: HashSet<Identifier>,
+    imported_modules: HashSet<Identifier>,
+    imported_members: HashSet<QualifiedIdentifier>,
+}
+
+///
+/// Builds a [`DatatypeDef`], see [`ModuleBuilder::datatype`].
+///
+#[derive(Debug)]
+pub struct DatatypeBuilder {
+    opaque: bool,
+    annotations: Vec<Annotation>,
+    checks: Checks,
+}
+
+///
+/// Builds the body of an [`EntityDef`], see [`ModuleBuilder::entity`].
+///
+#[derive(Debug)]
+pub struct EntityBuilder {
+    identity: Option<Member>,
+    members: MembersBuilder,
+}
+
+///
+/// Builds a [`StructureBody`] for either a [`StructureDef`] or an [`EventDef`], see
+/// [`ModuleBuilder::structure`] and [`ModuleBuilder::event`].
+///
+#[derive(Debug)]
+pub struct StructureBuilder {
+    members: MembersBuilder,
+}
+
+///
+/// Builds the body of an [`EnumDef`], see [`ModuleBuilder::enumeration`].
+///
+#[derive(Debug)]
+pub struct EnumBuilder {
+    body: EnumBody,
+    names: HashSet<Identifier>,
+    checks: Checks,
+}
+
+///
+/// Builds the body of a [`UnionDef`], see [`ModuleBuilder::union`].
+///
+#[derive(Debug)]
+pub struct UnionBuilder {
+    body: UnionBody,
+    names: HashSet<Identifier>,
+    checks: Checks,
+}
+
+///
+/// Builds the optional annotation body of a [`ValueVariant`] or [`TypeVariant`].
+///
+#[derive(Debug)]
+pub struct VariantBuilder {
+    annotations: Vec<Annotation>,
+    checks: Checks,
+}
+
+///
+/// Builds the cardinality and annotations of a [`MemberDef`], see [`StructureBuilder::member_with`]
+/// and [`ModuleBuilder::property`].
+///
+#[derive(Debug)]
+pub struct MemberBuilder {
+    cardinality: Cardinality,
+    annotations: Vec<Annotation>,
+    checks: Checks,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The first error found by a builder, and the references that must be resolved when the
+/// module is built.
+///
+#[derive(Debug, Default)]
+struct Checks {
+    error: Option<Error>,
+    references: Vec<IdentifierReference>,
+}
+
+#[derive(Debug)]
+struct MembersBuilder {
+    members: Vec<Member>,
+    names: HashSet<Identifier>,
+    annotations: Vec<Annotation>,
+    checks: Checks,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Macros
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! with_annotation_fn {
+    () => {
+        ///
+        /// Add an annotation property, named by `name`, with the value `value`.
+        ///
+        pub fn with_annotation<V>(self, name: &str, value: V) -> Self
+        where
+            V: Into<Value>,
+        {
+            let mut self_mut = self;
+            let property = self_mut.checks.annotation(name, value.into());
+            self_mut.annotations.push(property);
+            self_mut
+        }
+    };
+    ($inner: ident) => {
+        ///
+        /// Add an annotation property, named by `name`, with the value `value`.
+        ///
+        pub fn with_annotation<V>(self, name: &str, value: V) -> Self
+        where
+            V: Into<Value>,
+        {
+            let mut self_mut = self;
+            let property = self_mut.$inner.checks.annotation(name, value.into());
+            self_mut.$inner.annotations.push(property);
+            self_mut
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ModuleBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl ModuleBuilder {
+    ///
+    /// Start building a new, empty, module named `name`.
+    ///
+    pub fn new(name: &str) -> Self {
+        let mut checks = Checks::default();
+        let name = checks.identifier(name);
+        Self {
+            module: Module::empty(name),
+            checks,
+            definitions: Default::default(),
+            imported_modules: Default::default(),
+            imported_members: Default::default(),
+        }
+    }
+
+    ///
+    /// Set the module's base URI.
+    ///
+    pub fn with_base_uri(self, base_uri: &str) -> Self {
+        let mut self_mut = self;
+        match Url::parse(base_uri) {
+            Ok(base_uri) => self_mut.module = self_mut.module.with_base_uri(base_uri),
+            Err(e) => self_mut.checks.fail(e.into()),
+        }
+        self_mut
+    }
+
+    ///
+    /// Set the module's version information string.
+    ///
+    pub fn with_version_info<S>(self, version_info: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut self_mut = self;
+        self_mut.module = self_mut.module.with_version_info(version_info);
+        self_mut
+    }
+
+    ///
+    /// Set the module's version URI.
+    ///
+    pub fn with_version_uri(self, version_uri: &str) -> Self {
+        let mut self_mut = self;
+        match Url::parse(version_uri) {
+            Ok(version_uri) => self_mut.module = self_mut.module.with_version_uri(version_uri),
+            Err(e) => self_mut.checks.fail(e.into()),
+        }
+        self_mut
+    }
+
+    ///
+    /// Import the module named `name`, as in `import name`.
+    ///
+    pub fn import_module(self, name: &str) -> Self {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        if !self_mut.imported_modules.insert(name.clone()) {
+            self_mut
+                .checks
+                .fail(duplicate_error(duplicate_module_import, &name));
+        }
+        self_mut
+            .module
+            .body_mut()
+            .add_to_imports(ImportStatement::new_module(name));
+        self_mut
+    }
+
+    ///
+    /// Import a single member using its qualified name, as in `import module:Member`.
+    ///
+    pub fn import_member(self, name: &str) -> Self {
+        let mut self_mut = self;
+        let name = match QualifiedIdentifier::from_str(name) {
+            Ok(name) => name,
+            Err(e) => {
+                self_mut.checks.fail(e);
+                return self_mut;
+            }
+        };
+        if !self_mut.imported_members.insert(name.clone()) {
+            self_mut
+                .checks
+                .fail(duplicate_error(duplicate_definition_import, &name));
+        }
+        self_mut
+            .module
+            .body_mut()
+            .add_to_imports(ImportStatement::new_member(name));
+        self_mut
+    }
+
+    ///
+    /// Add an annotation property on the module itself.
+    ///
+    pub fn with_annotation<V>(self, name: &str, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        let mut self_mut = self;
+        let property = self_mut.checks.annotation(name, value.into());
+        self_mut.module.body_mut().add_to_annotations(property);
+        self_mut
+    }
+
+    ///
+    /// Add a datatype definition named `name` with the base type `base_type`.
+    ///
+    pub fn datatype<F>(self, name: &str, base_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(DatatypeBuilder) -> DatatypeBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let base_type = self_mut.checks.type_reference(base_type);
+        let mut datatype = builder(DatatypeBuilder::new());
+        let definition = if datatype.opaque {
+            DatatypeDef::new_opaque(name, base_type)
+        } else {
+            DatatypeDef::new(name, base_type)
+        };
+        let definition = match annotation_body(&mut datatype.annotations) {
+            Some(body) => definition.with_body(body),
+            None => definition,
+        };
+        self_mut.checks.merge(datatype.checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Add an entity definition named `name`. An entity with members or annotations must also
+    /// have an identity member, see [`EntityBuilder::identity`].
+    ///
+    pub fn entity<F>(self, name: &str, builder: F) -> Self
+    where
+        F: FnOnce(EntityBuilder) -> EntityBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let entity = builder(EntityBuilder::new());
+        let mut members = entity.members;
+        let mut definition = EntityDef::new(name.clone());
+        match entity.identity {
+            Some(identity) => {
+                let mut body = EntityBody::new(identity);
+                body.extend_annotations(members.annotations.drain(..));
+                members
+                    .members
+                    .drain(..)
+                    .for_each(|m| body.add_to_members(m));
+                definition.set_body(body);
+            }
+            None if !(members.members.is_empty() && members.annotations.is_empty()) => {
+                members.checks.fail(Error::GeneratorError {
+                    name: "ModuleBuilder".to_string(),
+                    message: format!("entity `{name}` has members or annotations but no identity"),
+                });
+            }
+            None => {}
+        }
+        self_mut.checks.merge(members.checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Add an enum definition named `name`.
+    ///
+    pub fn enumeration<F>(self, name: &str, builder: F) -> Self
+    where
+        F: FnOnce(EnumBuilder) -> EnumBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let enumeration = builder(EnumBuilder::new());
+        let mut definition = EnumDef::new(name);
+        if enumeration.body.has_variants() || enumeration.body.has_annotations() {
+            definition.set_body(enumeration.body);
+        }
+        self_mut.checks.merge(enumeration.checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Add an event definition named `name` with the source entity `event_source`.
+    ///
+    pub fn event<F>(self, name: &str, event_source: &str, builder: F) -> Self
+    where
+        F: FnOnce(StructureBuilder) -> StructureBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let event_source = self_mut.checks.type_reference(event_source);
+        let (body, checks) = builder(StructureBuilder::new()).finish();
+        let mut definition = EventDef::new(name, event_source);
+        if let Some(body) = body {
+            definition.set_body(body);
+        }
+        self_mut.checks.merge(checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Add a property definition named `name` with the type `target_type`.
+    ///
+    pub fn property<F>(self, name: &str, target_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        let mut self_mut = self;
+        let (member, checks) = MemberBuilder::build(name, target_type, builder);
+        self_mut.checks.merge(checks);
+        self_mut.add_definition(PropertyDef::new(member))
+    }
+
+    ///
+    /// Add a structure definition named `name`.
+    ///
+    pub fn structure<F>(self, name: &str, builder: F) -> Self
+    where
+        F: FnOnce(StructureBuilder) -> StructureBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let (body, checks) = builder(StructureBuilder::new()).finish();
+        let mut definition = StructureDef::new(name);
+        if let Some(body) = body {
+            definition.set_body(body);
+        }
+        self_mut.checks.merge(checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Add a union definition named `name`.
+    ///
+    pub fn union<F>(self, name: &str, builder: F) -> Self
+    where
+        F: FnOnce(UnionBuilder) -> UnionBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        let union = builder(UnionBuilder::new());
+        let definition = if union.body.has_variants() || union.body.has_annotations() {
+            UnionDef::new(name).with_body(union.body)
+        } else {
+            UnionDef::new(name)
+        };
+        self_mut.checks.merge(union.checks);
+        self_mut.add_definition(definition)
+    }
+
+    ///
+    /// Complete the module, returning the first error found while building it or any reference
+    /// that cannot be resolved.
+    ///
+    pub fn build(self) -> Result<Module, Error> {
+        let Self {
+            module,
+            checks,
+            definitions,
+            imported_modules,
+            imported_members,
+        } = self;
+        if let Some(error) = checks.error {
+            return Err(error);
+        }
+
+        let module_name = module.name();
+        for reference in &checks.references {
+            match reference {
+                IdentifierReference::Identifier(name) => {
+                    if !definitions.contains(name)
+                        && !imported_members.iter().any(|m| m.member() == name)
+                    {
+                        return Err(type_definition_not_found(0, None, name.to_string()).into());
+                    }
+                }
+                IdentifierReference::QualifiedIdentifier(name) => {
+                    let imported = name.module();
+                    if imported == module_name {
+                        if !definitions.contains(name.member()) {
+                            return Err(type_definition_not_found(0, None, name.to_string()).into());
+                        }
+                    } else if imported.as_ref() != sdml::MODULE_NAME
+                        && !imported_modules.contains(imported)
+                        && !imported_members.iter().any(|m| m.module() == imported)
+                    {
+                        return Err(imported_module_not_found(0, None, imported.to_string()).into());
+                    }
+                }
+            }
+        }
+
+        Ok(module)
+    }
+
+    fn add_definition<D>(self, definition: D) -> Self
+    where
+        D: Into<Definition>,
+    {
+        let mut self_mut = self;
+        let definition = definition.into();
+        if !self_mut.definitions.insert(definition.name().clone()) {
+            self_mut
+                .checks
+                .fail(duplicate_error(duplicate_definition, definition.name()));
+        }
+        if let Err(e) = self_mut.module.body_mut().add_to_definitions(definition) {
+            self_mut.checks.fail(e);
+        }
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ DatatypeBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl DatatypeBuilder {
+    fn new() -> Self {
+        Self {
+            opaque: false,
+            annotations: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    ///
+    /// Mark the datatype as `opaque`.
+    ///
+    pub fn opaque(self) -> Self {
+        Self {
+            opaque: true,
+            ..self
+        }
+    }
+
+    with_annotation_fn!();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ EntityBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl EntityBuilder {
+    fn new() -> Self {
+        Self {
+            identity: None,
+            members: MembersBuilder::new(),
+        }
+    }
+
+    ///
+    /// Set the entity's identity member, named `name` with the type `target_type`.
+    ///
+    pub fn identity(self, name: &str, target_type: &str) -> Self {
+        self.identity_with(name, target_type, |member| member)
+    }
+
+    ///
+    /// Set the entity's identity member, as [`EntityBuilder::identity`], using `builder` to
+    /// complete it.
+    ///
+    pub fn identity_with<F>(self, name: &str, target_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        let mut self_mut = self;
+        let (member, checks) = MemberBuilder::build(name, target_type, builder);
+        self_mut.members.checks.merge(checks);
+        let member = Member::new_definition(member);
+        self_mut.members.unique_name(member.name());
+        self_mut.identity = Some(member);
+        self_mut
+    }
+
+    ///
+    /// Add a member named `name` with the type `target_type` and a cardinality of one.
+    ///
+    pub fn member(self, name: &str, target_type: &str) -> Self {
+        self.member_with(name, target_type, |member| member)
+    }
+
+    ///
+    /// Add a member named `name` with the type `target_type`, using `builder` to complete it.
+    ///
+    pub fn member_with<F>(self, name: &str, target_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        Self {
+            members: self.members.member_with(name, target_type, builder),
+            ..self
+        }
+    }
+
+    ///
+    /// Add a member that references the property definition `property`.
+    ///
+    pub fn member_ref(self, property: &str) -> Self {
+        Self {
+            members: self.members.member_ref(property),
+            ..self
+        }
+    }
+
+    with_annotation_fn!(members);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ StructureBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl StructureBuilder {
+    fn new() -> Self {
+        Self {
+            members: MembersBuilder::new(),
+        }
+    }
+
+    ///
+    /// Add a member named `name` with the type `target_type` and a cardinality of one.
+    ///
+    pub fn member(self, name: &str, target_type: &str) -> Self {
+        self.member_with(name, target_type, |member| member)
+    }
+
+    ///
+    /// Add a member named `name` with the type `target_type`, using `builder` to complete it.
+    ///
+    pub fn member_with<F>(self, name: &str, target_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        Self {
+            members: self.members.member_with(name, target_type, builder),
+        }
+    }
+
+    ///
+    /// Add a member that references the property definition `property`.
+    ///
+    pub fn member_ref(self, property: &str) -> Self {
+        Self {
+            members: self.members.member_ref(property),
+        }
+    }
+
+    with_annotation_fn!(members);
+
+    fn finish(self) -> (Option<StructureBody>, Checks) {
+        let MembersBuilder {
+            members,
+            annotations,
+            checks,
+            ..
+        } = self.members;
+        if members.is_empty() && annotations.is_empty() {
+            (None, checks)
+        } else {
+            let mut body = StructureBody::default();
+            body.extend_annotations(annotations);
+            members.into_iter().for_each(|m| body.add_to_members(m));
+            (Some(body), checks)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ EnumBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl EnumBuilder {
+    fn new() -> Self {
+        Self {
+            body: Default::default(),
+            names: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    ///
+    /// Add a value variant named `name`.
+    ///
+    pub fn variant(self, name: &str) -> Self {
+        self.variant_with(name, |variant| variant)
+    }
+
+    ///
+    /// Add a value variant named `name`, using `builder` to add annotations.
+    ///
+    pub fn variant_with<F>(self, name: &str, builder: F) -> Self
+    where
+        F: FnOnce(VariantBuilder) -> VariantBuilder,
+    {
+        let mut self_mut = self;
+        let name = self_mut.checks.identifier(name);
+        if !self_mut.names.insert(name.clone()) {
+            self_mut
+                .checks
+                .fail(duplicate_error(duplicate_variant, &name));
+        }
+        let mut variant = builder(VariantBuilder::new());
+        let mut value_variant = ValueVariant::new(name);
+        if let Some(body) = annotation_body(&mut variant.annotations) {
+            value_variant.set_body(body);
+        }
+        self_mut.checks.merge(variant.checks);
+        self_mut.body.add_to_variants(value_variant);
+        self_mut
+    }
+
+    ///
+    /// Add an annotation property, named by `name`, with the value `value`.
+    ///
+    pub fn with_annotation<V>(self, name: &str, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        let mut self_mut = self;
+        let property = self_mut.checks.annotation(name, value.into());
+        self_mut.body.add_to_annotations(property);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ UnionBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl UnionBuilder {
+    fn new() -> Self {
+        Self {
+            body: Default::default(),
+            names: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    ///
+    /// Add a type variant for the type `target_type`.
+    ///
+    pub fn variant(self, target_type: &str) -> Self {
+        self.variant_with(target_type, None, |variant| variant)
+    }
+
+    ///
+    /// Add a type variant for the type `target_type`, renamed as `rename`.
+    ///
+    pub fn variant_as(self, target_type: &str, rename: &str) -> Self {
+        self.variant_with(target_type, Some(rename), |variant| variant)
+    }
+
+    ///
+    /// Add a type variant for the type `target_type`, optionally renamed, using `builder` to add
+    /// annotations.
+    ///
+    pub fn variant_with<F>(self, target_type: &str, rename: Option<&str>, builder: F) -> Self
+    where
+        F: FnOnce(VariantBuilder) -> VariantBuilder,
+    {
+        let mut self_mut = self;
+        let target_type = self_mut.checks.type_reference(target_type);
+        let mut type_variant = TypeVariant::new(target_type);
+        if let Some(rename) = rename {
+            type_variant = type_variant.with_rename(self_mut.checks.identifier(rename));
+        }
+        if !self_mut.names.insert(type_variant.name().clone()) {
+            self_mut
+                .checks
+                .fail(duplicate_error(duplicate_variant, type_variant.name()));
+        }
+        let mut variant = builder(VariantBuilder::new());
+        if let Some(body) = annotation_body(&mut variant.annotations) {
+            type_variant.set_body(body);
+        }
+        self_mut.checks.merge(variant.checks);
+        self_mut.body.add_to_variants(type_variant);
+        self_mut
+    }
+
+    ///
+    /// Add an annotation property, named by `name`, with the value `value`.
+    ///
+    pub fn with_annotation<V>(self, name: &str, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        let mut self_mut = self;
+        let property = self_mut.checks.annotation(name, value.into());
+        self_mut.body.add_to_annotations(property);
+        self_mut
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ VariantBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl VariantBuilder {
+    fn new() -> Self {
+        Self {
+            annotations: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    with_annotation_fn!();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ MemberBuilder
+// ------------------------------------------------------------------------------------------------
+
+impl MemberBuilder {
+    fn new() -> Self {
+        Self {
+            cardinality: Cardinality::one(),
+            annotations: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    ///
+    /// Set the member's cardinality range; a `max` of `None` is unbounded. A `max` of zero, or
+    /// less than `min`, is reported as an error when the module is built.
+    ///
+    pub fn cardinality(self, min: u32, max: Option<u32>) -> Self {
+        let mut self_mut = self;
+        let cardinality = match max {
+            Some(max) if max == 0 || max < min => {
+                self_mut.checks.fail(Error::GeneratorError {
+                    name: "ModuleBuilder".to_string(),
+                    message: format!("invalid cardinality range {{{min}..{max}}}"),
+                });
+                return self_mut;
+            }
+            Some(max) if max == min => Cardinality::new_single(max),
+            Some(max) => Cardinality::new_range(min, max),
+            None => Cardinality::new_unbounded(min, None, None),
+        }
+        .with_ordering(self_mut.cardinality.ordering())
+        .with_uniqueness(self_mut.cardinality.uniqueness());
+        Self {
+            cardinality,
+            ..self_mut
+        }
+    }
+
+    ///
+    /// Set the member's cardinality to `0..1`.
+    ///
+    pub fn optional(self) -> Self {
+        self.cardinality(0, Some(1))
+    }
+
+    ///
+    /// Set the member's cardinality to `0..`.
+    ///
+    pub fn many(self) -> Self {
+        self.cardinality(0, None)
+    }
+
+    ///
+    /// Mark the member's values as `ordered`.
+    ///
+    pub fn ordered(self) -> Self {
+        Self {
+            cardinality: self.cardinality.with_ordering(Some(Ordering::Ordered)),
+            ..self
+        }
+    }
+
+    ///
+    /// Mark the member's values as `unique`.
+    ///
+    pub fn unique(self) -> Self {
+        Self {
+            cardinality: self.cardinality.with_uniqueness(Some(Uniqueness::Unique)),
+            ..self
+        }
+    }
+
+    with_annotation_fn!();
+
+    fn build<F>(name: &str, target_type: &str, builder: F) -> (MemberDef, Checks)
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        let mut member = builder(MemberBuilder::new());
+        let name = member.checks.identifier(name);
+        let target_type = member.checks.type_reference(target_type);
+        let mut definition = MemberDef::new(name, TypeReference::Type(target_type))
+            .with_target_cardinality(member.cardinality);
+        if let Some(body) = annotation_body(&mut member.annotations) {
+            definition = definition.with_body(body);
+        }
+        (definition, member.checks)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Checks {
+    fn fail(&mut self, error: Error) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    fn merge(&mut self, other: Checks) {
+        if let Some(error) = other.error {
+            self.fail(error);
+        }
+        self.references.extend(other.references);
+    }
+
+    fn identifier(&mut self, name: &str) -> Identifier {
+        Identifier::from_str(name).unwrap_or_else(|e| {
+            self.fail(e);
+            Identifier::new_unchecked(name)
+        })
+    }
+
+    fn reference(&mut self, name: &str) -> IdentifierReference {
+        match IdentifierReference::from_str(name) {
+            Ok(reference) => {
+                self.references.push(reference.clone());
+                reference
+            }
+            Err(e) => {
+                self.fail(e);
+                Identifier::new_unchecked(name).into()
+            }
+        }
+    }
+
+    fn type_reference(&mut self, name: &str) -> IdentifierReference {
+        // builtin simple types are qualified, as the parser does.
+        match Identifier::from_str(name) {
+            Ok(name) if is_builtin_type_name(&name) => {
+                QualifiedIdentifier::new(Identifier::new_unchecked(sdml::MODULE_NAME), name).into()
+            }
+            _ => self.reference(name),
+        }
+    }
+
+    fn annotation(&mut self, name: &str, value: Value) -> Annotation {
+        AnnotationProperty::new(self.reference(name), value).into()
+    }
+}
+
+impl MembersBuilder {
+    fn new() -> Self {
+        Self {
+            members: Default::default(),
+            names: Default::default(),
+            annotations: Default::default(),
+            checks: Default::default(),
+        }
+    }
+
+    fn member_with<F>(self, name: &str, target_type: &str, builder: F) -> Self
+    where
+        F: FnOnce(MemberBuilder) -> MemberBuilder,
+    {
+        let mut self_mut = self;
+        let (member, checks) = MemberBuilder::build(name, target_type, builder);
+        self_mut.checks.merge(checks);
+        self_mut.add_member(Member::new_definition(member))
+    }
+
+    fn member_ref(self, property: &str) -> Self {
+        let mut self_mut = self;
+        let property = self_mut.checks.reference(property);
+        self_mut.add_member(Member::new_reference(property))
+    }
+
+    fn add_member(self, member: Member) -> Self {
+        let mut self_mut = self;
+        self_mut.unique_name(member.name());
+        self_mut.members.push(member);
+        self_mut
+    }
+
+    fn unique_name(&mut self, name: &Identifier) {
+        if !self.names.insert(name.clone()) {
+            self.checks.fail(duplicate_error(duplicate_member, name));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The duplicate diagnostics all expect source locations, a built module has none so the
+/// labels are replaced with a note naming the duplicate.
+///
+fn duplicate_error<S>(diagnostic: fn(FileId, Span, Span) -> Diagnostic, name: &S) -> Error
+where
+    S: std::fmt::Display,
+{
+    let mut diagnostic = diagnostic(0, 0..0, 0..0);
+    diagnostic.labels.clear();
+    diagnostic
+        .with_notes(vec![format!("name: `{name}`")])
+        .into()
+}
+
+fn annotation_body(annotations: &mut Vec<Annotation>) -> Option<AnnotationOnlyBody> {
+    if annotations.is_empty() {
+        None
+    } else {
+        Some(AnnotationOnlyBody::from(std::mem::take(annotations)))
+    }
+}
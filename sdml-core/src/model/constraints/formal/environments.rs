@@ -22,7 +22,7 @@ pub struct EnvironmentDef {
     body: EnvironmentDefBody,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum EnvironmentDefBody {
     Function(FunctionDef),
@@ -38,6 +38,8 @@ impl_has_body_for!(EnvironmentDef, EnvironmentDefBody);
 
 impl_has_name_for!(EnvironmentDef);
 
+impl_structural_eq_for!(EnvironmentDef => name, body);
+
 impl_has_source_span_for!(EnvironmentDef);
 
 impl EnvironmentDef {
@@ -53,7 +53,7 @@ pub struct FunctionType {
 }
 
 /// Corresponds to the grammar rule `cardinality`.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct FunctionCardinality {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -66,14 +66,14 @@ pub struct FunctionCardinality {
     range: Option<CardinalityRange>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct FunctionTypeReference {
     optional: bool,
     inner: FunctionTypeReferenceInner,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum FunctionTypeReferenceInner {
     Wildcard,
@@ -88,6 +88,8 @@ pub enum FunctionTypeReferenceInner {
 
 impl_has_body_for!(FunctionDef, ConstraintSentence);
 
+impl_structural_eq_for!(FunctionDef => signature, body);
+
 impl_has_source_span_for!(FunctionDef);
 
 impl FunctionDef {
@@ -108,10 +110,13 @@ impl FunctionDef {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub signature, set_signature => FunctionSignature);
+    getter!(pub signature => mut signature_mut, FunctionSignature);
 }
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(FunctionSignature => parameters, target_type);
+
 impl_has_source_span_for!(FunctionSignature);
 
 impl FunctionSignature {
@@ -143,12 +148,15 @@ impl FunctionSignature {
     );
 
     get_and_set!(pub target_type, set_target_type => FunctionType);
+    getter!(pub target_type => mut target_type_mut, FunctionType);
 }
 
 // ------------------------------------------------------------------------------------------------
 
 impl_has_name_for!(FunctionParameter);
 
+impl_structural_eq_for!(FunctionParameter => name, target_type);
+
 impl_has_source_span_for!(FunctionParameter);
 
 impl FunctionParameter {
@@ -169,10 +177,13 @@ impl FunctionParameter {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub target_type, set_target_type => FunctionType);
+    getter!(pub target_type => mut target_type_mut, FunctionType);
 }
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(FunctionType => target_cardinality, target_type);
+
 impl_has_source_span_for!(FunctionType);
 
 impl FunctionType {
@@ -210,6 +221,7 @@ impl FunctionType {
     }
 
     get_and_set!(pub target_cardinality, set_target_cardinality => FunctionCardinality);
+    getter!(pub target_cardinality => mut target_cardinality_mut, FunctionCardinality);
 
     pub fn with_target_type(self, target_type: FunctionTypeReference) -> Self {
         Self {
@@ -219,6 +231,7 @@ impl FunctionType {
     }
 
     get_and_set!(pub target_type, set_target_type => FunctionTypeReference);
+    getter!(pub target_type => mut target_type_mut, FunctionTypeReference);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -241,6 +254,8 @@ impl Display for FunctionCardinality {
     }
 }
 
+impl_structural_eq_for!(FunctionCardinality => ordering, uniqueness, range);
+
 impl_has_source_span_for!(FunctionCardinality);
 
 impl Validate for FunctionCardinality {
@@ -413,6 +428,10 @@ impl FunctionTypeReference {
     pub fn inner(&self) -> &FunctionTypeReferenceInner {
         &self.inner
     }
+
+    pub fn inner_mut(&mut self) -> &mut FunctionTypeReferenceInner {
+        &mut self.inner
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
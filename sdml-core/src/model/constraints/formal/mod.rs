@@ -1,8 +1,12 @@
 use crate::{
     load::ModuleLoader,
-    model::{check::Validate, modules::Module, References, Span},
+    model::{
+        check::Validate, identifiers::IdentifierReference, modules::Module, HasBody, References,
+        Span,
+    },
     store::ModuleStore,
 };
+use std::collections::HashSet;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,9 +31,27 @@ pub struct FormalConstraint {
 
 impl_has_body_for!(FormalConstraint, ConstraintSentence);
 
+impl_structural_eq_for!(FormalConstraint => environment, body);
+
 impl_has_source_span_for!(FormalConstraint);
 
-impl References for FormalConstraint {}
+///
+/// The types referenced by a formal constraint are the qualified identifiers used as terms, or
+/// as members of sequence values, in the constraint body and environment. Unqualified
+/// identifiers are not included as, within a constraint, these are most often variable names.
+///
+impl References for FormalConstraint {
+    fn referenced_types<'a>(&'a self, names: &mut HashSet<&'a IdentifierReference>) {
+        for defn in &self.environment {
+            match defn.body() {
+                EnvironmentDefBody::Function(v) => sentence_references(v.body(), names),
+                EnvironmentDefBody::Value(v) => value_references(v, names),
+                EnvironmentDefBody::Sentence(v) => sentence_references(v, names),
+            }
+        }
+        sentence_references(&self.body, names);
+    }
+}
 
 impl Validate for FormalConstraint {
     fn validate(
@@ -87,6 +109,72 @@ impl FormalConstraint {
     );
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sentence_references<'a>(
+    sentence: &'a ConstraintSentence,
+    names: &mut HashSet<&'a IdentifierReference>,
+) {
+    match sentence {
+        ConstraintSentence::Simple(SimpleSentence::Atomic(v)) => {
+            term_references(v.predicate(), names);
+            v.arguments().for_each(|arg| term_references(arg, names));
+        }
+        ConstraintSentence::Simple(SimpleSentence::Equation(v)) => {
+            term_references(v.left_operand(), names);
+            term_references(v.right_operand(), names);
+        }
+        ConstraintSentence::Simple(SimpleSentence::Inequation(v)) => {
+            term_references(v.left_operand(), names);
+            term_references(v.right_operand(), names);
+        }
+        ConstraintSentence::Boolean(BooleanSentence::Unary(v)) => {
+            sentence_references(v.operand(), names);
+        }
+        ConstraintSentence::Boolean(BooleanSentence::Binary(v)) => {
+            sentence_references(v.left_operand(), names);
+            sentence_references(v.right_operand(), names);
+        }
+        ConstraintSentence::Quantified(v) => quantified_references(v, names),
+    }
+}
+
+fn quantified_references<'a>(
+    sentence: &'a QuantifiedSentence,
+    names: &mut HashSet<&'a IdentifierReference>,
+) {
+    if let Some(binding) = sentence.binding().binding() {
+        term_references(binding.source(), names);
+    }
+    sentence_references(sentence.body(), names);
+}
+
+fn term_references<'a>(term: &'a Term, names: &mut HashSet<&'a IdentifierReference>) {
+    match term {
+        Term::Sequence(v) => quantified_references(v.body(), names),
+        Term::Function(v) => {
+            term_references(v.function(), names);
+            v.arguments().for_each(|arg| term_references(arg, names));
+        }
+        Term::Identifier(v) if v.is_qualified_identifier() => {
+            names.insert(v);
+        }
+        Term::Value(v) => value_references(v, names),
+        Term::Composition(_) | Term::Identifier(_) | Term::ReservedSelf => {}
+    }
+}
+
+fn value_references<'a>(value: &'a PredicateValue, names: &mut HashSet<&'a IdentifierReference>) {
+    if let PredicateValue::Sequence(sequence) = value {
+        names.extend(sequence.iter().filter_map(|member| match member {
+            PredicateSequenceMember::Reference(v) if v.is_qualified_identifier() => Some(v),
+            _ => None,
+        }));
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
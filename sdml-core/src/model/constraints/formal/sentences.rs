@@ -29,7 +29,7 @@ use serde::{Deserialize, Serialize};
 /// A `ConstraintSentence` is either a [`SimpleSentence`], a [`BooleanSentence`], or
 /// a [`QuantifiedSentence`].
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum ConstraintSentence {
     Simple(SimpleSentence),
@@ -42,7 +42,7 @@ pub enum ConstraintSentence {
 ///
 /// A `SimpleSentence` is either an [`AtomicSentence`] or an [`Equation`].
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum SimpleSentence {
     /// Corresponds to the choice `atomic_sentence`.
@@ -87,7 +87,7 @@ pub struct Inequation {
     right_operand: Term,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum InequalityRelation {
     NotEqual,
@@ -104,7 +104,7 @@ pub enum InequalityRelation {
 /// conjunction (and), disjunction (or), exclusive disjunction (xor), implication, or
 /// biconditional.
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum BooleanSentence {
     Unary(UnaryBooleanSentence),
@@ -137,7 +137,7 @@ pub struct BinaryBooleanSentence {
     right_operand: Box<ConstraintSentence>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum ConnectiveOperator {
     /// Corresponds to the grammar rule `negation`. Uses the prefix keyword **`not`**
@@ -186,11 +186,12 @@ pub struct QuantifiedVariableBinding {
     binding: Option<QuantifiedVariable>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Quantifier {
     /// Corresponds to the grammar rule `universal`. Introduced with the keyword **`forall`**
     /// or the operator $\forall$.
+    #[default]
     Universal,
     /// Corresponds to the grammar rule `existential`. Introduced with the keyword **`exists`**
     /// or the operator $\exists$.
@@ -279,6 +280,8 @@ impl SimpleSentence {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(AtomicSentence => predicate, arguments);
+
 impl_has_source_span_for!(AtomicSentence);
 
 impl AtomicSentence {
@@ -314,6 +317,7 @@ impl AtomicSentence {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub predicate, set_predicate => Term);
+    getter!(pub predicate => mut predicate_mut, Term);
 
     get_and_set_vec!(
         pub
@@ -329,6 +333,8 @@ impl AtomicSentence {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(Equation => left_operand, right_operand);
+
 impl_has_source_span_for!(Equation);
 
 impl Equation {
@@ -353,12 +359,16 @@ impl Equation {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub left_operand, set_left_operand => Term);
+    getter!(pub left_operand => mut left_operand_mut, Term);
 
     get_and_set!(pub right_operand, set_right_operand => Term);
+    getter!(pub right_operand => mut right_operand_mut, Term);
 }
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(Inequation => left_operand, relation, right_operand);
+
 impl_has_source_span_for!(Inequation);
 
 impl Inequation {
@@ -437,6 +447,7 @@ impl Inequation {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub left_operand, set_left_operand => Term);
+    getter!(pub left_operand => mut left_operand_mut, Term);
 
     get_and_set!(pub relation, set_relation => InequalityRelation);
 
@@ -466,6 +477,7 @@ impl Inequation {
     }
 
     get_and_set!(pub right_operand, set_right_operand => Term);
+    getter!(pub right_operand => mut right_operand_mut, Term);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -535,6 +547,8 @@ impl BooleanSentence {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(UnaryBooleanSentence => operand);
+
 impl_has_source_span_for!(UnaryBooleanSentence);
 
 impl UnaryBooleanSentence {
@@ -570,10 +584,13 @@ impl UnaryBooleanSentence {
     }
 
     get_and_set!(pub operand, set_operand => boxed ConstraintSentence);
+    getter!(pub operand => mut operand_mut, ConstraintSentence);
 }
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(BinaryBooleanSentence => left_operand, operator, right_operand);
+
 impl_has_source_span_for!(BinaryBooleanSentence);
 
 impl BinaryBooleanSentence {
@@ -653,10 +670,12 @@ impl BinaryBooleanSentence {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub left_operand, set_left_operand => boxed ConstraintSentence);
+    getter!(pub left_operand => mut left_operand_mut, ConstraintSentence);
 
     get_and_set!(pub operator, set_operator => ConnectiveOperator);
 
     get_and_set!(pub right_operand, set_right_operand => boxed ConstraintSentence);
+    getter!(pub right_operand => mut right_operand_mut, ConstraintSentence);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -690,6 +709,8 @@ impl Display for ConnectiveOperator {
 
 impl_has_body_for!(QuantifiedSentence, boxed ConstraintSentence);
 
+impl_structural_eq_for!(QuantifiedSentence => binding, body);
+
 impl_has_source_span_for!(QuantifiedSentence);
 
 impl QuantifiedSentence {
@@ -713,16 +734,11 @@ impl QuantifiedSentence {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub binding, set_binding => QuantifiedVariableBinding);
+    getter!(pub binding => mut binding_mut, QuantifiedVariableBinding);
 }
 
 // ------------------------------------------------------------------------------------------------
 
-impl Default for Quantifier {
-    fn default() -> Self {
-        Self::Universal
-    }
-}
-
 impl Display for Quantifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -755,6 +771,8 @@ impl FromStr for Quantifier {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(QuantifiedVariableBinding => quantifier, binding);
+
 impl_has_source_span_for!(QuantifiedVariableBinding);
 
 impl QuantifiedVariableBinding {
@@ -806,6 +824,8 @@ impl QuantifiedVariableBinding {
         self.binding.as_ref()
     }
 
+    getter!(pub binding => optional mut binding_mut, QuantifiedVariable);
+
     pub fn is_bound_to_variable(&self) -> bool {
         self.binding.is_some()
     }
@@ -825,6 +845,8 @@ impl QuantifiedVariableBinding {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(QuantifiedVariable => name, source);
+
 impl_has_source_span_for!(QuantifiedVariable);
 
 impl_has_name_for!(QuantifiedVariable);
@@ -850,4 +872,5 @@ impl QuantifiedVariable {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub source, set_source => into Term);
+    getter!(pub source => mut source_mut, Term);
 }
@@ -1,5 +1,6 @@
 use crate::model::{constraints::QuantifiedSentence, identifiers::Identifier, Span};
 use std::collections::HashSet;
+use std::hash::Hash;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -18,7 +19,7 @@ pub struct SequenceBuilder {
     body: QuantifiedSentence,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Variables {
     Named(NamedVariables),
@@ -48,6 +49,8 @@ pub struct MappingVariable {
 
 impl_has_body_for!(SequenceBuilder, QuantifiedSentence);
 
+impl_structural_eq_for!(SequenceBuilder => variables, body);
+
 impl_has_source_span_for!(SequenceBuilder);
 
 impl SequenceBuilder {
@@ -109,6 +112,23 @@ impl Variables {
 
 // ------------------------------------------------------------------------------------------------
 
+impl PartialEq for NamedVariables {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names
+    }
+}
+
+impl Eq for NamedVariables {}
+
+impl Hash for NamedVariables {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // ignore: self.span.hash(state);
+        let mut names: Vec<&Identifier> = self.names.iter().collect();
+        names.sort();
+        names.hash(state);
+    }
+}
+
 impl_has_source_span_for!(NamedVariables);
 
 impl FromIterator<Identifier> for NamedVariables {
@@ -146,6 +166,8 @@ impl NamedVariables {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(MappingVariable => domain, range);
+
 impl_has_source_span_for!(MappingVariable);
 
 impl MappingVariable {
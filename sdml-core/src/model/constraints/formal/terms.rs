@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 // ------------------------------------------------------------------------------------------------
 
 /// Corresponds to the grammar rule `term`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Term {
     Sequence(Box<SequenceBuilder>),
@@ -50,7 +50,7 @@ pub struct FunctionComposition {
 }
 
 /// Corresponds to the field `subject` in the grammar rule `name`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Subject {
     /// Corresponds to the grammar rule `reserved_self`, or the keyword **`self`**.
@@ -144,6 +144,8 @@ impl Term {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(FunctionComposition => subject, function_names);
+
 impl_has_source_span_for!(FunctionComposition);
 
 impl FunctionComposition {
@@ -212,6 +214,8 @@ impl Subject {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(FunctionalTerm => function, arguments);
+
 impl_has_source_span_for!(FunctionalTerm);
 
 impl FunctionalTerm {
@@ -247,6 +251,7 @@ impl FunctionalTerm {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub function, set_function => Term);
+    getter!(pub function => mut function_mut, Term);
 
     get_and_set_vec!(
         pub
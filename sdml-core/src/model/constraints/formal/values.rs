@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 // Public Types ❱ Formal Constraints ❱ Terms ❱ Values
 // ------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum PredicateValue {
     Simple(SimpleValue),
@@ -25,7 +25,7 @@ pub struct SequenceOfPredicateValues {
     values: Vec<PredicateSequenceMember>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum PredicateSequenceMember {
     Simple(SimpleValue),
@@ -91,6 +91,8 @@ impl FromIterator<PredicateSequenceMember> for SequenceOfPredicateValues {
     }
 }
 
+impl_structural_eq_for!(SequenceOfPredicateValues => values);
+
 impl_has_source_span_for!(SequenceOfPredicateValues);
 
 impl_as_sequence!(pub SequenceOfPredicateValues => PredicateSequenceMember);
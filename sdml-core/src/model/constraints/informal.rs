@@ -83,6 +83,8 @@ impl From<String> for ControlledLanguageString {
     }
 }
 
+impl_structural_eq_for!(ControlledLanguageString => value, language);
+
 impl_has_source_span_for!(ControlledLanguageString);
 
 impl References for ControlledLanguageString {}
@@ -163,19 +165,13 @@ impl AsRef<str> for ControlledLanguageTag {
     }
 }
 
-impl PartialEq for ControlledLanguageTag {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
-    }
-}
-
 impl PartialEq<str> for ControlledLanguageTag {
     fn eq(&self, other: &str) -> bool {
         self.value == other
     }
 }
 
-impl Eq for ControlledLanguageTag {}
+impl_structural_eq_for!(ControlledLanguageTag => value);
 
 impl_has_source_span_for!(ControlledLanguageTag);
 
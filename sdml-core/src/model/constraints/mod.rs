@@ -32,8 +32,9 @@ pub struct Constraint {
 /// The domain of discourse, $\mathbb{D}$, is the set of all definitions present in the current
 /// module and the set of modules transitively imported by it.
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[allow(clippy::large_enum_variant)]
 pub enum ConstraintBody {
     /// Corresponds to the grammar rule `informal_constraint`.
     Informal(ControlledLanguageString),
@@ -49,6 +50,8 @@ impl_has_body_for!(Constraint, ConstraintBody);
 
 impl_has_name_for!(Constraint);
 
+impl_structural_eq_for!(Constraint => name, body);
+
 impl_has_source_span_for!(Constraint);
 
 impl_references_for!(Constraint => delegate body);
@@ -63,7 +63,7 @@ pub struct TypeClassReference {
 }
 
 /// Corresponds to the grammar rule `type_class_arguments`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum TypeClassArgument {
     Wildcard,
@@ -104,6 +104,8 @@ impl_has_name_for!(TypeClassDef);
 
 impl_has_optional_body_for!(TypeClassDef, TypeClassBody);
 
+impl_structural_eq_for!(TypeClassDef => name, variables, body);
+
 impl_has_source_span_for!(TypeClassDef);
 
 impl_annotation_builder!(TypeClassDef, optional body);
@@ -175,6 +177,8 @@ impl TypeClassDef {
 
 impl_has_name_for!(TypeVariable);
 
+impl_structural_eq_for!(TypeVariable => name, cardinality, restrictions);
+
 impl_has_source_span_for!(TypeVariable);
 
 impl TypeVariable {
@@ -213,6 +217,7 @@ impl TypeVariable {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub cardinality, set_cardinality, unset_cardinality => optional has_cardinality, FunctionCardinality);
+    getter!(pub cardinality => optional mut cardinality_mut, FunctionCardinality);
 
     get_and_set_vec!(
         pub
@@ -228,6 +233,8 @@ impl TypeVariable {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(TypeClassReference => name, arguments);
+
 impl_has_source_span_for!(TypeClassReference);
 
 impl TypeClassReference {
@@ -258,6 +265,7 @@ impl TypeClassReference {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub name, set_name => IdentifierReference);
+    getter!(pub name => mut name_mut, IdentifierReference);
 
     get_and_set_vec!(
         pub
@@ -285,6 +293,8 @@ impl TypeClassArgument {
 
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(TypeClassBody => annotations, methods);
+
 impl_has_source_span_for!(TypeClassBody);
 
 impl_has_annotations_for!(TypeClassBody);
@@ -328,6 +338,8 @@ impl_has_name_for!(MethodDef);
 
 impl_has_optional_body_for!(MethodDef, ConstraintSentence);
 
+impl_structural_eq_for!(MethodDef => name, signature, body, annotations);
+
 impl_has_source_span_for!(MethodDef);
 
 impl MethodDef {
@@ -357,4 +369,5 @@ impl MethodDef {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub signature, set_signature => FunctionSignature);
+    getter!(pub signature => mut signature_mut, FunctionSignature);
 }
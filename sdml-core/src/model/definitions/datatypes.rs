@@ -40,6 +40,8 @@ pub struct DatatypeDef {
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(DatatypeDef => name, opaque, base_type, body);
+
 impl_has_source_span_for!(DatatypeDef);
 
 impl_has_name_for!(DatatypeDef);
@@ -156,4 +158,5 @@ impl DatatypeDef {
     get_and_set_bool!(pub opaque, is_opaque, set_opaque);
 
     get_and_set!(pub base_type, set_base_type => IdentifierReference);
+    getter!(pub base_type => mut base_type_mut, IdentifierReference);
 }
@@ -52,6 +52,8 @@ impl_has_name_for!(EntityDef);
 
 impl_has_optional_body_for!(EntityDef, EntityBody);
 
+impl_structural_eq_for!(EntityDef => name, body);
+
 impl_has_source_span_for!(EntityDef);
 
 impl_references_for!(EntityDef => delegate optional body);
@@ -96,6 +98,8 @@ impl_has_annotations_for!(EntityBody);
 
 impl_has_members_for!(EntityBody);
 
+impl_structural_eq_for!(EntityBody => identity, annotations, members);
+
 impl_has_source_span_for!(EntityBody);
 
 impl_maybe_incomplete_for!(EntityBody; over members);
@@ -148,4 +152,5 @@ impl EntityBody {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub identity, set_identity => Member);
+    getter!(pub identity => mut identity_mut, Member);
 }
@@ -63,6 +63,8 @@ impl_has_name_for!(EnumDef);
 
 impl_has_optional_body_for!(EnumDef, EnumBody);
 
+impl_structural_eq_for!(EnumDef => name, body);
+
 impl_has_source_span_for!(EnumDef);
 
 impl_maybe_incomplete_for!(EnumDef; exists body);
@@ -109,6 +111,8 @@ impl EnumDef {
 
 impl_has_annotations_for!(EnumBody);
 
+impl_structural_eq_for!(EnumBody => annotations, variants);
+
 impl_has_source_span_for!(EnumBody);
 
 impl_has_variants_for!(EnumBody, ValueVariant);
@@ -129,6 +133,8 @@ impl_has_name_for!(ValueVariant);
 
 impl_has_optional_body_for!(ValueVariant);
 
+impl_structural_eq_for!(ValueVariant => name, body);
+
 impl_has_source_span_for!(ValueVariant);
 
 impl_annotation_builder!(ValueVariant, optional body);
@@ -36,6 +36,8 @@ impl_has_optional_body_for!(EventDef, StructureBody);
 
 impl_references_for!(EventDef => delegate optional body);
 
+impl_structural_eq_for!(EventDef => name, event_source, body);
+
 impl_has_source_span_for!(EventDef);
 
 impl_maybe_incomplete_for!(EventDef);
@@ -78,4 +80,5 @@ impl EventDef {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub event_source, set_event_source => IdentifierReference);
+    getter!(pub event_source => mut event_source_mut, IdentifierReference);
 }
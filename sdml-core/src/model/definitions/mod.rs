@@ -55,7 +55,7 @@ pub trait HasVariants {
 // ------------------------------------------------------------------------------------------------
 
 /// Corresponds to the grammar rule `type_def`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Definition {
     Datatype(DatatypeDef),
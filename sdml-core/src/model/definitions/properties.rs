@@ -30,6 +30,8 @@ impl From<MemberDef> for PropertyDef {
     }
 }
 
+impl_structural_eq_for!(PropertyDef => member);
+
 impl_has_source_span_for!(PropertyDef);
 
 impl_references_for!(PropertyDef => delegate member);
@@ -83,4 +85,5 @@ impl PropertyDef {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub member, member_def, set_member_def => MemberDef);
+    getter!(pub member => mut member_def_mut, MemberDef);
 }
@@ -37,6 +37,8 @@ impl_has_name_for!(RdfDef);
 
 impl_has_body_for!(RdfDef, AnnotationOnlyBody);
 
+impl_structural_eq_for!(RdfDef => name, body);
+
 impl_has_source_span_for!(RdfDef);
 
 impl_references_for!(RdfDef => delegate body);
@@ -47,6 +47,8 @@ impl_has_name_for!(StructureDef);
 
 impl_has_optional_body_for!(StructureDef, StructureBody);
 
+impl_structural_eq_for!(StructureDef => name, body);
+
 impl_has_source_span_for!(StructureDef);
 
 impl_references_for!(StructureDef => delegate optional body);
@@ -91,6 +93,8 @@ impl_has_annotations_for!(StructureBody);
 
 impl_has_members_for!(StructureBody);
 
+impl_structural_eq_for!(StructureBody => annotations, members);
+
 impl_has_source_span_for!(StructureBody);
 
 impl_maybe_incomplete_for!(StructureBody; over members);
@@ -64,6 +64,8 @@ impl_has_name_for!(UnionDef);
 
 impl_has_optional_body_for!(UnionDef, UnionBody);
 
+impl_structural_eq_for!(UnionDef => name, body);
+
 impl_has_source_span_for!(UnionDef);
 
 impl_maybe_incomplete_for!(UnionDef; exists body);
@@ -118,6 +120,8 @@ impl UnionDef {
 
 impl_has_annotations_for!(UnionBody);
 
+impl_structural_eq_for!(UnionBody => annotations, variants);
+
 impl_has_source_span_for!(UnionBody);
 
 impl_has_variants_for!(UnionBody, TypeVariant);
@@ -146,6 +150,8 @@ impl_has_name_reference_for!(TypeVariant);
 
 impl_has_optional_body_for!(TypeVariant);
 
+impl_structural_eq_for!(TypeVariant => name_reference, rename, body);
+
 impl_has_source_span_for!(TypeVariant);
 
 impl_annotation_builder!(TypeVariant, optional body);
@@ -212,6 +218,7 @@ impl TypeVariant {
     }
 
     get_and_set!(pub name_reference, set_name_reference => IdentifierReference);
+    getter!(pub name_reference => mut name_reference_mut, IdentifierReference);
 
     get_and_set!(pub rename, set_rename, unset_rename => optional has_rename, Identifier);
 
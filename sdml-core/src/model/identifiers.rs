@@ -449,6 +449,10 @@ impl Eq for IdentifierReference {}
 impl Hash for IdentifierReference {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         core::mem::discriminant(self).hash(state);
+        match self {
+            Self::Identifier(v) => v.hash(state),
+            Self::QualifiedIdentifier(v) => v.hash(state),
+        }
     }
 }
 
@@ -0,0 +1,294 @@
+/*!
+Provide the lookup of model elements by their position in the source of a module.
+
+Editor tooling usually has a cursor position as a line and column, and needs the model element
+at that position. [`Module::element_at`] takes a byte offset into the module's source and
+returns the innermost [`ModelElement`] whose source span contains it, while
+[`Module::element_at_position`] first converts a line and column, counted in either UTF-8 bytes
+or UTF-16 code units, into a byte offset using the source held by a [`ModuleLoader`].
+
+Only modules parsed from source have spans, a module built in code will never contain an
+element at any position.
+
+# Example
+
+```rust
+use sdml_core::model::lookup::{ModelElement, PositionEncoding};
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
+use sdml_core::load::ModuleLoader;
+
+fn describe(module: &Module, loader: &impl ModuleLoader, line: usize, column: usize) -> String {
+    match module.element_at_position(loader, line, column, PositionEncoding::Utf16) {
+        Some(ModelElement::Definition(defn)) => format!("definition {}", defn.name()),
+        Some(ModelElement::IdentifierReference(name)) => format!("reference to {name}"),
+        Some(element) => format!("{element:?}"),
+        None => String::new(),
+    }
+}
+```
+
+*/
+
+use crate::error::Error;
+use crate::load::ModuleLoader;
+use crate::model::annotations::Annotation;
+use crate::model::definitions::{Definition, TypeVariant, ValueVariant};
+use crate::model::identifiers::{IdentifierReference, QualifiedIdentifier};
+use crate::model::members::{Member, MemberKind};
+use crate::model::modules::{Module, ModuleImport};
+use crate::model::values::{SimpleValue, Value};
+use crate::model::walk::visit::{
+    walk_annotation, walk_definition, walk_member, walk_module, walk_type_variant, walk_value,
+    walk_value_variant, Visitor,
+};
+use crate::model::{HasSourceSpan, Span};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A reference to an element of a module, returned by [`Module::element_at`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelElement<'a> {
+    Module(&'a Module),
+    ModuleImport(&'a ModuleImport),
+    MemberImport(&'a QualifiedIdentifier),
+    Definition(&'a Definition),
+    Member(&'a Member),
+    ValueVariant(&'a ValueVariant),
+    TypeVariant(&'a TypeVariant),
+    Annotation(&'a Annotation),
+    Value(&'a Value),
+    IdentifierReference(&'a IdentifierReference),
+}
+
+///
+/// The unit in which a column is counted, editors using the Language Server Protocol will
+/// commonly use UTF-16 code units.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PositionEncoding {
+    /// Columns are counted in bytes of the UTF-8 encoded source.
+    #[default]
+    Utf8,
+    /// Columns are counted in UTF-16 code units.
+    Utf16,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Convert a `line` and `column`, both starting at 1 as in [`SpanPosition`](super::SpanPosition),
+/// into a byte offset into `source`. Returns `None` if the line does not exist, if the column is
+/// past the end of the line, or if the column falls within a character.
+///
+pub fn source_offset(
+    source: &str,
+    line: usize,
+    column: usize,
+    encoding: PositionEncoding,
+) -> Option<usize> {
+    let line_start = if line == 1 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(line.checked_sub(2)?)
+            .map(|(i, _)| i + 1)?
+    };
+    let text = &source[line_start..];
+    let text = &text[..text.find('\n').unwrap_or(text.len())];
+    let column = column.checked_sub(1)?;
+
+    match encoding {
+        PositionEncoding::Utf8 => {
+            if text.is_char_boundary(column) {
+                Some(line_start + column)
+            } else {
+                None
+            }
+        }
+        PositionEncoding::Utf16 => {
+            let mut units = 0;
+            for (i, c) in text.char_indices() {
+                if units == column {
+                    return Some(line_start + i);
+                } else if units > column {
+                    return None;
+                }
+                units += c.len_utf16();
+            }
+            if units == column {
+                Some(line_start + text.len())
+            } else {
+                None
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Module {
+    ///
+    /// Return the innermost element of this module whose source span contains the byte
+    /// `offset`, or `None` if the offset is outside the module.
+    ///
+    pub fn element_at(&self, offset: usize) -> Option<ModelElement<'_>> {
+        let mut finder = ElementFinder {
+            offset,
+            found: None,
+        };
+        if finder.enter(self.source_span(), ModelElement::Module(self)) {
+            walk_module(&mut finder, self).ok()?;
+        }
+        finder.found
+    }
+
+    ///
+    /// Return the innermost element of this module at `line` and `column`, see
+    /// [`source_offset`]. The module's source is retrieved from `loader` using the module's
+    /// file identifier.
+    ///
+    pub fn element_at_position(
+        &self,
+        loader: &impl ModuleLoader,
+        line: usize,
+        column: usize,
+        encoding: PositionEncoding,
+    ) -> Option<ModelElement<'_>> {
+        let source = loader.get_source(*self.file_id()?)?;
+        let offset = source_offset(source.as_ref(), line, column, encoding)?;
+        self.element_at(offset)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct ElementFinder<'a> {
+    offset: usize,
+    found: Option<ModelElement<'a>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> ElementFinder<'a> {
+    ///
+    /// Record `element` if its span contains the offset; returns `false` if the element, and so
+    /// all of its children, are outside the offset. An element without a span is not recorded
+    /// but its children are still searched.
+    ///
+    fn enter(&mut self, span: Option<&Span>, element: ModelElement<'a>) -> bool {
+        match span {
+            Some(span) if span.byte_range().contains(&self.offset) => {
+                self.found = Some(element);
+                true
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for ElementFinder<'a> {
+    fn visit_module_import(&mut self, node: &'a ModuleImport) -> Result<(), Error> {
+        self.enter(node.source_span(), ModelElement::ModuleImport(node));
+        Ok(())
+    }
+
+    fn visit_member_import(&mut self, node: &'a QualifiedIdentifier) -> Result<(), Error> {
+        self.enter(node.source_span(), ModelElement::MemberImport(node));
+        Ok(())
+    }
+
+    fn visit_annotation(&mut self, node: &'a Annotation) -> Result<(), Error> {
+        if self.enter(node.source_span(), ModelElement::Annotation(node)) {
+            walk_annotation(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_value(&mut self, node: &'a Value) -> Result<(), Error> {
+        if self.enter(value_span(node), ModelElement::Value(node)) {
+            walk_value(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_definition(&mut self, node: &'a Definition) -> Result<(), Error> {
+        let span = match node {
+            Definition::Property(property) => property
+                .source_span()
+                .or_else(|| property.member_def().source_span()),
+            _ => node.source_span(),
+        };
+        if self.enter(span, ModelElement::Definition(node)) {
+            walk_definition(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_member(&mut self, node: &'a Member) -> Result<(), Error> {
+        if self.enter(member_span(node), ModelElement::Member(node)) {
+            walk_member(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_identity_member(&mut self, node: &'a Member) -> Result<(), Error> {
+        self.visit_member(node)
+    }
+
+    fn visit_value_variant(&mut self, node: &'a ValueVariant) -> Result<(), Error> {
+        if self.enter(node.source_span(), ModelElement::ValueVariant(node)) {
+            walk_value_variant(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_type_variant(&mut self, node: &'a TypeVariant) -> Result<(), Error> {
+        if self.enter(node.source_span(), ModelElement::TypeVariant(node)) {
+            walk_type_variant(self, node)?;
+        }
+        Ok(())
+    }
+
+    fn visit_identifier_reference(&mut self, node: &'a IdentifierReference) -> Result<(), Error> {
+        self.enter(node.source_span(), ModelElement::IdentifierReference(node));
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn member_span(member: &Member) -> Option<&Span> {
+    member.source_span().or_else(|| match member.kind() {
+        MemberKind::Reference(v) => v.source_span(),
+        MemberKind::Definition(v) => v.source_span(),
+    })
+}
+
+fn value_span(value: &Value) -> Option<&Span> {
+    match value {
+        Value::Simple(SimpleValue::String(v)) => v.source_span(),
+        Value::Simple(_) => None,
+        Value::ValueConstructor(v) => v.source_span(),
+        Value::Mapping(v) => v.source_span(),
+        Value::Reference(v) => v.source_span(),
+        Value::List(v) => v.source_span(),
+    }
+}
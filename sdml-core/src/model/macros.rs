@@ -3,6 +3,38 @@ Internal
 
 */
 
+// ------------------------------------------------------------------------------------------------
+// Public Macros ❱ traits PartialEq, Eq, Hash
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implement structural equality and hashing over the named fields, ignoring source locations.
+/// Every field must be listed, either as compared or ignored, so that adding a field to the type
+/// without updating the macro call is an error.
+///
+macro_rules! impl_structural_eq_for {
+    ($type: ty => $($field: ident),+) => {
+        impl_structural_eq_for!($type, ignore span => $($field),+);
+    };
+    ($type: ty, ignore $($ignored: ident),+ => $($field: ident),+) => {
+        impl PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                let Self { $($ignored: _,)+ $($field,)+ } = self;
+                $(*$field == other.$field)&&+
+            }
+        }
+
+        impl Eq for $type {}
+
+        impl ::std::hash::Hash for $type {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                let Self { $($ignored: _,)+ $($field,)+ } = self;
+                $($field.hash(state);)+
+            }
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Macros ❱ trait HasSourceSpan
 // ------------------------------------------------------------------------------------------------
@@ -692,6 +724,17 @@ macro_rules! getter {
         }
     };
     // --------------------------------------------------------------------------------------------
+    ($vis: vis $fieldname: ident => mut $fnname: ident, $fieldtype: ty) => {
+        $vis fn $fnname(&mut self) -> &mut $fieldtype {
+            &mut self.$fieldname
+        }
+    };
+    ($vis: vis $fieldname: ident => optional mut $fnname: ident, $fieldtype: ty) => {
+        $vis fn $fnname(&mut self) -> Option<&mut $fieldtype> {
+            self.$fieldname.as_mut()
+        }
+    };
+    // --------------------------------------------------------------------------------------------
     //($vis: vis $fieldname: ident => copy $fieldtype: ty) => {
     //    getter!($vis $fieldname => copy $fieldname, $fieldtype);
     //};
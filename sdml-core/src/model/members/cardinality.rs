@@ -24,7 +24,7 @@ pub trait HasCardinality {
 }
 
 /// Corresponds to the grammar rule `cardinality`.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Cardinality {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -47,7 +47,7 @@ pub const TYPE_ORDERED_SET_CARDINALITY: Cardinality =
     Cardinality::zero_or_more(Some(Ordering::Ordered), Some(Uniqueness::Unique));
 pub const TYPE_MAYBE_CARDINALITY: Cardinality = Cardinality::zero_or_one();
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct CardinalityRange {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -60,22 +60,24 @@ pub struct CardinalityRange {
 pub const DEFAULT_CARDINALITY_RANGE: CardinalityRange = CardinalityRange::one();
 
 /// Corresponds to the grammar rule `sequence_ordering`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Ordering {
     Ordered,
+    #[default]
     Unordered,
 }
 
 /// Corresponds to the grammar rule `sequence_uniqueness`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Uniqueness {
     Unique,
+    #[default]
     Nonunique,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PseudoSequenceType {
     Maybe,
     Bag,
@@ -120,6 +122,8 @@ impl From<CardinalityRange> for Cardinality {
     }
 }
 
+impl_structural_eq_for!(Cardinality => ordering, uniqueness, range);
+
 impl_has_source_span_for!(Cardinality);
 
 impl Validate for Cardinality {
@@ -361,6 +365,8 @@ impl From<u32> for CardinalityRange {
     }
 }
 
+impl_structural_eq_for!(CardinalityRange => min, max);
+
 impl_has_source_span_for!(CardinalityRange);
 
 impl Validate for CardinalityRange {
@@ -520,12 +526,6 @@ impl CardinalityRange {
 
 // ------------------------------------------------------------------------------------------------
 
-impl Default for Ordering {
-    fn default() -> Self {
-        Self::Unordered
-    }
-}
-
 impl Display for Ordering {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -553,12 +553,6 @@ impl FromStr for Ordering {
 
 // ------------------------------------------------------------------------------------------------
 
-impl Default for Uniqueness {
-    fn default() -> Self {
-        Self::Nonunique
-    }
-}
-
 impl Display for Uniqueness {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
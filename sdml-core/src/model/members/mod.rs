@@ -33,7 +33,7 @@ pub struct Member {
     kind: MemberKind,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum MemberKind {
     Reference(IdentifierReference),
@@ -69,6 +69,8 @@ where
     }
 }
 
+impl_structural_eq_for!(Member => kind);
+
 impl_has_source_span_for!(Member);
 
 impl MaybeIncomplete for Member {
@@ -187,6 +189,10 @@ impl Member {
         &self.kind
     }
 
+    pub fn kind_mut(&mut self) -> &mut MemberKind {
+        &mut self.kind
+    }
+
     delegate!(pub const is_definition, bool, kind);
     delegate!(pub const as_definition, Option<&MemberDef>, kind);
 
@@ -270,6 +276,8 @@ impl_has_cardinality_for!(MemberDef);
 
 impl_has_optional_body_for!(MemberDef);
 
+impl_structural_eq_for!(MemberDef => name, target_cardinality, target_type, body);
+
 impl_has_source_span_for!(MemberDef);
 
 impl_has_type_for!(MemberDef);
@@ -350,7 +358,9 @@ impl MemberDef {
 
     get_and_set!(pub name, set_name  => Identifier);
     get_and_set!(pub target_type, set_target_type  => TypeReference);
+    getter!(pub target_type => mut target_type_mut, TypeReference);
     get_and_set!(pub target_cardinality, set_target_cardinality  => Cardinality);
+    getter!(pub target_cardinality => mut target_cardinality_mut, Cardinality);
 }
 
 // ------------------------------------------------------------------------------------------------
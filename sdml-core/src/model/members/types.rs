@@ -40,7 +40,7 @@ pub trait HasType {
 // ------------------------------------------------------------------------------------------------
 
 /// Corresponds to the grammar rule `type_reference`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum TypeReference {
     Unknown,
@@ -122,20 +122,18 @@ impl Validate for TypeReference {
         match self {
             TypeReference::Unknown => {}
             TypeReference::Type(name) => match find_definition(name, top, cache) {
-                None => {
-                    if !name
-                        .as_identifier()
-                        .map(is_builtin_type_name)
-                        .unwrap_or_default()
-                    {
-                        loader
-                            .report(&type_definition_not_found(
-                                top.file_id().copied().unwrap_or_default(),
-                                name.source_span().as_ref().map(|span| (*span).into()),
-                                name,
-                            ))
-                            .unwrap()
-                    }
+                None if !name
+                    .as_identifier()
+                    .map(is_builtin_type_name)
+                    .unwrap_or_default() =>
+                {
+                    loader
+                        .report(&type_definition_not_found(
+                            top.file_id().copied().unwrap_or_default(),
+                            name.source_span().as_ref().map(|span| (*span).into()),
+                            name,
+                        ))
+                        .unwrap()
                 }
                 Some(Definition::TypeClass(_)) => loader
                     .report(&type_class_incompatible_usage(
@@ -151,17 +149,13 @@ impl Validate for TypeReference {
                         name,
                     ))
                     .unwrap(),
-                Some(Definition::Rdf(defn)) => {
-                    if !(defn.is_datatype() || defn.is_class()) {
-                        loader
-                            .report(&rdf_definition_incompatible_usage(
-                                top.file_id().copied().unwrap_or_default(),
-                                name.source_span().as_ref().map(|span| (*span).into()),
-                                name,
-                            ))
-                            .unwrap()
-                    }
-                }
+                Some(Definition::Rdf(defn)) if !(defn.is_datatype() || defn.is_class()) => loader
+                    .report(&rdf_definition_incompatible_usage(
+                        top.file_id().copied().unwrap_or_default(),
+                        name.source_span().as_ref().map(|span| (*span).into()),
+                        name,
+                    ))
+                    .unwrap(),
                 _ => {}
             },
             TypeReference::MappingType(v) => v.validate(top, cache, loader, check_constraints),
@@ -197,6 +191,8 @@ impl Display for MappingType {
     }
 }
 
+impl_structural_eq_for!(MappingType => domain, range);
+
 impl_has_source_span_for!(MappingType);
 
 impl References for MappingType {
@@ -251,5 +247,7 @@ impl MappingType {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub domain, set_domain => boxed into TypeReference);
+    getter!(pub domain => mut domain_mut, TypeReference);
     get_and_set!(pub range, set_range => boxed into TypeReference);
+    getter!(pub range => mut range_mut, TypeReference);
 }
@@ -313,6 +313,8 @@ impl SpanPosition {
 
 pub mod annotations;
 
+pub mod builder;
+
 pub mod check;
 
 pub mod constraints;
@@ -321,6 +323,8 @@ pub mod definitions;
 
 pub mod identifiers;
 
+pub mod lookup;
+
 pub mod members;
 
 pub mod modules;
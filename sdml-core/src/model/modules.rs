@@ -13,7 +13,7 @@ use crate::model::{
     identifiers::{Identifier, IdentifierReference, QualifiedIdentifier},
     HasBody, HasName, HasSourceSpan, Span,
 };
-use crate::store::{InMemoryModuleCache, ModuleStore};
+use crate::store::ModuleStore;
 use sdml_errors::diagnostics::functions::{
     definition_not_found, imported_module_not_found, library_definition_not_allowed,
     module_is_incomplete, module_version_info_empty, module_version_mismatch,
@@ -123,6 +123,12 @@ pub struct ModuleImport {
 // Implementations ❱ Modules
 // ------------------------------------------------------------------------------------------------
 
+impl_structural_eq_for!(
+    Module,
+    ignore source_file, file_id, span =>
+    name, base_uri, version_info, version_uri, body
+);
+
 impl_has_source_span_for!(Module);
 
 impl_has_name_for!(Module);
@@ -141,7 +147,7 @@ impl HasBody for Module {
     fn set_body(&mut self, body: Self::Body) {
         let mut body_mut = body;
         body_mut.file_id = self.file_id;
-        body_mut.set_library_status(self.name());
+        body_mut.is_library = self.body.is_library || body_mut.is_library;
         self.body = body_mut;
     }
 }
@@ -168,6 +174,20 @@ impl Module {
     pub fn new(name: Identifier, body: ModuleBody) -> Self {
         let mut body = body;
         body.set_library_status(&name);
+        Self::new_inner(name, body)
+    }
+
+    ///
+    /// Create a new library module, this module will be treated in the same way as the
+    /// standard library modules and so may contain library definitions.
+    ///
+    pub fn new_library(name: Identifier, body: ModuleBody) -> Self {
+        let mut body = body;
+        body.is_library = true;
+        Self::new_inner(name, body)
+    }
+
+    fn new_inner(name: Identifier, body: ModuleBody) -> Self {
         Self {
             source_file: None,
             file_id: None,
@@ -239,7 +259,7 @@ impl Module {
     // Module :: Pseudo-Validate
     // --------------------------------------------------------------------------------------------
 
-    pub fn is_incomplete(&self, cache: &InMemoryModuleCache) -> bool {
+    pub fn is_incomplete(&self, cache: &impl ModuleStore) -> bool {
         if !self.is_library_module() {
             self.body.is_incomplete(self, cache)
         } else {
@@ -258,7 +278,7 @@ impl Module {
     ///
     pub fn validate(
         &self,
-        cache: &InMemoryModuleCache,
+        cache: &impl ModuleStore,
         loader: &impl ModuleLoader,
         check_constraints: bool,
     ) {
@@ -292,8 +312,20 @@ impl Module {
     // Module :: Helpers
     // --------------------------------------------------------------------------------------------
 
+    ///
+    /// Returns `true` if this is a library module, either one of the standard library modules or
+    /// a module explicitly marked as a library module.
+    ///
     pub fn is_library_module(&self) -> bool {
-        Identifier::is_library_module_name(self.name().as_ref())
+        self.body.is_library
+    }
+
+    ///
+    /// Mark, or un-mark, this module as a library module. Note that a standard library module
+    /// cannot be un-marked.
+    ///
+    pub fn set_library_module(&mut self, is_library: bool) {
+        self.body.is_library = is_library || Identifier::is_library_module_name(self.name());
     }
 
     pub fn resolve_local(&self, name: &Identifier) -> Option<&Definition> {
@@ -378,6 +410,30 @@ impl<T> HeaderValue<T> {
 
 // ------------------------------------------------------------------------------------------------
 
+// Imports are compared without regard to how they are grouped into statements, so that
+// `import [ skos xsd ]` is equal to `import skos` followed by `import xsd`.
+impl PartialEq for ModuleBody {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_library == other.is_library
+            && self.flat_imports().eq(other.flat_imports())
+            && self.annotations == other.annotations
+            && self.definitions == other.definitions
+    }
+}
+
+impl Eq for ModuleBody {}
+
+impl Hash for ModuleBody {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // ignore: self.span.hash(state);
+        // ignore: self.file_id.hash(state);
+        self.is_library.hash(state);
+        self.flat_imports().for_each(|import| import.hash(state));
+        self.annotations.hash(state);
+        self.definitions.hash(state);
+    }
+}
+
 impl_has_source_span_for!(ModuleBody);
 
 impl_has_annotations_for!(ModuleBody);
@@ -427,6 +483,18 @@ impl ModuleBody {
         self.is_library = Identifier::is_library_module_name(module_name);
     }
 
+    pub fn is_library(&self) -> bool {
+        self.is_library
+    }
+
+    ///
+    /// Mark, or un-mark, this body as the body of a library module, only the body of a library
+    /// module may contain library definitions.
+    ///
+    pub fn set_library(&mut self, is_library: bool) {
+        self.is_library = is_library;
+    }
+
     // --------------------------------------------------------------------------------------------
     // ModuleBody :: Fields
     // --------------------------------------------------------------------------------------------
@@ -442,6 +510,10 @@ impl ModuleBody {
             => imports, ImportStatement
     );
 
+    fn flat_imports(&self) -> impl Iterator<Item = &Import> {
+        self.imports().flat_map(|statement| statement.imports())
+    }
+
     pub fn has_definitions(&self) -> bool {
         !self.definitions.is_empty()
     }
@@ -621,6 +693,8 @@ impl From<ImportStatement> for Vec<Import> {
     }
 }
 
+impl_structural_eq_for!(ImportStatement => imports);
+
 impl_has_source_span_for! {ImportStatement}
 
 impl Validate for ImportStatement {
@@ -865,21 +939,7 @@ impl From<Identifier> for ModuleImport {
     }
 }
 
-impl PartialEq for ModuleImport {
-    fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && self.version_uri == other.version_uri
-    }
-}
-
-impl Eq for ModuleImport {}
-
-impl Hash for ModuleImport {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        // ignore: self.span.hash(state);
-        self.name.hash(state);
-        self.version_uri.hash(state);
-    }
-}
+impl_structural_eq_for!(ModuleImport => name, version_uri);
 
 impl_has_source_span_for!(ModuleImport);
 
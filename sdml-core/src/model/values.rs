@@ -24,7 +24,7 @@ use serde::{Deserialize, Serialize};
 // ------------------------------------------------------------------------------------------------
 
 /// Corresponds to the grammar rule `value`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Value {
     Simple(SimpleValue),
@@ -35,7 +35,7 @@ pub enum Value {
 }
 
 /// Corresponds to the grammar rule `simple_value`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum SimpleValue {
     /// Corresponds to the grammar rule `boolean`.
@@ -57,7 +57,7 @@ pub enum SimpleValue {
 }
 
 /// Corresponds to the grammar rule `binary`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Binary(Vec<u8>);
 
@@ -103,7 +103,7 @@ pub struct SequenceOfValues {
 }
 
 /// Corresponds to the grammar rule `name`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum SequenceMember {
     Simple(SimpleValue),
@@ -406,13 +406,7 @@ impl From<&str> for LanguageString {
     }
 }
 
-impl PartialEq for LanguageString {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value && self.language == other.language
-    }
-}
-
-impl Eq for LanguageString {}
+impl_structural_eq_for!(LanguageString => value, language);
 
 impl_has_source_span_for!(LanguageString);
 
@@ -493,12 +487,6 @@ impl AsRef<str> for LanguageTag {
     }
 }
 
-impl PartialEq for LanguageTag {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
-    }
-}
-
 impl PartialEq<language_tags::LanguageTag> for LanguageTag {
     fn eq(&self, other: &language_tags::LanguageTag) -> bool {
         self.value == *other
@@ -511,7 +499,7 @@ impl PartialEq<str> for LanguageTag {
     }
 }
 
-impl Eq for LanguageTag {}
+impl_structural_eq_for!(LanguageTag => value);
 
 impl_has_source_span_for!(LanguageTag);
 
@@ -636,6 +624,8 @@ impl Display for MappingValue {
     }
 }
 
+impl_structural_eq_for!(MappingValue => domain, range);
+
 impl_has_source_span_for!(MappingValue);
 
 impl MappingValue {
@@ -656,8 +646,10 @@ impl MappingValue {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub domain, set_domain => into SimpleValue);
+    getter!(pub domain => mut domain_mut, SimpleValue);
 
     get_and_set!(pub range, set_range => boxed into Value);
+    getter!(pub range => mut range_mut, Value);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -693,6 +685,8 @@ impl FromIterator<SequenceMember> for SequenceOfValues {
     }
 }
 
+impl_structural_eq_for!(SequenceOfValues => ordering, uniqueness, values);
+
 impl_has_source_span_for!(SequenceOfValues);
 
 impl_as_sequence!(pub SequenceOfValues => SequenceMember);
@@ -739,6 +733,8 @@ impl Display for ValueConstructor {
     }
 }
 
+impl_structural_eq_for!(ValueConstructor => type_name, value);
+
 impl_has_source_span_for!(ValueConstructor);
 
 impl ValueConstructor {
@@ -759,6 +755,8 @@ impl ValueConstructor {
     // --------------------------------------------------------------------------------------------
 
     get_and_set!(pub type_name, set_type_name => IdentifierReference);
+    getter!(pub type_name => mut type_name_mut, IdentifierReference);
 
     get_and_set!(pub value, set_value => SimpleValue);
+    getter!(pub value => mut value_mut, SimpleValue);
 }
@@ -1,7 +1,16 @@
 /*!
 Provides the capability to walk the in-memory model of an SDML module.
 
-To use the model walker:
+Three walkers are provided:
+
+1. The simple walker, described below, with start and end callbacks for definitions and their
+   members and variants.
+2. The full walker in [`visit`], with a [`Visitor`] method for every node of the model including
+   the sentences and terms of formal constraints and the structure of values.
+3. The mutable walker in [`visit_mut`], with a [`VisitorMut`] method for every node of the model
+   that is able to rewrite nodes in place.
+
+To use the simple model walker:
 
 1. Provide a type, say `MyModuleWalker`.
 2. Provide an implementation of `SimpleModuleVisitor` for `MyModuleWalker`.
@@ -31,7 +40,7 @@ use crate::model::annotations::{Annotation, AnnotationProperty, HasAnnotations};
 use crate::model::constraints::{ConstraintBody, ControlledLanguageString, FormalConstraint};
 use crate::model::definitions::{
     DatatypeDef, Definition, EntityDef, EnumDef, EventDef, HasMembers, HasVariants, PropertyDef,
-    RdfDef, StructureDef, TypeVariant, UnionDef, ValueVariant,
+    RdfDef, StructureDef, TypeClassDef, TypeVariant, UnionDef, ValueVariant,
 };
 use crate::model::identifiers::{IdentifierReference, QualifiedIdentifier};
 use crate::model::members::{Member, MemberDef, MemberKind};
@@ -219,6 +228,7 @@ pub trait SimpleModuleVisitor {
     /// - `property_start`
     /// - `rdf_start`
     /// - `structure_start`
+    /// - `type_class_start`
     /// - `union_start`
     /// - `definition_end`
     ///
@@ -419,6 +429,31 @@ pub trait SimpleModuleVisitor {
         Ok(())
     }
 
+    ///
+    /// Called to denote the start of a `TypeClassDef` instance.
+    ///
+    /// # Nested
+    ///
+    /// - `annotation_start`
+    /// - `type_class_end`
+    ///
+    fn type_class_start(&mut self, _thing: &TypeClassDef) -> Result<bool, Error> {
+        info!("SimpleModuleWalker::type_class_start(..) -- skipped");
+        Self::INCLUDE_NESTED
+    }
+
+    ///
+    /// Called to denote the end of a `TypeClassDef` instance.
+    ///
+    /// # Nested
+    ///
+    /// None.
+    ///
+    fn type_class_end(&mut self, _thing: &TypeClassDef) -> Result<(), Error> {
+        info!("SimpleModuleWalker::type_class_end(..) -- skipped");
+        Ok(())
+    }
+
     ///
     /// Called to denote the start of an `UnionDef` instance.
     ///
@@ -680,7 +715,9 @@ pub fn walk_module_simple(
                         visit_annotations,
                         visit_members_and_variants,
                     )?,
-                    Definition::TypeClass(_) => todo!(),
+                    Definition::TypeClass(def) => {
+                        walk_type_class_def(def, walker, visit_annotations)?
+                    }
                     Definition::Union(def) => {
                         walk_union_def(def, walker, visit_annotations, visit_members_and_variants)?
                     }
@@ -828,6 +865,20 @@ fn walk_structure_def(
     Ok(())
 }
 
+fn walk_type_class_def(
+    thing: &TypeClassDef,
+    walker: &mut impl SimpleModuleVisitor,
+    visit_annotations: bool,
+) -> Result<(), Error> {
+    if walker.type_class_start(thing)? {
+        if let Some(body) = thing.body() {
+            walk_annotations!(walker, body.annotations(), visit_annotations);
+        }
+        walker.type_class_end(thing)?;
+    }
+    Ok(())
+}
+
 fn walk_union_def(
     thing: &UnionDef,
     walker: &mut impl SimpleModuleVisitor,
@@ -924,3 +975,13 @@ fn walk_type_variant(
 
     walker.type_variant_end(thing)
 }
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod visit;
+pub use visit::Visitor;
+
+pub mod visit_mut;
+pub use visit_mut::VisitorMut;
@@ -0,0 +1,977 @@
+/*!
+Provides a visitor over every node of the in-memory model of an SDML module.
+
+Each method on [`Visitor`] is called for one kind of model node and, by default, calls the
+corresponding `walk_*` function in this module to visit the node's children. An implementation
+overrides only the methods for the nodes of interest; to continue into the children of an
+overridden node call the `walk_*` function, and to skip them simply return. Nodes are visited
+with the lifetime `'ast` of the module being walked, so a visitor may keep references to them.
+
+```rust
+use sdml_core::error::Error;
+use sdml_core::model::identifiers::IdentifierReference;
+use sdml_core::model::modules::Module;
+use sdml_core::model::walk::visit::{walk_module, Visitor};
+
+#[derive(Debug, Default)]
+struct ReferenceCounter {
+    count: usize,
+}
+
+impl Visitor<'_> for ReferenceCounter {
+    fn visit_identifier_reference(&mut self, _node: &IdentifierReference) -> Result<(), Error> {
+        self.count += 1;
+        Ok(())
+    }
+}
+
+fn count_references(module: &Module) -> Result<usize, Error> {
+    let mut counter = ReferenceCounter::default();
+    walk_module(&mut counter, module)?;
+    Ok(counter.count)
+}
+```
+
+*/
+
+use crate::error::Error;
+use crate::model::annotations::{Annotation, AnnotationProperty, HasAnnotations};
+use crate::model::constraints::{
+    AtomicSentence, BinaryBooleanSentence, BooleanSentence, Constraint, ConstraintBody,
+    ConstraintSentence, ControlledLanguageString, EnvironmentDef, EnvironmentDefBody, Equation,
+    FormalConstraint, FunctionCardinality, FunctionComposition, FunctionDef, FunctionParameter,
+    FunctionSignature, FunctionType, FunctionTypeReference, FunctionTypeReferenceInner,
+    FunctionalTerm, Inequation, PredicateSequenceMember, PredicateValue, QuantifiedSentence,
+    QuantifiedVariable, QuantifiedVariableBinding, SequenceBuilder, SequenceOfPredicateValues,
+    SimpleSentence, Term, UnaryBooleanSentence,
+};
+use crate::model::definitions::{
+    DatatypeDef, Definition, EntityDef, EnumDef, EventDef, HasMembers, HasVariants, MethodDef,
+    PropertyDef, RdfDef, StructureDef, TypeClassArgument, TypeClassDef, TypeClassReference,
+    TypeVariable, TypeVariant, UnionDef, ValueVariant,
+};
+use crate::model::identifiers::{IdentifierReference, QualifiedIdentifier};
+use crate::model::members::{
+    Cardinality, MappingType, Member, MemberDef, MemberKind, TypeReference,
+};
+use crate::model::modules::{Import, ImportStatement, Module, ModuleImport};
+use crate::model::values::{
+    MappingValue, SequenceMember, SequenceOfValues, SimpleValue, Value, ValueConstructor,
+};
+use crate::model::{HasBody, HasNameReference, HasOptionalBody};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The trait implemented by a visitor of the model, see the [module documentation](self).
+///
+pub trait Visitor<'ast> {
+    // --------------------------------------------------------------------------------------------
+    // Modules
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_module(&mut self, node: &'ast Module) -> Result<(), Error> {
+        walk_module(self, node)
+    }
+
+    fn visit_import_statement(&mut self, node: &'ast ImportStatement) -> Result<(), Error> {
+        walk_import_statement(self, node)
+    }
+
+    fn visit_module_import(&mut self, _node: &'ast ModuleImport) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_member_import(&mut self, _node: &'ast QualifiedIdentifier) -> Result<(), Error> {
+        Ok(())
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Annotations and Constraints
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_annotation(&mut self, node: &'ast Annotation) -> Result<(), Error> {
+        walk_annotation(self, node)
+    }
+
+    fn visit_annotation_property(&mut self, node: &'ast AnnotationProperty) -> Result<(), Error> {
+        walk_annotation_property(self, node)
+    }
+
+    fn visit_constraint(&mut self, node: &'ast Constraint) -> Result<(), Error> {
+        walk_constraint(self, node)
+    }
+
+    fn visit_informal_constraint(
+        &mut self,
+        _node: &'ast ControlledLanguageString,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_formal_constraint(&mut self, node: &'ast FormalConstraint) -> Result<(), Error> {
+        walk_formal_constraint(self, node)
+    }
+
+    fn visit_environment_def(&mut self, node: &'ast EnvironmentDef) -> Result<(), Error> {
+        walk_environment_def(self, node)
+    }
+
+    fn visit_function_def(&mut self, node: &'ast FunctionDef) -> Result<(), Error> {
+        walk_function_def(self, node)
+    }
+
+    fn visit_function_signature(&mut self, node: &'ast FunctionSignature) -> Result<(), Error> {
+        walk_function_signature(self, node)
+    }
+
+    fn visit_function_parameter(&mut self, node: &'ast FunctionParameter) -> Result<(), Error> {
+        walk_function_parameter(self, node)
+    }
+
+    fn visit_function_type(&mut self, node: &'ast FunctionType) -> Result<(), Error> {
+        walk_function_type(self, node)
+    }
+
+    fn visit_function_cardinality(
+        &mut self,
+        _node: &'ast FunctionCardinality,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_function_type_reference(
+        &mut self,
+        node: &'ast FunctionTypeReference,
+    ) -> Result<(), Error> {
+        walk_function_type_reference(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Sentences
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_constraint_sentence(&mut self, node: &'ast ConstraintSentence) -> Result<(), Error> {
+        walk_constraint_sentence(self, node)
+    }
+
+    fn visit_simple_sentence(&mut self, node: &'ast SimpleSentence) -> Result<(), Error> {
+        walk_simple_sentence(self, node)
+    }
+
+    fn visit_atomic_sentence(&mut self, node: &'ast AtomicSentence) -> Result<(), Error> {
+        walk_atomic_sentence(self, node)
+    }
+
+    fn visit_equation(&mut self, node: &'ast Equation) -> Result<(), Error> {
+        walk_equation(self, node)
+    }
+
+    fn visit_inequation(&mut self, node: &'ast Inequation) -> Result<(), Error> {
+        walk_inequation(self, node)
+    }
+
+    fn visit_boolean_sentence(&mut self, node: &'ast BooleanSentence) -> Result<(), Error> {
+        walk_boolean_sentence(self, node)
+    }
+
+    fn visit_unary_boolean_sentence(
+        &mut self,
+        node: &'ast UnaryBooleanSentence,
+    ) -> Result<(), Error> {
+        walk_unary_boolean_sentence(self, node)
+    }
+
+    fn visit_binary_boolean_sentence(
+        &mut self,
+        node: &'ast BinaryBooleanSentence,
+    ) -> Result<(), Error> {
+        walk_binary_boolean_sentence(self, node)
+    }
+
+    fn visit_quantified_sentence(&mut self, node: &'ast QuantifiedSentence) -> Result<(), Error> {
+        walk_quantified_sentence(self, node)
+    }
+
+    fn visit_quantified_variable_binding(
+        &mut self,
+        node: &'ast QuantifiedVariableBinding,
+    ) -> Result<(), Error> {
+        walk_quantified_variable_binding(self, node)
+    }
+
+    fn visit_quantified_variable(&mut self, node: &'ast QuantifiedVariable) -> Result<(), Error> {
+        walk_quantified_variable(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Terms
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_term(&mut self, node: &'ast Term) -> Result<(), Error> {
+        walk_term(self, node)
+    }
+
+    fn visit_sequence_builder(&mut self, node: &'ast SequenceBuilder) -> Result<(), Error> {
+        walk_sequence_builder(self, node)
+    }
+
+    fn visit_functional_term(&mut self, node: &'ast FunctionalTerm) -> Result<(), Error> {
+        walk_functional_term(self, node)
+    }
+
+    fn visit_function_composition(
+        &mut self,
+        _node: &'ast FunctionComposition,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_predicate_value(&mut self, node: &'ast PredicateValue) -> Result<(), Error> {
+        walk_predicate_value(self, node)
+    }
+
+    fn visit_sequence_of_predicate_values(
+        &mut self,
+        node: &'ast SequenceOfPredicateValues,
+    ) -> Result<(), Error> {
+        walk_sequence_of_predicate_values(self, node)
+    }
+
+    fn visit_predicate_sequence_member(
+        &mut self,
+        node: &'ast PredicateSequenceMember,
+    ) -> Result<(), Error> {
+        walk_predicate_sequence_member(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Values
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_value(&mut self, node: &'ast Value) -> Result<(), Error> {
+        walk_value(self, node)
+    }
+
+    fn visit_simple_value(&mut self, _node: &'ast SimpleValue) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_value_constructor(&mut self, node: &'ast ValueConstructor) -> Result<(), Error> {
+        walk_value_constructor(self, node)
+    }
+
+    fn visit_mapping_value(&mut self, node: &'ast MappingValue) -> Result<(), Error> {
+        walk_mapping_value(self, node)
+    }
+
+    fn visit_sequence_of_values(&mut self, node: &'ast SequenceOfValues) -> Result<(), Error> {
+        walk_sequence_of_values(self, node)
+    }
+
+    fn visit_sequence_member(&mut self, node: &'ast SequenceMember) -> Result<(), Error> {
+        walk_sequence_member(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Definitions
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_definition(&mut self, node: &'ast Definition) -> Result<(), Error> {
+        walk_definition(self, node)
+    }
+
+    fn visit_datatype_def(&mut self, node: &'ast DatatypeDef) -> Result<(), Error> {
+        walk_datatype_def(self, node)
+    }
+
+    fn visit_entity_def(&mut self, node: &'ast EntityDef) -> Result<(), Error> {
+        walk_entity_def(self, node)
+    }
+
+    fn visit_enum_def(&mut self, node: &'ast EnumDef) -> Result<(), Error> {
+        walk_enum_def(self, node)
+    }
+
+    fn visit_event_def(&mut self, node: &'ast EventDef) -> Result<(), Error> {
+        walk_event_def(self, node)
+    }
+
+    fn visit_property_def(&mut self, node: &'ast PropertyDef) -> Result<(), Error> {
+        walk_property_def(self, node)
+    }
+
+    fn visit_rdf_def(&mut self, node: &'ast RdfDef) -> Result<(), Error> {
+        walk_rdf_def(self, node)
+    }
+
+    fn visit_structure_def(&mut self, node: &'ast StructureDef) -> Result<(), Error> {
+        walk_structure_def(self, node)
+    }
+
+    fn visit_type_class_def(&mut self, node: &'ast TypeClassDef) -> Result<(), Error> {
+        walk_type_class_def(self, node)
+    }
+
+    fn visit_type_variable(&mut self, node: &'ast TypeVariable) -> Result<(), Error> {
+        walk_type_variable(self, node)
+    }
+
+    fn visit_type_class_reference(&mut self, node: &'ast TypeClassReference) -> Result<(), Error> {
+        walk_type_class_reference(self, node)
+    }
+
+    fn visit_method_def(&mut self, node: &'ast MethodDef) -> Result<(), Error> {
+        walk_method_def(self, node)
+    }
+
+    fn visit_union_def(&mut self, node: &'ast UnionDef) -> Result<(), Error> {
+        walk_union_def(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Members, Variants, and Types
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_member(&mut self, node: &'ast Member) -> Result<(), Error> {
+        walk_member(self, node)
+    }
+
+    fn visit_identity_member(&mut self, node: &'ast Member) -> Result<(), Error> {
+        walk_member(self, node)
+    }
+
+    fn visit_member_def(&mut self, node: &'ast MemberDef) -> Result<(), Error> {
+        walk_member_def(self, node)
+    }
+
+    fn visit_value_variant(&mut self, node: &'ast ValueVariant) -> Result<(), Error> {
+        walk_value_variant(self, node)
+    }
+
+    fn visit_type_variant(&mut self, node: &'ast TypeVariant) -> Result<(), Error> {
+        walk_type_variant(self, node)
+    }
+
+    fn visit_type_reference(&mut self, node: &'ast TypeReference) -> Result<(), Error> {
+        walk_type_reference(self, node)
+    }
+
+    fn visit_mapping_type(&mut self, node: &'ast MappingType) -> Result<(), Error> {
+        walk_mapping_type(self, node)
+    }
+
+    fn visit_cardinality(&mut self, _node: &'ast Cardinality) -> Result<(), Error> {
+        Ok(())
+    }
+
+    ///
+    /// Called for every reference to a definition, or annotation property, by name.
+    ///
+    fn visit_identifier_reference(
+        &mut self,
+        _node: &'ast IdentifierReference,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Modules
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_module<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Module,
+) -> Result<(), Error> {
+    let body = node.body();
+    for import in body.imports() {
+        visitor.visit_import_statement(import)?;
+    }
+    walk_annotations(visitor, body)?;
+    for definition in body.definitions() {
+        visitor.visit_definition(definition)?;
+    }
+    Ok(())
+}
+
+pub fn walk_import_statement<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ImportStatement,
+) -> Result<(), Error> {
+    for import in node.imports() {
+        match import {
+            Import::Module(v) => visitor.visit_module_import(v)?,
+            Import::Member(v) => visitor.visit_member_import(v)?,
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Annotations and Constraints
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_annotations<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast impl HasAnnotations,
+) -> Result<(), Error> {
+    for annotation in node.annotations() {
+        visitor.visit_annotation(annotation)?;
+    }
+    Ok(())
+}
+
+pub fn walk_annotation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Annotation,
+) -> Result<(), Error> {
+    match node {
+        Annotation::Property(v) => visitor.visit_annotation_property(v),
+        Annotation::Constraint(v) => visitor.visit_constraint(v),
+    }
+}
+
+pub fn walk_annotation_property<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AnnotationProperty,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.name_reference())?;
+    visitor.visit_value(node.value())
+}
+
+pub fn walk_constraint<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Constraint,
+) -> Result<(), Error> {
+    match node.body() {
+        ConstraintBody::Informal(v) => visitor.visit_informal_constraint(v),
+        ConstraintBody::Formal(v) => visitor.visit_formal_constraint(v),
+    }
+}
+
+pub fn walk_formal_constraint<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FormalConstraint,
+) -> Result<(), Error> {
+    for definition in node.definitions() {
+        visitor.visit_environment_def(definition)?;
+    }
+    visitor.visit_constraint_sentence(node.body())
+}
+
+pub fn walk_environment_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EnvironmentDef,
+) -> Result<(), Error> {
+    match node.body() {
+        EnvironmentDefBody::Function(v) => visitor.visit_function_def(v),
+        EnvironmentDefBody::Value(v) => visitor.visit_predicate_value(v),
+        EnvironmentDefBody::Sentence(v) => visitor.visit_constraint_sentence(v),
+    }
+}
+
+pub fn walk_function_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionDef,
+) -> Result<(), Error> {
+    visitor.visit_function_signature(node.signature())?;
+    visitor.visit_constraint_sentence(node.body())
+}
+
+pub fn walk_function_signature<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionSignature,
+) -> Result<(), Error> {
+    for parameter in node.parameters() {
+        visitor.visit_function_parameter(parameter)?;
+    }
+    visitor.visit_function_type(node.target_type())
+}
+
+pub fn walk_function_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionParameter,
+) -> Result<(), Error> {
+    visitor.visit_function_type(node.target_type())
+}
+
+pub fn walk_function_type<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionType,
+) -> Result<(), Error> {
+    visitor.visit_function_cardinality(node.target_cardinality())?;
+    visitor.visit_function_type_reference(node.target_type())
+}
+
+pub fn walk_function_type_reference<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionTypeReference,
+) -> Result<(), Error> {
+    match node.inner() {
+        FunctionTypeReferenceInner::Wildcard => Ok(()),
+        FunctionTypeReferenceInner::Reference(v) => visitor.visit_identifier_reference(v),
+        FunctionTypeReferenceInner::MappingType(v) => visitor.visit_mapping_type(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Sentences
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_constraint_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ConstraintSentence,
+) -> Result<(), Error> {
+    match node {
+        ConstraintSentence::Simple(v) => visitor.visit_simple_sentence(v),
+        ConstraintSentence::Boolean(v) => visitor.visit_boolean_sentence(v),
+        ConstraintSentence::Quantified(v) => visitor.visit_quantified_sentence(v),
+    }
+}
+
+pub fn walk_simple_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SimpleSentence,
+) -> Result<(), Error> {
+    match node {
+        SimpleSentence::Atomic(v) => visitor.visit_atomic_sentence(v),
+        SimpleSentence::Equation(v) => visitor.visit_equation(v),
+        SimpleSentence::Inequation(v) => visitor.visit_inequation(v),
+    }
+}
+
+pub fn walk_atomic_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AtomicSentence,
+) -> Result<(), Error> {
+    visitor.visit_term(node.predicate())?;
+    for argument in node.arguments() {
+        visitor.visit_term(argument)?;
+    }
+    Ok(())
+}
+
+pub fn walk_equation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Equation,
+) -> Result<(), Error> {
+    visitor.visit_term(node.left_operand())?;
+    visitor.visit_term(node.right_operand())
+}
+
+pub fn walk_inequation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Inequation,
+) -> Result<(), Error> {
+    visitor.visit_term(node.left_operand())?;
+    visitor.visit_term(node.right_operand())
+}
+
+pub fn walk_boolean_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast BooleanSentence,
+) -> Result<(), Error> {
+    match node {
+        BooleanSentence::Unary(v) => visitor.visit_unary_boolean_sentence(v),
+        BooleanSentence::Binary(v) => visitor.visit_binary_boolean_sentence(v),
+    }
+}
+
+pub fn walk_unary_boolean_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast UnaryBooleanSentence,
+) -> Result<(), Error> {
+    visitor.visit_constraint_sentence(node.operand())
+}
+
+pub fn walk_binary_boolean_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast BinaryBooleanSentence,
+) -> Result<(), Error> {
+    visitor.visit_constraint_sentence(node.left_operand())?;
+    visitor.visit_constraint_sentence(node.right_operand())
+}
+
+pub fn walk_quantified_sentence<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast QuantifiedSentence,
+) -> Result<(), Error> {
+    visitor.visit_quantified_variable_binding(node.binding())?;
+    visitor.visit_constraint_sentence(node.body())
+}
+
+pub fn walk_quantified_variable_binding<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast QuantifiedVariableBinding,
+) -> Result<(), Error> {
+    if let Some(binding) = node.binding() {
+        visitor.visit_quantified_variable(binding)?;
+    }
+    Ok(())
+}
+
+pub fn walk_quantified_variable<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast QuantifiedVariable,
+) -> Result<(), Error> {
+    visitor.visit_term(node.source())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Terms
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_term<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Term,
+) -> Result<(), Error> {
+    match node {
+        Term::Sequence(v) => visitor.visit_sequence_builder(v),
+        Term::Function(v) => visitor.visit_functional_term(v),
+        Term::Composition(v) => visitor.visit_function_composition(v),
+        Term::Identifier(v) => visitor.visit_identifier_reference(v),
+        Term::ReservedSelf => Ok(()),
+        Term::Value(v) => visitor.visit_predicate_value(v),
+    }
+}
+
+pub fn walk_sequence_builder<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SequenceBuilder,
+) -> Result<(), Error> {
+    visitor.visit_quantified_sentence(node.body())
+}
+
+pub fn walk_functional_term<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionalTerm,
+) -> Result<(), Error> {
+    visitor.visit_term(node.function())?;
+    for argument in node.arguments() {
+        visitor.visit_term(argument)?;
+    }
+    Ok(())
+}
+
+pub fn walk_predicate_value<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PredicateValue,
+) -> Result<(), Error> {
+    match node {
+        PredicateValue::Simple(v) => visitor.visit_simple_value(v),
+        PredicateValue::Sequence(v) => visitor.visit_sequence_of_predicate_values(v),
+    }
+}
+
+pub fn walk_sequence_of_predicate_values<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SequenceOfPredicateValues,
+) -> Result<(), Error> {
+    for member in node.iter() {
+        visitor.visit_predicate_sequence_member(member)?;
+    }
+    Ok(())
+}
+
+pub fn walk_predicate_sequence_member<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PredicateSequenceMember,
+) -> Result<(), Error> {
+    match node {
+        PredicateSequenceMember::Simple(v) => visitor.visit_simple_value(v),
+        PredicateSequenceMember::ValueConstructor(v) => visitor.visit_value_constructor(v),
+        PredicateSequenceMember::Mapping(v) => visitor.visit_mapping_value(v),
+        PredicateSequenceMember::Reference(v) => visitor.visit_identifier_reference(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Values
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_value<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Value,
+) -> Result<(), Error> {
+    match node {
+        Value::Simple(v) => visitor.visit_simple_value(v),
+        Value::ValueConstructor(v) => visitor.visit_value_constructor(v),
+        Value::Mapping(v) => visitor.visit_mapping_value(v),
+        Value::Reference(v) => visitor.visit_identifier_reference(v),
+        Value::List(v) => visitor.visit_sequence_of_values(v),
+    }
+}
+
+pub fn walk_value_constructor<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ValueConstructor,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.type_name())?;
+    visitor.visit_simple_value(node.value())
+}
+
+pub fn walk_mapping_value<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MappingValue,
+) -> Result<(), Error> {
+    visitor.visit_simple_value(node.domain())?;
+    visitor.visit_value(node.range())
+}
+
+pub fn walk_sequence_of_values<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SequenceOfValues,
+) -> Result<(), Error> {
+    for member in node.iter() {
+        visitor.visit_sequence_member(member)?;
+    }
+    Ok(())
+}
+
+pub fn walk_sequence_member<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SequenceMember,
+) -> Result<(), Error> {
+    match node {
+        SequenceMember::Simple(v) => visitor.visit_simple_value(v),
+        SequenceMember::ValueConstructor(v) => visitor.visit_value_constructor(v),
+        SequenceMember::Reference(v) => visitor.visit_identifier_reference(v),
+        SequenceMember::Mapping(v) => visitor.visit_mapping_value(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Definitions
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_definition<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Definition,
+) -> Result<(), Error> {
+    match node {
+        Definition::Datatype(v) => visitor.visit_datatype_def(v),
+        Definition::Entity(v) => visitor.visit_entity_def(v),
+        Definition::Enum(v) => visitor.visit_enum_def(v),
+        Definition::Event(v) => visitor.visit_event_def(v),
+        Definition::Property(v) => visitor.visit_property_def(v),
+        Definition::Rdf(v) => visitor.visit_rdf_def(v),
+        Definition::Structure(v) => visitor.visit_structure_def(v),
+        Definition::TypeClass(v) => visitor.visit_type_class_def(v),
+        Definition::Union(v) => visitor.visit_union_def(v),
+    }
+}
+
+pub fn walk_datatype_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DatatypeDef,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.base_type())?;
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_entity_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EntityDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body() {
+        visitor.visit_identity_member(body.identity())?;
+        walk_annotations(visitor, body)?;
+        for member in body.members() {
+            visitor.visit_member(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_enum_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EnumDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+        for variant in body.variants() {
+            visitor.visit_value_variant(variant)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_event_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EventDef,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.event_source())?;
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+        for member in body.members() {
+            visitor.visit_member(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_property_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PropertyDef,
+) -> Result<(), Error> {
+    visitor.visit_member_def(node.member_def())
+}
+
+pub fn walk_rdf_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast RdfDef,
+) -> Result<(), Error> {
+    walk_annotations(visitor, node.body())
+}
+
+pub fn walk_structure_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast StructureDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+        for member in body.members() {
+            visitor.visit_member(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_type_class_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeClassDef,
+) -> Result<(), Error> {
+    for variable in node.variables() {
+        visitor.visit_type_variable(variable)?;
+    }
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+        for method in body.methods() {
+            visitor.visit_method_def(method)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_type_variable<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeVariable,
+) -> Result<(), Error> {
+    if let Some(cardinality) = node.cardinality() {
+        visitor.visit_function_cardinality(cardinality)?;
+    }
+    for restriction in node.restrictions() {
+        visitor.visit_type_class_reference(restriction)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_class_reference<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeClassReference,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.name())?;
+    for argument in node.arguments() {
+        match argument {
+            TypeClassArgument::Wildcard => {}
+            TypeClassArgument::Reference(v) => visitor.visit_type_class_reference(v)?,
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_method_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MethodDef,
+) -> Result<(), Error> {
+    visitor.visit_function_signature(node.signature())?;
+    if let Some(body) = node.body() {
+        visitor.visit_constraint_sentence(body)?;
+    }
+    walk_annotations(visitor, node)
+}
+
+pub fn walk_union_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast UnionDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+        for variant in body.variants() {
+            visitor.visit_type_variant(variant)?;
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Members, Variants, and Types
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_member<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Member,
+) -> Result<(), Error> {
+    match node.kind() {
+        MemberKind::Reference(v) => visitor.visit_identifier_reference(v),
+        MemberKind::Definition(v) => visitor.visit_member_def(v),
+    }
+}
+
+pub fn walk_member_def<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MemberDef,
+) -> Result<(), Error> {
+    visitor.visit_cardinality(node.target_cardinality())?;
+    visitor.visit_type_reference(node.target_type())?;
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_value_variant<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ValueVariant,
+) -> Result<(), Error> {
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_variant<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeVariant,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference(node.name_reference())?;
+    if let Some(body) = node.body() {
+        walk_annotations(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_reference<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeReference,
+) -> Result<(), Error> {
+    match node {
+        TypeReference::Unknown => Ok(()),
+        TypeReference::Type(v) => visitor.visit_identifier_reference(v),
+        TypeReference::MappingType(v) => visitor.visit_mapping_type(v),
+    }
+}
+
+pub fn walk_mapping_type<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MappingType,
+) -> Result<(), Error> {
+    visitor.visit_type_reference(node.domain())?;
+    visitor.visit_type_reference(node.range())
+}
@@ -0,0 +1,996 @@
+/*!
+Provides a visitor that may rewrite, in place, every node of the in-memory model of an SDML module.
+
+This is the mutable counterpart of [`Visitor`](super::visit::Visitor); each method on
+[`VisitorMut`] is called with a mutable reference to one kind of model node and, by default, calls
+the corresponding `walk_*_mut` function in this module to visit the node's children. This allows
+transformations, such as renaming or normalizing references, to be written as passes over a module.
+
+```rust
+use sdml_core::error::Error;
+use sdml_core::model::identifiers::{Identifier, IdentifierReference, QualifiedIdentifier};
+use sdml_core::model::modules::Module;
+use sdml_core::model::walk::visit_mut::{walk_module_mut, VisitorMut};
+
+struct ModuleRenamer {
+    from: Identifier,
+    to: Identifier,
+}
+
+impl VisitorMut for ModuleRenamer {
+    fn visit_identifier_reference_mut(
+        &mut self,
+        node: &mut IdentifierReference,
+    ) -> Result<(), Error> {
+        if let IdentifierReference::QualifiedIdentifier(name) = node {
+            if name.module() == &self.from {
+                *node = QualifiedIdentifier::new(self.to.clone(), name.member().clone()).into();
+            }
+        }
+        Ok(())
+    }
+}
+
+fn rename_module_references(module: &mut Module, from: Identifier, to: Identifier) -> Result<(), Error> {
+    walk_module_mut(&mut ModuleRenamer { from, to }, module)
+}
+```
+
+*/
+
+use crate::error::Error;
+use crate::model::annotations::{Annotation, AnnotationProperty, HasAnnotations};
+use crate::model::constraints::{
+    AtomicSentence, BinaryBooleanSentence, BooleanSentence, Constraint, ConstraintBody,
+    ConstraintSentence, ControlledLanguageString, EnvironmentDef, EnvironmentDefBody, Equation,
+    FormalConstraint, FunctionCardinality, FunctionComposition, FunctionDef, FunctionParameter,
+    FunctionSignature, FunctionType, FunctionTypeReference, FunctionTypeReferenceInner,
+    FunctionalTerm, Inequation, PredicateSequenceMember, PredicateValue, QuantifiedSentence,
+    QuantifiedVariable, QuantifiedVariableBinding, SequenceBuilder, SequenceOfPredicateValues,
+    SimpleSentence, Term, UnaryBooleanSentence,
+};
+use crate::model::definitions::{
+    DatatypeDef, Definition, EntityDef, EnumDef, EventDef, HasMembers, HasVariants, MethodDef,
+    PropertyDef, RdfDef, StructureDef, TypeClassArgument, TypeClassDef, TypeClassReference,
+    TypeVariable, TypeVariant, UnionDef, ValueVariant,
+};
+use crate::model::identifiers::{IdentifierReference, QualifiedIdentifier};
+use crate::model::members::{
+    Cardinality, MappingType, Member, MemberDef, MemberKind, TypeReference,
+};
+use crate::model::modules::{Import, ImportStatement, Module, ModuleImport};
+use crate::model::values::{
+    MappingValue, SequenceMember, SequenceOfValues, SimpleValue, Value, ValueConstructor,
+};
+use crate::model::{HasBody, HasOptionalBody};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The trait implemented by a visitor of the model, see the [module documentation](self).
+///
+pub trait VisitorMut {
+    // --------------------------------------------------------------------------------------------
+    // Modules
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_module_mut(&mut self, node: &mut Module) -> Result<(), Error> {
+        walk_module_mut(self, node)
+    }
+
+    fn visit_import_statement_mut(&mut self, node: &mut ImportStatement) -> Result<(), Error> {
+        walk_import_statement_mut(self, node)
+    }
+
+    fn visit_module_import_mut(&mut self, _node: &mut ModuleImport) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_member_import_mut(&mut self, _node: &mut QualifiedIdentifier) -> Result<(), Error> {
+        Ok(())
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Annotations and Constraints
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_annotation_mut(&mut self, node: &mut Annotation) -> Result<(), Error> {
+        walk_annotation_mut(self, node)
+    }
+
+    fn visit_annotation_property_mut(
+        &mut self,
+        node: &mut AnnotationProperty,
+    ) -> Result<(), Error> {
+        walk_annotation_property_mut(self, node)
+    }
+
+    fn visit_constraint_mut(&mut self, node: &mut Constraint) -> Result<(), Error> {
+        walk_constraint_mut(self, node)
+    }
+
+    fn visit_informal_constraint_mut(
+        &mut self,
+        _node: &mut ControlledLanguageString,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_formal_constraint_mut(&mut self, node: &mut FormalConstraint) -> Result<(), Error> {
+        walk_formal_constraint_mut(self, node)
+    }
+
+    fn visit_environment_def_mut(&mut self, node: &mut EnvironmentDef) -> Result<(), Error> {
+        walk_environment_def_mut(self, node)
+    }
+
+    fn visit_function_def_mut(&mut self, node: &mut FunctionDef) -> Result<(), Error> {
+        walk_function_def_mut(self, node)
+    }
+
+    fn visit_function_signature_mut(&mut self, node: &mut FunctionSignature) -> Result<(), Error> {
+        walk_function_signature_mut(self, node)
+    }
+
+    fn visit_function_parameter_mut(&mut self, node: &mut FunctionParameter) -> Result<(), Error> {
+        walk_function_parameter_mut(self, node)
+    }
+
+    fn visit_function_type_mut(&mut self, node: &mut FunctionType) -> Result<(), Error> {
+        walk_function_type_mut(self, node)
+    }
+
+    fn visit_function_cardinality_mut(
+        &mut self,
+        _node: &mut FunctionCardinality,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_function_type_reference_mut(
+        &mut self,
+        node: &mut FunctionTypeReference,
+    ) -> Result<(), Error> {
+        walk_function_type_reference_mut(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Sentences
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_constraint_sentence_mut(
+        &mut self,
+        node: &mut ConstraintSentence,
+    ) -> Result<(), Error> {
+        walk_constraint_sentence_mut(self, node)
+    }
+
+    fn visit_simple_sentence_mut(&mut self, node: &mut SimpleSentence) -> Result<(), Error> {
+        walk_simple_sentence_mut(self, node)
+    }
+
+    fn visit_atomic_sentence_mut(&mut self, node: &mut AtomicSentence) -> Result<(), Error> {
+        walk_atomic_sentence_mut(self, node)
+    }
+
+    fn visit_equation_mut(&mut self, node: &mut Equation) -> Result<(), Error> {
+        walk_equation_mut(self, node)
+    }
+
+    fn visit_inequation_mut(&mut self, node: &mut Inequation) -> Result<(), Error> {
+        walk_inequation_mut(self, node)
+    }
+
+    fn visit_boolean_sentence_mut(&mut self, node: &mut BooleanSentence) -> Result<(), Error> {
+        walk_boolean_sentence_mut(self, node)
+    }
+
+    fn visit_unary_boolean_sentence_mut(
+        &mut self,
+        node: &mut UnaryBooleanSentence,
+    ) -> Result<(), Error> {
+        walk_unary_boolean_sentence_mut(self, node)
+    }
+
+    fn visit_binary_boolean_sentence_mut(
+        &mut self,
+        node: &mut BinaryBooleanSentence,
+    ) -> Result<(), Error> {
+        walk_binary_boolean_sentence_mut(self, node)
+    }
+
+    fn visit_quantified_sentence_mut(
+        &mut self,
+        node: &mut QuantifiedSentence,
+    ) -> Result<(), Error> {
+        walk_quantified_sentence_mut(self, node)
+    }
+
+    fn visit_quantified_variable_binding_mut(
+        &mut self,
+        node: &mut QuantifiedVariableBinding,
+    ) -> Result<(), Error> {
+        walk_quantified_variable_binding_mut(self, node)
+    }
+
+    fn visit_quantified_variable_mut(
+        &mut self,
+        node: &mut QuantifiedVariable,
+    ) -> Result<(), Error> {
+        walk_quantified_variable_mut(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Terms
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_term_mut(&mut self, node: &mut Term) -> Result<(), Error> {
+        walk_term_mut(self, node)
+    }
+
+    fn visit_sequence_builder_mut(&mut self, node: &mut SequenceBuilder) -> Result<(), Error> {
+        walk_sequence_builder_mut(self, node)
+    }
+
+    fn visit_functional_term_mut(&mut self, node: &mut FunctionalTerm) -> Result<(), Error> {
+        walk_functional_term_mut(self, node)
+    }
+
+    fn visit_function_composition_mut(
+        &mut self,
+        _node: &mut FunctionComposition,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_predicate_value_mut(&mut self, node: &mut PredicateValue) -> Result<(), Error> {
+        walk_predicate_value_mut(self, node)
+    }
+
+    fn visit_sequence_of_predicate_values_mut(
+        &mut self,
+        node: &mut SequenceOfPredicateValues,
+    ) -> Result<(), Error> {
+        walk_sequence_of_predicate_values_mut(self, node)
+    }
+
+    fn visit_predicate_sequence_member_mut(
+        &mut self,
+        node: &mut PredicateSequenceMember,
+    ) -> Result<(), Error> {
+        walk_predicate_sequence_member_mut(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Values
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_value_mut(&mut self, node: &mut Value) -> Result<(), Error> {
+        walk_value_mut(self, node)
+    }
+
+    fn visit_simple_value_mut(&mut self, _node: &mut SimpleValue) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_value_constructor_mut(&mut self, node: &mut ValueConstructor) -> Result<(), Error> {
+        walk_value_constructor_mut(self, node)
+    }
+
+    fn visit_mapping_value_mut(&mut self, node: &mut MappingValue) -> Result<(), Error> {
+        walk_mapping_value_mut(self, node)
+    }
+
+    fn visit_sequence_of_values_mut(&mut self, node: &mut SequenceOfValues) -> Result<(), Error> {
+        walk_sequence_of_values_mut(self, node)
+    }
+
+    fn visit_sequence_member_mut(&mut self, node: &mut SequenceMember) -> Result<(), Error> {
+        walk_sequence_member_mut(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Definitions
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_definition_mut(&mut self, node: &mut Definition) -> Result<(), Error> {
+        walk_definition_mut(self, node)
+    }
+
+    fn visit_datatype_def_mut(&mut self, node: &mut DatatypeDef) -> Result<(), Error> {
+        walk_datatype_def_mut(self, node)
+    }
+
+    fn visit_entity_def_mut(&mut self, node: &mut EntityDef) -> Result<(), Error> {
+        walk_entity_def_mut(self, node)
+    }
+
+    fn visit_enum_def_mut(&mut self, node: &mut EnumDef) -> Result<(), Error> {
+        walk_enum_def_mut(self, node)
+    }
+
+    fn visit_event_def_mut(&mut self, node: &mut EventDef) -> Result<(), Error> {
+        walk_event_def_mut(self, node)
+    }
+
+    fn visit_property_def_mut(&mut self, node: &mut PropertyDef) -> Result<(), Error> {
+        walk_property_def_mut(self, node)
+    }
+
+    fn visit_rdf_def_mut(&mut self, node: &mut RdfDef) -> Result<(), Error> {
+        walk_rdf_def_mut(self, node)
+    }
+
+    fn visit_structure_def_mut(&mut self, node: &mut StructureDef) -> Result<(), Error> {
+        walk_structure_def_mut(self, node)
+    }
+
+    fn visit_type_class_def_mut(&mut self, node: &mut TypeClassDef) -> Result<(), Error> {
+        walk_type_class_def_mut(self, node)
+    }
+
+    fn visit_type_variable_mut(&mut self, node: &mut TypeVariable) -> Result<(), Error> {
+        walk_type_variable_mut(self, node)
+    }
+
+    fn visit_type_class_reference_mut(
+        &mut self,
+        node: &mut TypeClassReference,
+    ) -> Result<(), Error> {
+        walk_type_class_reference_mut(self, node)
+    }
+
+    fn visit_method_def_mut(&mut self, node: &mut MethodDef) -> Result<(), Error> {
+        walk_method_def_mut(self, node)
+    }
+
+    fn visit_union_def_mut(&mut self, node: &mut UnionDef) -> Result<(), Error> {
+        walk_union_def_mut(self, node)
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Members, Variants, and Types
+    // --------------------------------------------------------------------------------------------
+
+    fn visit_member_mut(&mut self, node: &mut Member) -> Result<(), Error> {
+        walk_member_mut(self, node)
+    }
+
+    fn visit_identity_member_mut(&mut self, node: &mut Member) -> Result<(), Error> {
+        walk_member_mut(self, node)
+    }
+
+    fn visit_member_def_mut(&mut self, node: &mut MemberDef) -> Result<(), Error> {
+        walk_member_def_mut(self, node)
+    }
+
+    fn visit_value_variant_mut(&mut self, node: &mut ValueVariant) -> Result<(), Error> {
+        walk_value_variant_mut(self, node)
+    }
+
+    fn visit_type_variant_mut(&mut self, node: &mut TypeVariant) -> Result<(), Error> {
+        walk_type_variant_mut(self, node)
+    }
+
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) -> Result<(), Error> {
+        walk_type_reference_mut(self, node)
+    }
+
+    fn visit_mapping_type_mut(&mut self, node: &mut MappingType) -> Result<(), Error> {
+        walk_mapping_type_mut(self, node)
+    }
+
+    fn visit_cardinality_mut(&mut self, _node: &mut Cardinality) -> Result<(), Error> {
+        Ok(())
+    }
+
+    ///
+    /// Called for every reference to a definition, or annotation property, by name.
+    ///
+    fn visit_identifier_reference_mut(
+        &mut self,
+        _node: &mut IdentifierReference,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Modules
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_module_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Module,
+) -> Result<(), Error> {
+    let body = node.body_mut();
+    for import in body.imports_mut() {
+        visitor.visit_import_statement_mut(import)?;
+    }
+    walk_annotations_mut(visitor, body)?;
+    for definition in body.definitions_mut() {
+        visitor.visit_definition_mut(definition)?;
+    }
+    Ok(())
+}
+
+pub fn walk_import_statement_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ImportStatement,
+) -> Result<(), Error> {
+    for import in node.imports_mut() {
+        match import {
+            Import::Module(v) => visitor.visit_module_import_mut(v)?,
+            Import::Member(v) => visitor.visit_member_import_mut(v)?,
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Annotations and Constraints
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_annotations_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut impl HasAnnotations,
+) -> Result<(), Error> {
+    for annotation in node.annotations_mut() {
+        visitor.visit_annotation_mut(annotation)?;
+    }
+    Ok(())
+}
+
+pub fn walk_annotation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Annotation,
+) -> Result<(), Error> {
+    match node {
+        Annotation::Property(v) => visitor.visit_annotation_property_mut(v),
+        Annotation::Constraint(v) => visitor.visit_constraint_mut(v),
+    }
+}
+
+pub fn walk_annotation_property_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnnotationProperty,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.name_reference_mut())?;
+    visitor.visit_value_mut(node.value_mut())
+}
+
+pub fn walk_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Constraint,
+) -> Result<(), Error> {
+    match node.body_mut() {
+        ConstraintBody::Informal(v) => visitor.visit_informal_constraint_mut(v),
+        ConstraintBody::Formal(v) => visitor.visit_formal_constraint_mut(v),
+    }
+}
+
+pub fn walk_formal_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FormalConstraint,
+) -> Result<(), Error> {
+    for definition in node.definitions_mut() {
+        visitor.visit_environment_def_mut(definition)?;
+    }
+    visitor.visit_constraint_sentence_mut(node.body_mut())
+}
+
+pub fn walk_environment_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EnvironmentDef,
+) -> Result<(), Error> {
+    match node.body_mut() {
+        EnvironmentDefBody::Function(v) => visitor.visit_function_def_mut(v),
+        EnvironmentDefBody::Value(v) => visitor.visit_predicate_value_mut(v),
+        EnvironmentDefBody::Sentence(v) => visitor.visit_constraint_sentence_mut(v),
+    }
+}
+
+pub fn walk_function_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionDef,
+) -> Result<(), Error> {
+    visitor.visit_function_signature_mut(node.signature_mut())?;
+    visitor.visit_constraint_sentence_mut(node.body_mut())
+}
+
+pub fn walk_function_signature_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionSignature,
+) -> Result<(), Error> {
+    for parameter in node.parameters_mut() {
+        visitor.visit_function_parameter_mut(parameter)?;
+    }
+    visitor.visit_function_type_mut(node.target_type_mut())
+}
+
+pub fn walk_function_parameter_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionParameter,
+) -> Result<(), Error> {
+    visitor.visit_function_type_mut(node.target_type_mut())
+}
+
+pub fn walk_function_type_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionType,
+) -> Result<(), Error> {
+    visitor.visit_function_cardinality_mut(node.target_cardinality_mut())?;
+    visitor.visit_function_type_reference_mut(node.target_type_mut())
+}
+
+pub fn walk_function_type_reference_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionTypeReference,
+) -> Result<(), Error> {
+    match node.inner_mut() {
+        FunctionTypeReferenceInner::Wildcard => Ok(()),
+        FunctionTypeReferenceInner::Reference(v) => visitor.visit_identifier_reference_mut(v),
+        FunctionTypeReferenceInner::MappingType(v) => visitor.visit_mapping_type_mut(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Sentences
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_constraint_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstraintSentence,
+) -> Result<(), Error> {
+    match node {
+        ConstraintSentence::Simple(v) => visitor.visit_simple_sentence_mut(v),
+        ConstraintSentence::Boolean(v) => visitor.visit_boolean_sentence_mut(v),
+        ConstraintSentence::Quantified(v) => visitor.visit_quantified_sentence_mut(v),
+    }
+}
+
+pub fn walk_simple_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SimpleSentence,
+) -> Result<(), Error> {
+    match node {
+        SimpleSentence::Atomic(v) => visitor.visit_atomic_sentence_mut(v),
+        SimpleSentence::Equation(v) => visitor.visit_equation_mut(v),
+        SimpleSentence::Inequation(v) => visitor.visit_inequation_mut(v),
+    }
+}
+
+pub fn walk_atomic_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AtomicSentence,
+) -> Result<(), Error> {
+    visitor.visit_term_mut(node.predicate_mut())?;
+    for argument in node.arguments_mut() {
+        visitor.visit_term_mut(argument)?;
+    }
+    Ok(())
+}
+
+pub fn walk_equation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Equation,
+) -> Result<(), Error> {
+    visitor.visit_term_mut(node.left_operand_mut())?;
+    visitor.visit_term_mut(node.right_operand_mut())
+}
+
+pub fn walk_inequation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Inequation,
+) -> Result<(), Error> {
+    visitor.visit_term_mut(node.left_operand_mut())?;
+    visitor.visit_term_mut(node.right_operand_mut())
+}
+
+pub fn walk_boolean_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BooleanSentence,
+) -> Result<(), Error> {
+    match node {
+        BooleanSentence::Unary(v) => visitor.visit_unary_boolean_sentence_mut(v),
+        BooleanSentence::Binary(v) => visitor.visit_binary_boolean_sentence_mut(v),
+    }
+}
+
+pub fn walk_unary_boolean_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnaryBooleanSentence,
+) -> Result<(), Error> {
+    visitor.visit_constraint_sentence_mut(node.operand_mut())
+}
+
+pub fn walk_binary_boolean_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BinaryBooleanSentence,
+) -> Result<(), Error> {
+    visitor.visit_constraint_sentence_mut(node.left_operand_mut())?;
+    visitor.visit_constraint_sentence_mut(node.right_operand_mut())
+}
+
+pub fn walk_quantified_sentence_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut QuantifiedSentence,
+) -> Result<(), Error> {
+    visitor.visit_quantified_variable_binding_mut(node.binding_mut())?;
+    visitor.visit_constraint_sentence_mut(node.body_mut())
+}
+
+pub fn walk_quantified_variable_binding_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut QuantifiedVariableBinding,
+) -> Result<(), Error> {
+    if let Some(binding) = node.binding_mut() {
+        visitor.visit_quantified_variable_mut(binding)?;
+    }
+    Ok(())
+}
+
+pub fn walk_quantified_variable_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut QuantifiedVariable,
+) -> Result<(), Error> {
+    visitor.visit_term_mut(node.source_mut())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Terms
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_term_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Term,
+) -> Result<(), Error> {
+    match node {
+        Term::Sequence(v) => visitor.visit_sequence_builder_mut(v),
+        Term::Function(v) => visitor.visit_functional_term_mut(v),
+        Term::Composition(v) => visitor.visit_function_composition_mut(v),
+        Term::Identifier(v) => visitor.visit_identifier_reference_mut(v),
+        Term::ReservedSelf => Ok(()),
+        Term::Value(v) => visitor.visit_predicate_value_mut(v),
+    }
+}
+
+pub fn walk_sequence_builder_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SequenceBuilder,
+) -> Result<(), Error> {
+    visitor.visit_quantified_sentence_mut(node.body_mut())
+}
+
+pub fn walk_functional_term_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionalTerm,
+) -> Result<(), Error> {
+    visitor.visit_term_mut(node.function_mut())?;
+    for argument in node.arguments_mut() {
+        visitor.visit_term_mut(argument)?;
+    }
+    Ok(())
+}
+
+pub fn walk_predicate_value_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PredicateValue,
+) -> Result<(), Error> {
+    match node {
+        PredicateValue::Simple(v) => visitor.visit_simple_value_mut(v),
+        PredicateValue::Sequence(v) => visitor.visit_sequence_of_predicate_values_mut(v),
+    }
+}
+
+pub fn walk_sequence_of_predicate_values_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SequenceOfPredicateValues,
+) -> Result<(), Error> {
+    for member in node.iter_mut() {
+        visitor.visit_predicate_sequence_member_mut(member)?;
+    }
+    Ok(())
+}
+
+pub fn walk_predicate_sequence_member_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PredicateSequenceMember,
+) -> Result<(), Error> {
+    match node {
+        PredicateSequenceMember::Simple(v) => visitor.visit_simple_value_mut(v),
+        PredicateSequenceMember::ValueConstructor(v) => visitor.visit_value_constructor_mut(v),
+        PredicateSequenceMember::Mapping(v) => visitor.visit_mapping_value_mut(v),
+        PredicateSequenceMember::Reference(v) => visitor.visit_identifier_reference_mut(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Values
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_value_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Value,
+) -> Result<(), Error> {
+    match node {
+        Value::Simple(v) => visitor.visit_simple_value_mut(v),
+        Value::ValueConstructor(v) => visitor.visit_value_constructor_mut(v),
+        Value::Mapping(v) => visitor.visit_mapping_value_mut(v),
+        Value::Reference(v) => visitor.visit_identifier_reference_mut(v),
+        Value::List(v) => visitor.visit_sequence_of_values_mut(v),
+    }
+}
+
+pub fn walk_value_constructor_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ValueConstructor,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.type_name_mut())?;
+    visitor.visit_simple_value_mut(node.value_mut())
+}
+
+pub fn walk_mapping_value_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MappingValue,
+) -> Result<(), Error> {
+    visitor.visit_simple_value_mut(node.domain_mut())?;
+    visitor.visit_value_mut(node.range_mut())
+}
+
+pub fn walk_sequence_of_values_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SequenceOfValues,
+) -> Result<(), Error> {
+    for member in node.iter_mut() {
+        visitor.visit_sequence_member_mut(member)?;
+    }
+    Ok(())
+}
+
+pub fn walk_sequence_member_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SequenceMember,
+) -> Result<(), Error> {
+    match node {
+        SequenceMember::Simple(v) => visitor.visit_simple_value_mut(v),
+        SequenceMember::ValueConstructor(v) => visitor.visit_value_constructor_mut(v),
+        SequenceMember::Reference(v) => visitor.visit_identifier_reference_mut(v),
+        SequenceMember::Mapping(v) => visitor.visit_mapping_value_mut(v),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Definitions
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_definition_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Definition,
+) -> Result<(), Error> {
+    match node {
+        Definition::Datatype(v) => visitor.visit_datatype_def_mut(v),
+        Definition::Entity(v) => visitor.visit_entity_def_mut(v),
+        Definition::Enum(v) => visitor.visit_enum_def_mut(v),
+        Definition::Event(v) => visitor.visit_event_def_mut(v),
+        Definition::Property(v) => visitor.visit_property_def_mut(v),
+        Definition::Rdf(v) => visitor.visit_rdf_def_mut(v),
+        Definition::Structure(v) => visitor.visit_structure_def_mut(v),
+        Definition::TypeClass(v) => visitor.visit_type_class_def_mut(v),
+        Definition::Union(v) => visitor.visit_union_def_mut(v),
+    }
+}
+
+pub fn walk_datatype_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DatatypeDef,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.base_type_mut())?;
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_entity_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EntityDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body_mut() {
+        visitor.visit_identity_member_mut(body.identity_mut())?;
+        walk_annotations_mut(visitor, body)?;
+        for member in body.members_mut() {
+            visitor.visit_member_mut(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_enum_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EnumDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+        for variant in body.variants_mut() {
+            visitor.visit_value_variant_mut(variant)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_event_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EventDef,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.event_source_mut())?;
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+        for member in body.members_mut() {
+            visitor.visit_member_mut(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_property_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyDef,
+) -> Result<(), Error> {
+    visitor.visit_member_def_mut(node.member_def_mut())
+}
+
+pub fn walk_rdf_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut RdfDef,
+) -> Result<(), Error> {
+    walk_annotations_mut(visitor, node.body_mut())
+}
+
+pub fn walk_structure_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StructureDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+        for member in body.members_mut() {
+            visitor.visit_member_mut(member)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_type_class_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeClassDef,
+) -> Result<(), Error> {
+    for variable in node.variables_mut() {
+        visitor.visit_type_variable_mut(variable)?;
+    }
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+        for method in body.methods_mut() {
+            visitor.visit_method_def_mut(method)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_type_variable_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeVariable,
+) -> Result<(), Error> {
+    if let Some(cardinality) = node.cardinality_mut() {
+        visitor.visit_function_cardinality_mut(cardinality)?;
+    }
+    for restriction in node.restrictions_mut() {
+        visitor.visit_type_class_reference_mut(restriction)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_class_reference_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeClassReference,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.name_mut())?;
+    for argument in node.arguments_mut() {
+        match argument {
+            TypeClassArgument::Wildcard => {}
+            TypeClassArgument::Reference(v) => visitor.visit_type_class_reference_mut(v)?,
+        }
+    }
+    Ok(())
+}
+
+pub fn walk_method_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodDef,
+) -> Result<(), Error> {
+    visitor.visit_function_signature_mut(node.signature_mut())?;
+    if let Some(body) = node.body_mut() {
+        visitor.visit_constraint_sentence_mut(body)?;
+    }
+    walk_annotations_mut(visitor, node)
+}
+
+pub fn walk_union_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnionDef,
+) -> Result<(), Error> {
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+        for variant in body.variants_mut() {
+            visitor.visit_type_variant_mut(variant)?;
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions ❱ Members, Variants, and Types
+// ------------------------------------------------------------------------------------------------
+
+pub fn walk_member_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Member,
+) -> Result<(), Error> {
+    match node.kind_mut() {
+        MemberKind::Reference(v) => visitor.visit_identifier_reference_mut(v),
+        MemberKind::Definition(v) => visitor.visit_member_def_mut(v),
+    }
+}
+
+pub fn walk_member_def_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MemberDef,
+) -> Result<(), Error> {
+    visitor.visit_cardinality_mut(node.target_cardinality_mut())?;
+    visitor.visit_type_reference_mut(node.target_type_mut())?;
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_value_variant_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ValueVariant,
+) -> Result<(), Error> {
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_variant_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeVariant,
+) -> Result<(), Error> {
+    visitor.visit_identifier_reference_mut(node.name_reference_mut())?;
+    if let Some(body) = node.body_mut() {
+        walk_annotations_mut(visitor, body)?;
+    }
+    Ok(())
+}
+
+pub fn walk_type_reference_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeReference,
+) -> Result<(), Error> {
+    match node {
+        TypeReference::Unknown => Ok(()),
+        TypeReference::Type(v) => visitor.visit_identifier_reference_mut(v),
+        TypeReference::MappingType(v) => visitor.visit_mapping_type_mut(v),
+    }
+}
+
+pub fn walk_mapping_type_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MappingType,
+) -> Result<(), Error> {
+    visitor.visit_type_reference_mut(node.domain_mut())?;
+    visitor.visit_type_reference_mut(node.range_mut())
+}
@@ -0,0 +1,353 @@
+/*!
+This module provides refactoring operations over the modules in a module store.
+
+Refactoring operations do not change the in-memory model, instead they compute a set of
+[`SourceEdits`], text edits to the source files of the affected modules. These edits may be
+inspected before they are applied, and once applied the modules should be reloaded.
+
+# Example
+
+```rust,no_run
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::refactor::rename::rename_definition;
+use sdml_core::store::InMemoryModuleCache;
+use std::str::FromStr;
+
+let cache = InMemoryModuleCache::default().with_stdlib();
+// load modules into the cache ...
+
+let edits = rename_definition(
+    &cache,
+    &QualifiedIdentifier::from_str("rentals:Vehicle").unwrap(),
+    &Identifier::from_str("Car").unwrap(),
+)
+.unwrap();
+
+for (file, file_edits) in edits.files() {
+    println!("{}: {} edits", file.display(), file_edits.len());
+}
+
+edits.apply().unwrap();
+```
+
+*/
+
+use crate::model::modules::Module;
+use crate::model::{HasName, Span};
+use sdml_errors::Error;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single edit, replacing a range of bytes in a source file with new text. An empty range is
+/// an insertion, and empty new text is a deletion.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    range: Range<usize>,
+    new_text: String,
+}
+
+///
+/// A set of text edits, grouped by source file, and a set of new source files to create.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceEdits {
+    files: BTreeMap<PathBuf, Vec<TextEdit>>,
+    new_files: BTreeMap<PathBuf, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The source text of modules, read on demand from their source files.
+///
+#[derive(Debug, Default)]
+struct SourceCache {
+    sources: HashMap<PathBuf, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Apply `edits` to the text `source` returning the new text. Edits are applied in order of
+/// their start position, and must not overlap.
+///
+pub fn apply_edits(source: &str, edits: &[TextEdit]) -> Result<String, Error> {
+    let mut edits = edits.iter().collect::<Vec<_>>();
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+
+    let mut result = String::with_capacity(source.len());
+    let mut last = 0;
+    for edit in edits {
+        if edit.range.start < last
+            || edit.range.end > source.len()
+            || !source.is_char_boundary(edit.range.start)
+            || !source.is_char_boundary(edit.range.end)
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "text edit {:?} overlaps, or is outside, the source",
+                    edit.range
+                ),
+            )
+            .into());
+        }
+        result.push_str(&source[last..edit.range.start]);
+        result.push_str(&edit.new_text);
+        last = edit.range.end;
+    }
+    result.push_str(&source[last..]);
+    Ok(result)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TextEdit {
+    ///
+    /// Replace the bytes in `range` with `new_text`.
+    ///
+    pub fn replace<S>(range: Range<usize>, new_text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            range,
+            new_text: new_text.into(),
+        }
+    }
+
+    ///
+    /// Replace the text covered by `span` with `new_text`.
+    ///
+    pub fn replace_span<S>(span: &Span, new_text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::replace(span.byte_range(), new_text)
+    }
+
+    ///
+    /// Insert `new_text` at the byte offset `at`.
+    ///
+    pub fn insert<S>(at: usize, new_text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::replace(at..at, new_text)
+    }
+
+    ///
+    /// Delete the bytes in `range`.
+    ///
+    pub fn delete(range: Range<usize>) -> Self {
+        Self::replace(range, String::new())
+    }
+
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    pub fn new_text(&self) -> &str {
+        &self.new_text
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl SourceEdits {
+    ///
+    /// Returns `true` if there are no edits, or new files, in this set.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.new_files.is_empty()
+    }
+
+    ///
+    /// Return the total number of edits, across all files, and new files in this set.
+    ///
+    pub fn len(&self) -> usize {
+        self.files.values().map(Vec::len).sum::<usize>() + self.new_files.len()
+    }
+
+    ///
+    /// Add `edit` to the edits for `file`, an edit identical to one already present is ignored.
+    ///
+    pub fn add<P>(&mut self, file: P, edit: TextEdit)
+    where
+        P: Into<PathBuf>,
+    {
+        let edits = self.files.entry(file.into()).or_default();
+        if !edits.contains(&edit) {
+            edits.push(edit);
+            edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+        }
+    }
+
+    ///
+    /// Add a new file, at `file`, with the text `content`. The file must not exist when the
+    /// edits are applied.
+    ///
+    pub fn create_file<P, S>(&mut self, file: P, content: S)
+    where
+        P: Into<PathBuf>,
+        S: Into<String>,
+    {
+        self.new_files.insert(file.into(), content.into());
+    }
+
+    ///
+    /// Add all the edits, and new files, in `other` to this set.
+    ///
+    pub fn extend(&mut self, other: SourceEdits) {
+        for (file, edits) in other.files {
+            for edit in edits {
+                self.add(file.clone(), edit);
+            }
+        }
+        self.new_files.extend(other.new_files);
+    }
+
+    ///
+    /// Return the files affected, and their edits ordered by position.
+    ///
+    pub fn files(&self) -> impl Iterator<Item = (&PathBuf, &[TextEdit])> {
+        self.files
+            .iter()
+            .map(|(file, edits)| (file, edits.as_slice()))
+    }
+
+    ///
+    /// Return the new files to create, and their content.
+    ///
+    pub fn new_files(&self) -> impl Iterator<Item = (&PathBuf, &str)> {
+        self.new_files
+            .iter()
+            .map(|(file, content)| (file, content.as_str()))
+    }
+
+    ///
+    /// Return the edits for `file`, if any.
+    ///
+    pub fn edits_for(&self, file: &Path) -> Option<&[TextEdit]> {
+        self.files.get(file).map(Vec::as_slice)
+    }
+
+    ///
+    /// Apply all edits, rewriting each affected file in place, and create all new files. All
+    /// files are read, and the edits applied, before any file is written so that an invalid
+    /// edit leaves all files unchanged. Returns the number of files written.
+    ///
+    pub fn apply(&self) -> Result<usize, Error> {
+        let mut results = Vec::with_capacity(self.files.len() + self.new_files.len());
+        for (file, edits) in &self.files {
+            let source = fs::read_to_string(file)?;
+            results.push((file, apply_edits(&source, edits)?));
+        }
+        for (file, content) in &self.new_files {
+            if file.exists() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("will not overwrite existing file {file:?}"),
+                )
+                .into());
+            }
+            results.push((file, content.clone()));
+        }
+        for (file, result) in &results {
+            if let Some(parent) = file
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+            {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(file, result)?;
+        }
+        Ok(results.len())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl SourceCache {
+    ///
+    /// Return the canonical path, and source text, of `module`'s source file.
+    ///
+    fn source_for(&mut self, module: &Module) -> Result<(PathBuf, &str), Error> {
+        let file = module
+            .source_file()
+            .ok_or_else(|| source_error(module, "has no source file to edit"))?;
+        // The same file may be named by relative and absolute paths, and all edits for a file
+        // must be applied together.
+        let file = file.canonicalize().unwrap_or_else(|_| file.clone());
+        if !self.sources.contains_key(&file) {
+            let source = fs::read_to_string(&file)?;
+            self.sources.insert(file.clone(), source);
+        }
+        let source = self.sources[&file].as_str();
+        Ok((file, source))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn source_error(module: &Module, message: &str) -> Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("module {}: {message}", module.name()),
+    )
+    .into()
+}
+
+///
+/// Return the byte range of `span`, which must be present, within `module`'s source.
+///
+fn required_range(module: &Module, span: Option<&Span>) -> Result<Range<usize>, Error> {
+    span.map(Span::byte_range)
+        .ok_or_else(|| source_error(module, "element has no source location"))
+}
+
+///
+/// Return the text of `range` within `module`'s source, the range is taken from the loaded model
+/// and so is invalid if the source file has changed since the module was loaded.
+///
+fn source_text<'a>(
+    module: &Module,
+    source: &'a str,
+    range: &Range<usize>,
+) -> Result<&'a str, Error> {
+    source.get(range.clone()).ok_or_else(|| {
+        source_error(
+            module,
+            "source file has changed since the module was loaded",
+        )
+    })
+}
+
+#[inline]
+fn span_range(span: Option<&Span>) -> Range<usize> {
+    span.map(Span::byte_range).unwrap_or_default()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod relocate;
+
+pub mod rename;
@@ -0,0 +1,468 @@
+/*!
+This module provides the move refactoring, moving one or more definitions from one module to
+another, existing or new, module.
+
+Moving a definition removes its text, along with any comment lines immediately preceding it,
+from the source module and adds it to the end of the target module. References are then
+rewritten across the store:
+
+- member imports of a moved definition, `import from:Name`, import it from the target module;
+- qualified references to a moved definition, `from:Name`, are qualified with the target module;
+- references remaining in the source module, and references from within the moved text to
+  definitions left behind, are qualified with their module's name;
+- references to a moved definition from within the target module are no longer qualified.
+
+Any module that now needs to import the target, or source, module has an import statement added.
+
+*/
+
+use crate::model::definitions::Definition;
+use crate::model::identifiers::{Identifier, QualifiedIdentifier};
+use crate::model::modules::{Import, Module};
+use crate::model::{HasBody, HasName, HasSourceSpan};
+use crate::refactor::{
+    apply_edits, required_range, source_error, source_text, span_range, SourceCache, SourceEdits,
+    TextEdit,
+};
+use crate::stdlib::is_builtin_type_name;
+use crate::store::{InMemoryModuleCache, ModuleStore, ReferenceKind};
+use sdml_errors::diagnostics::functions::{
+    definition_not_found, duplicate_definition, duplicate_definition_import, module_not_found,
+};
+use sdml_errors::Error;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::ops::Range;
+use std::path::Path;
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Compute the edits required to move the definitions `names` from the module `from` to the
+/// module `to`. If `to` is not in `cache` a new module is created in the file
+/// `new_module_file`, which is required in this case.
+///
+/// # Errors
+///
+/// - `ModuleNotFound` if `from` is not in the cache, or if `to` is not in the cache and no new
+///   module file is provided.
+/// - `DefinitionNotFound` if any of `names` are not defined in `from`.
+/// - `DuplicateDefinitionName` if the target module already defines any of `names`.
+/// - `DuplicateDefinitionImport` if the target module imports a different definition with any
+///   of `names`.
+/// - An I/O error if any affected module has no source file, or the source file cannot be read.
+///
+pub fn move_definitions(
+    cache: &InMemoryModuleCache,
+    from: &Identifier,
+    names: &[Identifier],
+    to: &Identifier,
+    new_module_file: Option<&Path>,
+) -> Result<SourceEdits, Error> {
+    trace!("move_definitions({from}, {names:?}, {to}, {new_module_file:?})");
+    let source_module = cache
+        .get(from)
+        .ok_or_else(|| module_not_found(from.as_ref()))?;
+    let mut edits = SourceEdits::default();
+    if from == to || names.is_empty() {
+        return Ok(edits);
+    }
+
+    let source_file_id = source_module.file_id().copied().unwrap_or_default();
+    let mut definitions: Vec<&Definition> = Default::default();
+    for name in names {
+        let definition = source_module
+            .resolve_local(name)
+            .ok_or_else(|| definition_not_found(source_file_id, None, format!("{from}:{name}")))?;
+        if !definitions.iter().any(|defn| defn.name() == name) {
+            definitions.push(definition);
+        }
+    }
+    definitions.sort_by_key(|defn| span_range(defn.source_span()).start);
+
+    let target_module = match cache.get(to) {
+        Some(target_module) => {
+            check_target(target_module, from, &definitions)?;
+            Some(target_module)
+        }
+        None if new_module_file.is_some() => None,
+        None => return Err(module_not_found(to.as_ref()).into()),
+    };
+
+    let moved_names: HashSet<&Identifier> = definitions.iter().map(|defn| defn.name()).collect();
+    let mut sources = SourceCache::default();
+    let mut moved_edits: Vec<TextEdit> = Default::default();
+    let mut required_imports: BTreeMap<Identifier, BTreeSet<Identifier>> = Default::default();
+    let mut removed_imports: Vec<Range<usize>> = Default::default();
+
+    for target in cache.reference_index().referenced_names() {
+        let is_moved_target = target.module() == from && moved_names.contains(target.member());
+        let new_target = if is_moved_target {
+            QualifiedIdentifier::new(to.clone(), target.member().clone())
+        } else {
+            target.clone()
+        };
+
+        for site in cache.references_to(target) {
+            let in_moved = site.module() == from
+                && site
+                    .definition()
+                    .map(|defn| moved_names.contains(defn))
+                    .unwrap_or_default();
+            if !(in_moved || is_moved_target) {
+                continue;
+            }
+            let module = cache
+                .get(site.module())
+                .ok_or_else(|| module_not_found(site.module().as_ref()))?;
+            let range = required_range(module, site.source_span())?;
+            let (file, source) = sources.source_for(module)?;
+            let text = source_text(module, source, &range)?;
+
+            if site.kind() == ReferenceKind::Import {
+                if site.module() == to {
+                    let edit = remove_import(module, source, &range)?;
+                    removed_imports.push(edit.range().clone());
+                    edits.add(file, edit);
+                } else if text != new_target.to_string() {
+                    edits.add(file, TextEdit::replace(range, new_target.to_string()));
+                }
+                continue;
+            }
+
+            let context = if in_moved { to } else { site.module() };
+            let is_qualified = text.contains(':');
+            let replacement = if new_target.module() == context {
+                new_target.member().to_string()
+            } else if !is_qualified
+                && ((!in_moved && site.module() != from)
+                    || (in_moved
+                        && !is_moved_target
+                        && (is_builtin_type_name(target.member())
+                            || cache.resolve(target).is_none())))
+            {
+                // Either resolved through a member import, which is rewritten, or an
+                // unresolved or builtin name; neither change.
+                continue;
+            } else {
+                required_imports
+                    .entry(context.clone())
+                    .or_default()
+                    .insert(new_target.module().clone());
+                new_target.to_string()
+            };
+
+            if replacement != text {
+                let edit = TextEdit::replace(range, replacement);
+                if in_moved {
+                    moved_edits.push(edit);
+                } else {
+                    edits.add(file, edit);
+                }
+            }
+        }
+    }
+
+    // Cut the moved definitions from the source module.
+    let mut moved_text: Vec<String> = Default::default();
+    {
+        let (file, source) = sources.source_for(source_module)?;
+        for definition in &definitions {
+            let span = required_range(source_module, definition.source_span())?;
+            // check the span is valid before the text around it is read.
+            source_text(source_module, source, &span)?;
+            let start = leading_comments_start(source, span.start);
+            let snippet_edits = moved_edits
+                .iter()
+                .filter(|edit| edit.range().start >= span.start && edit.range().end <= span.end)
+                .map(|edit| {
+                    TextEdit::replace(
+                        (edit.range().start - start)..(edit.range().end - start),
+                        edit.new_text(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let snippet = apply_edits(&source[start..span.end], &snippet_edits)?;
+            moved_text.push(reindent(
+                &snippet,
+                &source[start..line_text_start(source, start)],
+            ));
+            edits.add(
+                file.clone(),
+                TextEdit::delete(start..removal_end(source, start, span.end)),
+            );
+        }
+    }
+
+    // Add the moved definitions to the target module.
+    let moved_text = moved_text.join("\n");
+    match target_module {
+        Some(target_module) => {
+            let (file, source) = sources.source_for(target_module)?;
+            let body = required_range(target_module, target_module.body().source_span())?;
+            let end = body.end.saturating_sub("end".len());
+            if source.get(end..body.end) != Some("end") {
+                return Err(source_error(
+                    target_module,
+                    "source file has changed since the module was loaded",
+                ));
+            }
+            let line = line_start(source, end);
+            let (at, text) = if source[line..end].trim().is_empty() {
+                let previous = line_start(source, line.saturating_sub(1));
+                if source[previous..line].trim().is_empty() {
+                    (line, format!("{moved_text}\n\n"))
+                } else {
+                    (line, format!("\n{moved_text}\n"))
+                }
+            } else {
+                (end, format!("\n{moved_text}\n"))
+            };
+            edits.add(file, TextEdit::insert(at, text));
+        }
+        None => {
+            let imports = required_imports
+                .remove(to)
+                .map(|imports| {
+                    imports
+                        .into_iter()
+                        .filter(|import| import != to)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let imports = if imports.is_empty() {
+                String::new()
+            } else {
+                format!("  {}\n\n", import_statement(&imports))
+            };
+            edits.create_file(
+                new_module_file.unwrap(),
+                format!("module {to} is\n\n{imports}{moved_text}\n\nend\n"),
+            );
+        }
+    }
+
+    // Add any imports now required.
+    for (context, imports) in required_imports {
+        let module = cache
+            .get(&context)
+            .ok_or_else(|| module_not_found(context.as_ref()))?;
+        let existing = imported_modules_after_move(module, from, &moved_names, to);
+        let imports = imports
+            .into_iter()
+            .filter(|import| *import != context && !existing.contains(import))
+            .collect::<Vec<_>>();
+        if !imports.is_empty() {
+            let removed: &[Range<usize>] = if &context == to {
+                &removed_imports
+            } else {
+                &[]
+            };
+            let (file, _) = sources.source_for(module)?;
+            edits.add(file, add_import(module, &imports, removed)?);
+        }
+    }
+
+    Ok(edits)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn check_target(
+    target_module: &Module,
+    from: &Identifier,
+    definitions: &[&Definition],
+) -> Result<(), Error> {
+    let file_id = target_module.file_id().copied().unwrap_or_default();
+    for definition in definitions {
+        let name = definition.name();
+        if let Some(existing) = target_module.resolve_local(name) {
+            let existing = span_range(existing.name().source_span());
+            return Err(duplicate_definition(file_id, existing.clone(), existing).into());
+        }
+        if let Some(existing) = target_module
+            .imported_types()
+            .into_iter()
+            .find(|imported| imported.member() == name && imported.module() != from)
+        {
+            let existing = span_range(existing.source_span());
+            return Err(duplicate_definition_import(file_id, existing.clone(), existing).into());
+        }
+    }
+    Ok(())
+}
+
+///
+/// Remove the member import at `range`, if it is the only import in its statement remove the
+/// entire statement.
+///
+fn remove_import(module: &Module, source: &str, range: &Range<usize>) -> Result<TextEdit, Error> {
+    let statement = module.body().imports().find(|statement| {
+        let statement = span_range(statement.source_span());
+        statement.start <= range.start && range.end <= statement.end
+    });
+    match statement {
+        Some(statement) if statement.imports().count() == 1 => {
+            let statement = span_range(statement.source_span());
+            source_text(module, source, &statement)?;
+            let start = line_start(source, statement.start);
+            Ok(TextEdit::delete(
+                start..removal_end(source, start, statement.end),
+            ))
+        }
+        _ => {
+            let end =
+                range.end + source[range.end..].len() - source[range.end..].trim_start().len();
+            Ok(TextEdit::delete(range.start..end))
+        }
+    }
+}
+
+///
+/// Return the modules imported by `module` once member imports of the moved definitions have
+/// been rewritten, or removed.
+///
+fn imported_modules_after_move<'a>(
+    module: &'a Module,
+    from: &Identifier,
+    moved_names: &HashSet<&Identifier>,
+    to: &'a Identifier,
+) -> HashSet<&'a Identifier> {
+    module
+        .body()
+        .imports()
+        .flat_map(|statement| statement.imports())
+        .map(|import| match import {
+            Import::Module(imported) => imported.name(),
+            Import::Member(imported)
+                if imported.module() == from && moved_names.contains(imported.member()) =>
+            {
+                to
+            }
+            Import::Member(imported) => imported.module(),
+        })
+        .collect()
+}
+
+///
+/// Add an import statement for `imports` after the last import statement not in `removed`, or
+/// at the start of the module body.
+///
+fn add_import(
+    module: &Module,
+    imports: &[Identifier],
+    removed: &[Range<usize>],
+) -> Result<TextEdit, Error> {
+    let statement = import_statement(imports);
+    let last = module.body().imports().filter(|statement| {
+        let start = span_range(statement.source_span()).start;
+        !removed.iter().any(|range| range.contains(&start))
+    });
+    match last.last() {
+        Some(last) => Ok(TextEdit::insert(
+            span_range(last.source_span()).end,
+            format!("\n  {statement}"),
+        )),
+        None => {
+            let body = required_range(module, module.body().source_span())?;
+            Ok(TextEdit::insert(
+                body.start + "is".len(),
+                format!("\n\n  {statement}"),
+            ))
+        }
+    }
+}
+
+fn import_statement(imports: &[Identifier]) -> String {
+    if imports.len() == 1 {
+        format!("import {}", imports[0])
+    } else {
+        format!(
+            "import [ {} ]",
+            imports
+                .iter()
+                .map(|import| import.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
+///
+/// Return the start of the line containing `position`, extended backwards over any lines that
+/// contain only a comment.
+///
+fn leading_comments_start(source: &str, position: usize) -> usize {
+    let mut start = line_start(source, position);
+    while start > 0 {
+        let previous = line_start(source, start - 1);
+        if source[previous..start].trim_start().starts_with(';') {
+            start = previous;
+        } else {
+            break;
+        }
+    }
+    start
+}
+
+///
+/// Return the end of the text to remove for a definition, this includes the end of the
+/// definition's last line and, if the definition was preceded by a blank line, one following
+/// blank line.
+///
+fn removal_end(source: &str, start: usize, end: usize) -> usize {
+    let end = next_line_start(source, end);
+    let preceded_by_blank = start > 0 && {
+        let previous = line_start(source, start - 1);
+        source[previous..start].trim().is_empty()
+    };
+    let next = next_line_start(source, end);
+    if preceded_by_blank && next > end && source[end..next].trim().is_empty() {
+        next
+    } else {
+        end
+    }
+}
+
+fn line_start(source: &str, position: usize) -> usize {
+    source[..position]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or_default()
+}
+
+fn line_text_start(source: &str, line_start: usize) -> usize {
+    let line = &source[line_start..];
+    line_start + line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+fn next_line_start(source: &str, position: usize) -> usize {
+    source[position..]
+        .find('\n')
+        .map(|i| position + i + 1)
+        .unwrap_or(source.len())
+}
+
+///
+/// Replace the indentation `indent`, from the start of each line in `text`, with the standard
+/// indentation of a definition within a module body.
+///
+fn reindent(text: &str, indent: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "  {}",
+                    line.strip_prefix(indent).unwrap_or(line.trim_start())
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,171 @@
+/*!
+This module provides the rename refactoring, renaming a definition and every reference to it
+across all modules in a store.
+
+Renaming produces an edit for the definition's name, for every member import of the definition,
+and for every reference to it, qualified or not, found in the store's [`ReferenceIndex`]. Before
+any edits are produced the new name is checked for collisions, in the defining module and in each
+module that imports the definition by name, using the same rules, and diagnostics, as the parser's
+duplicate definition checks.
+
+[`ReferenceIndex`]: crate::store::ReferenceIndex
+
+*/
+
+use crate::model::identifiers::{Identifier, QualifiedIdentifier};
+use crate::model::modules::Module;
+use crate::model::{HasName, HasSourceSpan, Span};
+use crate::refactor::{
+    required_range, source_error, span_range, SourceCache, SourceEdits, TextEdit,
+};
+use crate::store::{InMemoryModuleCache, ModuleStore, ReferenceKind};
+use sdml_errors::diagnostics::functions::{
+    definition_not_found, duplicate_definition, duplicate_definition_import, module_not_found,
+};
+use sdml_errors::Error;
+use tracing::trace;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Compute the edits required to rename the definition `name` to `new_name` in all modules in
+/// `cache`. Renaming a definition to its current name results in no edits.
+///
+/// # Errors
+///
+/// - `ModuleNotFound` or `DefinitionNotFound` if `name` cannot be resolved.
+/// - `DuplicateDefinitionName` if the defining module, or a module that imports the definition
+///   by name, already defines `new_name`.
+/// - `DuplicateDefinitionImport` if a module that imports the definition by name already
+///   imports a different definition named `new_name`.
+/// - An I/O error if any affected module has no source file, or the source file cannot be read,
+///   or no longer matches the loaded module.
+///
+pub fn rename_definition(
+    cache: &InMemoryModuleCache,
+    name: &QualifiedIdentifier,
+    new_name: &Identifier,
+) -> Result<SourceEdits, Error> {
+    trace!("rename_definition({name}, {new_name})");
+    let module = cache
+        .get(name.module())
+        .ok_or_else(|| module_not_found(name.module().as_ref()))?;
+    let file_id = module.file_id().copied().unwrap_or_default();
+    let definition = module
+        .resolve_local(name.member())
+        .ok_or_else(|| definition_not_found(file_id, None, name.to_string()))?;
+
+    let mut edits = SourceEdits::default();
+    if name.member() == new_name {
+        return Ok(edits);
+    }
+
+    if let Some(existing) = module.resolve_local(new_name) {
+        return Err(duplicate_definition(
+            file_id,
+            span_range(existing.name().source_span()),
+            span_range(definition.name().source_span()),
+        )
+        .into());
+    }
+    for site in cache
+        .references_to(name)
+        .filter(|site| site.kind() == ReferenceKind::Import)
+    {
+        if let Some(importer) = cache.get(site.module()) {
+            check_importer(importer, name, new_name, site.source_span())?;
+        }
+    }
+
+    let mut sources = SourceCache::default();
+    let old_name = name.member().as_ref();
+    rename_at(
+        &mut sources,
+        &mut edits,
+        module,
+        definition.name().source_span(),
+        old_name,
+        new_name,
+    )?;
+    for site in cache.references_to(name) {
+        let referencing = cache
+            .get(site.module())
+            .ok_or_else(|| module_not_found(site.module().as_ref()))?;
+        rename_at(
+            &mut sources,
+            &mut edits,
+            referencing,
+            site.source_span(),
+            old_name,
+            new_name,
+        )?;
+    }
+
+    Ok(edits)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn check_importer(
+    importer: &Module,
+    name: &QualifiedIdentifier,
+    new_name: &Identifier,
+    import_span: Option<&Span>,
+) -> Result<(), Error> {
+    let file_id = importer.file_id().copied().unwrap_or_default();
+    if let Some(existing) = importer.resolve_local(new_name) {
+        return Err(duplicate_definition(
+            file_id,
+            span_range(existing.name().source_span()),
+            span_range(import_span),
+        )
+        .into());
+    }
+    if let Some(existing) = importer
+        .imported_types()
+        .into_iter()
+        .find(|imported| imported.member() == new_name && *imported != name)
+    {
+        return Err(duplicate_definition_import(
+            file_id,
+            span_range(existing.source_span()),
+            span_range(import_span),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+///
+/// Add an edit replacing the identifier `old_name` at the end of `span`, this replaces only
+/// the member part of a qualified identifier.
+///
+fn rename_at(
+    sources: &mut SourceCache,
+    edits: &mut SourceEdits,
+    module: &Module,
+    span: Option<&Span>,
+    old_name: &str,
+    new_name: &Identifier,
+) -> Result<(), Error> {
+    let range = required_range(module, span)?;
+    let (file, source) = sources.source_for(module)?;
+    match source.get(range.clone()) {
+        Some(text) if text.ends_with(old_name) => {
+            let start = range.end - old_name.len();
+            edits.add(
+                file,
+                TextEdit::replace(start..range.end, new_name.to_string()),
+            );
+            Ok(())
+        }
+        _ => Err(source_error(
+            module,
+            "source file has changed since the module was loaded",
+        )),
+    }
+}
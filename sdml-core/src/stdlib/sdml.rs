@@ -63,6 +63,7 @@ pub const LANGUAGE: &str = "language";
 pub const STRING: &str = "string";
 pub const UNSIGNED: &str = "unsigned";
 
+pub const FIELD_NUMBER: &str = "fieldNumber";
 pub const HAS_NAME: &str = "hasName";
 pub const HAS_ANNOTATION: &str = "hasAnnotation";
 pub const HAS_CARDINALITY: &str = "hasCardinality";
@@ -169,6 +170,7 @@ pub fn module() -> Module {
                 .with_equivalent_class(qualid!(xsd::MODULE_NAME, xsd::LANGUAGE))
                 .into(),
             // Properties
+            rdf!(property FIELD_NUMBER, MODULE_IRI).into(),
             rdf!(property HAS_ANNOTATION, MODULE_IRI).into(),
             rdf!(property HAS_CARDINALITY, MODULE_IRI).into(),
             rdf!(property HAS_DEFINITION, MODULE_IRI).into(),
@@ -1,6 +1,9 @@
 /*!
 This module provides a trait for module *stores*, and an implementation for in-memory caches.
 
+The in-memory cache also maintains a [`ReferenceIndex`], a reverse index from each qualified
+name to the places in the cached modules that reference it.
+
 # Example
 
 ```
@@ -17,12 +20,21 @@ assert_eq!(true, store.contains(&xml_schema_module));
 
 */
 
-use crate::model::definitions::Definition;
+use crate::error::Error;
+use crate::model::annotations::Annotation;
+use crate::model::definitions::{Definition, PropertyDef, TypeClassDef, TypeVariant, ValueVariant};
 use crate::model::identifiers::{Identifier, IdentifierReference, QualifiedIdentifier};
+use crate::model::members::Member;
 use crate::model::modules::Module;
-use crate::model::HasName;
+use crate::model::values::ValueConstructor;
+use crate::model::walk::visit::{
+    walk_annotation, walk_definition, walk_member, walk_module, walk_property_def,
+    walk_type_variant, walk_value_variant, Visitor,
+};
+use crate::model::{HasName, HasSourceSpan, Span};
 use crate::stdlib;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 use url::Url;
 
 // ------------------------------------------------------------------------------------------------
@@ -128,6 +140,16 @@ pub trait ModuleStore {
         }
     }
 
+    ///
+    /// Returns `true` if the module named `name` is a library module, else `false`. Library
+    /// modules are provided to the store rather than loaded from source, they are not loaded
+    /// or validated and are allowed to contain library definitions. By default only the modules
+    /// of the standard library are library modules.
+    ///
+    fn is_library_module(&self, name: &Identifier) -> bool {
+        stdlib::is_library_module(name)
+    }
+
     ///
     /// Return the module name corresponding to the provided `url` if it exists, or else `None`.
     ///
@@ -198,10 +220,92 @@ pub trait ModuleStore {
 /// An implementation of [`ModuleStore`] that has no persistence it simply acts as an in-process
 /// cache.
 ///
+/// This cache also acts as a registry of library modules, any module inserted that is marked as a
+/// library module (see [`Module::is_library_module`]) is treated in the same way as the standard
+/// library modules.
+///
+/// # Example
+///
+/// ```
+/// use sdml_core::model::identifiers::Identifier;
+/// use sdml_core::model::modules::Module;
+/// use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+/// use std::str::FromStr;
+///
+/// let corporate = Identifier::from_str("corporate").unwrap();
+/// let store = InMemoryModuleCache::default()
+///     .with_stdlib()
+///     .with_library_module(Module::empty(corporate.clone()));
+///
+/// assert!(store.is_library_module(&corporate));
+/// assert!(store.get(&corporate).unwrap().is_library_module());
+/// ```
+///
 #[derive(Clone, Debug, Default)]
 pub struct InMemoryModuleCache {
     uri_map: HashMap<Url, Identifier>,
     modules: HashMap<Identifier, Module>,
+    library_modules: HashSet<Identifier>,
+    references: ReferenceIndex,
+}
+
+///
+/// A reverse index of references, this maps the qualified name of each referenced definition to
+/// the places in a set of modules where it is referenced.
+///
+/// The index is built from the [`References`] trait for members and constraints, and from the
+/// definitions, imports, and annotations of each module. Unqualified references are qualified
+/// with the name of the referencing module, unless the module imports a member with that name.
+///
+/// # Example
+///
+/// ```
+/// use sdml_core::model::identifiers::QualifiedIdentifier;
+/// use sdml_core::store::{InMemoryModuleCache, ReferenceKind};
+/// use std::str::FromStr;
+///
+/// let cache = InMemoryModuleCache::default().with_stdlib();
+/// let name = QualifiedIdentifier::from_str("rdfs:Resource").unwrap();
+///
+/// for site in cache.references_to(&name) {
+///     println!("{site} ({:?})", site.kind());
+/// }
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceIndex {
+    targets: HashMap<QualifiedIdentifier, Vec<ReferenceSite>>,
+}
+
+///
+/// The place in a module where a definition is referenced.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferenceSite {
+    kind: ReferenceKind,
+    module: Identifier,
+    definition: Option<Identifier>,
+    member: Option<Identifier>,
+    span: Option<Span>,
+}
+
+///
+/// The kind of model element containing a reference.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReferenceKind {
+    /// A member import in the module's import statements.
+    Import,
+    /// A definition, for example a datatype's base type or an event's source entity.
+    Definition,
+    /// A member's type, or a property reference member.
+    Member,
+    /// A type variant of a union.
+    Variant,
+    /// An annotation property's name or value.
+    Annotation,
+    /// A formal constraint.
+    Constraint,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -257,12 +361,21 @@ impl ModuleStore for InMemoryModuleCache {
             self.uri_map
                 .insert(base_uri.value().clone(), module.name().clone());
         }
+        if module.is_library_module() {
+            self.library_modules.insert(module.name().clone());
+        } else {
+            self.library_modules.remove(module.name());
+        }
+        self.references.remove_module(module.name());
+        self.references.insert_module(&module);
         self.modules.insert(module.name().clone(), module);
     }
 
     fn remove(&mut self, name: &Identifier) -> bool {
         if self.modules.remove(name).is_some() {
-            self.uri_map.retain(|_, v| v == name);
+            self.uri_map.retain(|_, v| v != name);
+            self.library_modules.remove(name);
+            self.references.remove_module(name);
             true
         } else {
             false
@@ -272,12 +385,18 @@ impl ModuleStore for InMemoryModuleCache {
     fn remove_by_uri(&mut self, uri: &Url) -> bool {
         if let Some(name) = self.uri_map.remove(uri) {
             self.modules.remove(&name);
+            self.library_modules.remove(&name);
+            self.references.remove_module(&name);
             true
         } else {
             false
         }
     }
 
+    fn is_library_module(&self, name: &Identifier) -> bool {
+        self.library_modules.contains(name) || stdlib::is_library_module(name)
+    }
+
     fn uri_to_module_name(&self, url: &Url) -> Option<&Identifier> {
         self.uri_map.get(url)
     }
@@ -317,4 +436,365 @@ impl InMemoryModuleCache {
         self_mut.insert(module);
         self_mut
     }
+
+    ///
+    /// Builder-like function to add a module, as a library module, to a newly constructed cache.
+    ///
+    pub fn with_library_module(self, module: Module) -> Self {
+        let mut self_mut = self;
+        self_mut.insert_library_module(module);
+        self_mut
+    }
+
+    ///
+    /// Insert `module` into the cache, marking it as a library module. Library modules behave
+    /// exactly as the standard library modules; they are not loaded by a loader, not validated,
+    /// and may contain library definitions.
+    ///
+    pub fn insert_library_module(&mut self, module: Module) {
+        let mut module = module;
+        module.set_library_module(true);
+        self.insert(module);
+    }
+
+    ///
+    /// Return an iterator over the names of all library modules in this cache, this includes
+    /// any standard library modules.
+    ///
+    pub fn library_module_names(&self) -> impl Iterator<Item = &Identifier> {
+        self.library_modules.iter()
+    }
+
+    ///
+    /// Return the index of references across all modules in this cache.
+    ///
+    pub fn reference_index(&self) -> &ReferenceIndex {
+        &self.references
+    }
+
+    ///
+    /// Return all the places in this cache's modules that reference `name`, see
+    /// [`ReferenceIndex::references_to`].
+    ///
+    pub fn references_to(
+        &self,
+        name: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = &ReferenceSite> {
+        self.references.references_to(name)
+    }
+
+    ///
+    /// Rebuild the index entries for the module named `name`; this is only necessary if the
+    /// module has been changed in place using [`ModuleStore::get_mut`].
+    ///
+    pub fn reindex_module(&mut self, name: &Identifier) {
+        self.references.remove_module(name);
+        if let Some(module) = self.modules.get(name) {
+            self.references.insert_module(module);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ReferenceIndex {
+    ///
+    /// Construct an index from all of the modules in `store`.
+    ///
+    pub fn from_store(store: &impl ModuleStore) -> Self {
+        let mut index = Self::default();
+        store
+            .modules()
+            .for_each(|module| index.insert_module(module));
+        index
+    }
+
+    ///
+    /// Returns `true` if no references are present in the index.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    ///
+    /// Return the number of distinct referenced names in the index.
+    ///
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    ///
+    /// Returns `true` if `name` is referenced by any indexed module.
+    ///
+    pub fn is_referenced(&self, name: &QualifiedIdentifier) -> bool {
+        self.targets.contains_key(name)
+    }
+
+    ///
+    /// Return all the places that reference `name`, ordered by module, then by their position in
+    /// the module.
+    ///
+    pub fn references_to(
+        &self,
+        name: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = &ReferenceSite> {
+        self.targets.get(name).into_iter().flatten()
+    }
+
+    ///
+    /// Return all referenced names in the index.
+    ///
+    pub fn referenced_names(&self) -> impl Iterator<Item = &QualifiedIdentifier> {
+        self.targets.keys()
+    }
+
+    ///
+    /// Add all the references made by `module` to the index.
+    ///
+    pub fn insert_module(&mut self, module: &Module) {
+        let mut collector = ReferenceCollector::new(module);
+        // the collector itself never returns an error.
+        let _ = walk_module(&mut collector, module);
+        let mut touched: HashSet<QualifiedIdentifier> = Default::default();
+        for (target, site) in collector.found {
+            self.targets.entry(target.clone()).or_default().push(site);
+            touched.insert(target);
+        }
+        for target in touched {
+            if let Some(sites) = self.targets.get_mut(&target) {
+                sites.sort_by(|lhs, rhs| lhs.sort_key().cmp(&rhs.sort_key()));
+            }
+        }
+    }
+
+    ///
+    /// Remove all the references made by the module named `name` from the index.
+    ///
+    pub fn remove_module(&mut self, name: &Identifier) {
+        self.targets.retain(|_, sites| {
+            sites.retain(|site| site.module() != name);
+            !sites.is_empty()
+        });
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ReferenceSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.module)?;
+        if let Some(definition) = &self.definition {
+            write!(f, ":{definition}")?;
+            if let Some(member) = &self.member {
+                write!(f, ".{member}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ReferenceSite {
+    pub fn kind(&self) -> ReferenceKind {
+        self.kind
+    }
+
+    ///
+    /// Return the name of the module containing the reference.
+    ///
+    pub fn module(&self) -> &Identifier {
+        &self.module
+    }
+
+    ///
+    /// Return the name of the definition containing the reference, or `None` if the reference
+    /// is made by the module itself, in an import or a module annotation.
+    ///
+    pub fn definition(&self) -> Option<&Identifier> {
+        self.definition.as_ref()
+    }
+
+    ///
+    /// Return the name of the member, or variant, containing the reference if any.
+    ///
+    pub fn member(&self) -> Option<&Identifier> {
+        self.member.as_ref()
+    }
+
+    ///
+    /// Return the source span of the reference itself, if known.
+    ///
+    pub fn source_span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+
+    fn sort_key(&self) -> (&Identifier, usize, ReferenceKind) {
+        (
+            &self.module,
+            self.span
+                .map(|span| span.start().byte())
+                .unwrap_or_default(),
+            self.kind,
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ReferenceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Import => "import",
+                Self::Definition => "definition",
+                Self::Member => "member",
+                Self::Variant => "variant",
+                Self::Annotation => "annotation",
+                Self::Constraint => "constraint",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Collects every reference made by a module, each with its own span; the `kind` is that of the
+/// innermost model element being visited.
+///
+struct ReferenceCollector<'a> {
+    module: &'a Module,
+    imported: HashMap<&'a Identifier, &'a QualifiedIdentifier>,
+    kind: ReferenceKind,
+    definition: Option<&'a Identifier>,
+    member: Option<&'a Identifier>,
+    found: Vec<(QualifiedIdentifier, ReferenceSite)>,
+}
+
+impl<'a> ReferenceCollector<'a> {
+    fn new(module: &'a Module) -> Self {
+        Self {
+            module,
+            imported: module
+                .imported_types()
+                .into_iter()
+                .map(|name| (name.member(), name))
+                .collect(),
+            kind: ReferenceKind::Definition,
+            definition: None,
+            member: None,
+            found: Default::default(),
+        }
+    }
+
+    fn add(&mut self, reference: &IdentifierReference) {
+        let target = match reference {
+            IdentifierReference::QualifiedIdentifier(v) => v.clone(),
+            IdentifierReference::Identifier(v) => match self.imported.get(v) {
+                Some(imported) if self.module.resolve_local(v).is_none() => (*imported).clone(),
+                _ => QualifiedIdentifier::new(self.module.name().clone(), v.clone()),
+            },
+        };
+        self.found.push((
+            target,
+            ReferenceSite {
+                kind: self.kind,
+                module: self.module.name().clone(),
+                definition: self.definition.cloned(),
+                member: self.member.cloned(),
+                span: reference.source_span().copied(),
+            },
+        ));
+    }
+
+    fn with_kind<F>(&mut self, kind: ReferenceKind, walk: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Self) -> Result<(), Error>,
+    {
+        let outer = std::mem::replace(&mut self.kind, kind);
+        let result = walk(self);
+        self.kind = outer;
+        result
+    }
+
+    fn with_member<F>(&mut self, member: &'a Identifier, walk: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Self) -> Result<(), Error>,
+    {
+        self.member = Some(member);
+        let result = walk(self);
+        self.member = None;
+        result
+    }
+}
+
+impl<'a> Visitor<'a> for ReferenceCollector<'a> {
+    fn visit_member_import(&mut self, node: &'a QualifiedIdentifier) -> Result<(), Error> {
+        self.with_kind(ReferenceKind::Import, |this| {
+            this.add(&node.clone().into());
+            Ok(())
+        })
+    }
+
+    fn visit_annotation(&mut self, node: &'a Annotation) -> Result<(), Error> {
+        let kind = match node {
+            Annotation::Property(_) => ReferenceKind::Annotation,
+            Annotation::Constraint(_) => ReferenceKind::Constraint,
+        };
+        self.with_kind(kind, |this| walk_annotation(this, node))
+    }
+
+    fn visit_definition(&mut self, node: &'a Definition) -> Result<(), Error> {
+        self.definition = Some(node.name());
+        let result = self.with_kind(ReferenceKind::Definition, |this| {
+            walk_definition(this, node)
+        });
+        self.definition = None;
+        result
+    }
+
+    fn visit_type_class_def(&mut self, _node: &'a TypeClassDef) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn visit_member(&mut self, node: &'a Member) -> Result<(), Error> {
+        self.with_member(node.name(), |this| {
+            this.with_kind(ReferenceKind::Member, |this| walk_member(this, node))
+        })
+    }
+
+    fn visit_identity_member(&mut self, node: &'a Member) -> Result<(), Error> {
+        self.visit_member(node)
+    }
+
+    fn visit_property_def(&mut self, node: &'a PropertyDef) -> Result<(), Error> {
+        self.with_kind(ReferenceKind::Member, |this| walk_property_def(this, node))
+    }
+
+    fn visit_value_variant(&mut self, node: &'a ValueVariant) -> Result<(), Error> {
+        self.with_member(node.name(), |this| walk_value_variant(this, node))
+    }
+
+    fn visit_type_variant(&mut self, node: &'a TypeVariant) -> Result<(), Error> {
+        self.with_member(node.name(), |this| {
+            this.with_kind(ReferenceKind::Variant, |this| walk_type_variant(this, node))
+        })
+    }
+
+    fn visit_value_constructor(&mut self, node: &'a ValueConstructor) -> Result<(), Error> {
+        // the type of a value constructor is always a definition, even within a constraint.
+        self.add(node.type_name());
+        Ok(())
+    }
+
+    fn visit_identifier_reference(&mut self, node: &'a IdentifierReference) -> Result<(), Error> {
+        // unqualified names in a formal constraint are variables, not definitions.
+        if self.kind != ReferenceKind::Constraint || node.is_qualified_identifier() {
+            self.add(node);
+        }
+        Ok(())
+    }
 }
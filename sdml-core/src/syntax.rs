@@ -126,9 +126,11 @@ pub const NODE_KIND_STRING: &str = "string";
 pub const NODE_KIND_STRUCTURE_BODY: &str = "structure_body";
 pub const NODE_KIND_STRUCTURE_DEF: &str = "structure_def";
 pub const NODE_KIND_STRUCTURE_MEMBER: &str = "structure_member";
+pub const NODE_KIND_STRUCTURED_BODY: &str = "structured_body";
 
 pub const NODE_KIND_TERM: &str = "term";
 pub const NODE_KIND_TYPE_CLASS_ARGUMENTS: &str = "type_class_arguments";
+pub const NODE_KIND_TYPE_CLASS_BODY: &str = "type_class_body";
 pub const NODE_KIND_TYPE_CLASS_DEF: &str = "type_class_def";
 pub const NODE_KIND_TYPE_CLASS_REFERENCE: &str = "type_class_reference";
 pub const NODE_KIND_TYPE_ITERATOR: &str = "type_iterator";
@@ -8,9 +8,10 @@ use codespan_reporting::term::termcolor::ColorChoice;
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum UseColor {
     Always,
+    #[default]
     Auto,
     Never,
 }
@@ -42,12 +43,6 @@ const COLOR_CLI_COLOR_OFF: &str = "0";
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for UseColor {
-    fn default() -> Self {
-        Self::Auto
-    }
-}
-
 impl From<UseColor> for ColorChoice {
     fn from(value: UseColor) -> Self {
         match value {
@@ -23,9 +23,10 @@ pub type Diagnostic = codespan_reporting::diagnostic::Diagnostic<FileId>;
 ///
 /// This value determines the level of diagnostics to be emitted by **any** reporter.
 ///
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum SeverityFilter {
     Bug,
+    #[default]
     Error,
     Warning,
     Note,
@@ -37,12 +38,6 @@ pub enum SeverityFilter {
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for SeverityFilter {
-    fn default() -> Self {
-        Self::Error
-    }
-}
-
 impl Display for SeverityFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -14,10 +14,10 @@ use codespan_reporting::{
         Chars, Config,
     },
 };
-use std::cell::RefCell;
 use std::fmt::Debug;
 use std::io::Write;
 use std::ops::{Add, AddAssign};
+use std::sync::Mutex;
 use tracing::{error, info, warn};
 
 // ------------------------------------------------------------------------------------------------
@@ -27,7 +27,10 @@ use tracing::{error, info, warn};
 ///
 /// This trait describes a facility to report diagnostics.
 ///
-pub trait Reporter: Debug {
+/// Reporters may be shared by a loader across threads, diagnostics are emitted from a single
+/// thread at a time, but implementations must be both `Send` and `Sync`.
+///
+pub trait Reporter: Debug + Send + Sync {
     ///
     /// Emit a diagnostic, providing a mapping for source code.
     ///
@@ -89,7 +92,7 @@ pub struct ReportCounters {
 pub struct StandardStreamReporter {
     stream: StandardStream,
     filter: SeverityFilter,
-    counters: RefCell<ReportCounters>,
+    counters: Mutex<ReportCounters>,
     config: Config,
 }
 
@@ -97,13 +100,13 @@ pub struct StandardStreamReporter {
 pub struct CompactStreamReporter {
     stream: StandardStream,
     filter: SeverityFilter,
-    counters: RefCell<ReportCounters>,
+    counters: Mutex<ReportCounters>,
 }
 
 #[derive(Debug, Default)]
 pub struct BailoutReporter {
     filter: SeverityFilter,
-    counters: RefCell<ReportCounters>,
+    counters: Mutex<ReportCounters>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -212,7 +215,7 @@ impl Reporter for StandardStreamReporter {
     fn emit(&self, diagnostic: &Diagnostic, sources: &SourceFiles) -> Result<(), Error> {
         if self.is_enabled(diagnostic.severity) {
             self.log(diagnostic);
-            let mut counters = self.counters.borrow_mut();
+            let mut counters = self.counters.lock().unwrap();
             counters.report(diagnostic.severity);
             Ok(emit(
                 &mut self.stream.lock(),
@@ -226,12 +229,12 @@ impl Reporter for StandardStreamReporter {
     }
 
     fn counters(&self) -> ReportCounters {
-        *self.counters.borrow()
+        *self.counters.lock().unwrap()
     }
 
     fn done(&self, module_name: Option<String>) -> Result<ReportCounters, Error> {
         self.done_stats(module_name)?;
-        let old_counters = self.counters.replace(ReportCounters::default());
+        let old_counters = std::mem::take(&mut *self.counters.lock().unwrap());
         Ok(old_counters)
     }
 
@@ -275,7 +278,7 @@ impl StandardStreamReporter {
     }
 
     fn done_stats(&self, module_name: Option<String>) -> Result<(), Error> {
-        let counters = self.counters.borrow();
+        let counters = self.counters();
         if counters.total() > 0 {
             let severity = if counters.bugs > 0 {
                 Severity::Bug
@@ -358,7 +361,7 @@ impl Reporter for CompactStreamReporter {
         use codespan_reporting::files::Files;
         if self.is_enabled(diagnostic.severity) {
             self.log(diagnostic);
-            let mut counters = self.counters.borrow_mut();
+            let mut counters = self.counters.lock().unwrap();
             counters.report(diagnostic.severity);
             let mut stream = self.stream.lock();
             let (file_name, start, end) = if let Some(label) = diagnostic.labels.first() {
@@ -398,11 +401,11 @@ impl Reporter for CompactStreamReporter {
     }
 
     fn counters(&self) -> ReportCounters {
-        *self.counters.borrow()
+        *self.counters.lock().unwrap()
     }
 
     fn done(&self, _: Option<String>) -> Result<ReportCounters, Error> {
-        let old_counters = self.counters.replace(ReportCounters::default());
+        let old_counters = std::mem::take(&mut *self.counters.lock().unwrap());
         Ok(old_counters)
     }
 
@@ -427,7 +430,7 @@ impl Reporter for BailoutReporter {
     fn emit(&self, diagnostic: &Diagnostic, _: &SourceFiles) -> Result<(), Error> {
         if self.is_enabled(diagnostic.severity) {
             self.log(diagnostic);
-            let mut counters = self.counters.borrow_mut();
+            let mut counters = self.counters.lock().unwrap();
             counters.report(diagnostic.severity);
             Err(diagnostic.clone().into())
         } else {
@@ -436,11 +439,11 @@ impl Reporter for BailoutReporter {
     }
 
     fn counters(&self) -> ReportCounters {
-        *self.counters.borrow()
+        *self.counters.lock().unwrap()
     }
 
     fn done(&self, _: Option<String>) -> Result<ReportCounters, Error> {
-        let old_counters = self.counters.replace(ReportCounters::default());
+        let old_counters = std::mem::take(&mut *self.counters.lock().unwrap());
         Ok(old_counters)
     }
 
@@ -16,7 +16,7 @@ use sdml_core::model::identifiers::Identifier;
 use sdml_core::model::modules::HeaderValue;
 use sdml_core::model::modules::Module;
 use sdml_core::model::HasName;
-use sdml_core::{stdlib::is_library_module, store::ModuleStore};
+use sdml_core::store::ModuleStore;
 use std::collections::HashSet;
 use std::io::Write;
 use std::path::PathBuf;
@@ -42,7 +42,7 @@ pub struct DependencyViewOptions {
 ///
 /// The supported variations of dependency view.
 ///
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum DependencyViewRepresentation {
     ///
     /// This representation is most intended for command-line tools, it displays the output in a
@@ -67,6 +67,7 @@ pub enum DependencyViewRepresentation {
     /// └── xsd
     /// ```
     ///
+    #[default]
     TextTree,
     ///
     /// This representation gives a
@@ -139,6 +140,7 @@ struct Node<'a> {
     name: &'a Identifier,
     base_uri: Option<&'a HeaderValue<Url>>,
     version_uri: Option<&'a HeaderValue<Url>>,
+    is_library: bool,
     children: Option<Vec<Node<'a>>>,
 }
 
@@ -146,12 +148,6 @@ struct Node<'a> {
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for DependencyViewRepresentation {
-    fn default() -> Self {
-        Self::TextTree
-    }
-}
-
 impl DependencyViewOptions {
     pub fn with_depth(self, depth: usize) -> Self {
         Self { depth, ..self }
@@ -294,7 +290,7 @@ impl DependencyViewGenerator {
 
         if !seen.contains(module.name()) {
             writer.write_all(
-                self.write_gv_node(module.name(), true, is_library_module(module.name()))
+                self.write_gv_node(module.name(), true, module.is_library_module())
                     .as_bytes(),
             )?;
         }
@@ -304,7 +300,7 @@ impl DependencyViewGenerator {
                 self.write_gv_node(
                     module_name,
                     module_name == module.name(),
-                    is_library_module(module_name),
+                    cache.is_library_module(module_name),
                 )
                 .as_bytes(),
             )?;
@@ -408,14 +404,14 @@ impl DependencyViewGenerator {
         node: &'a Node<'_>,
         list: &mut Vec<(&'a HeaderValue<Url>, &'a HeaderValue<Url>)>,
     ) {
-        if node.base_uri.is_some() {
+        if let Some(node_base_uri) = node.base_uri {
             if let Some(children) = &node.children {
                 for child in children {
                     if let Some(child_base_uri) = child.base_uri {
                         if let Some(child_version_uri) = child.version_uri {
-                            list.push((node.base_uri.unwrap(), child_version_uri));
+                            list.push((node_base_uri, child_version_uri));
                         } else {
-                            list.push((node.base_uri.unwrap(), child_base_uri));
+                            list.push((node_base_uri, child_base_uri));
                         }
                     }
                 }
@@ -452,9 +448,14 @@ impl<'a> Node<'a> {
                         imported,
                         cached.base_uri(),
                         imported_version_uri,
+                        cached.is_library_module(),
                     ));
                 } else {
-                    children.push(Self::from_name_only(imported, imported_version_uri));
+                    children.push(Self::from_name_only(
+                        imported,
+                        imported_version_uri,
+                        cache.is_library_module(imported),
+                    ));
                 }
             } else {
                 seen.insert(imported);
@@ -467,7 +468,11 @@ impl<'a> Node<'a> {
                         depth - 1,
                     ));
                 } else {
-                    children.push(Self::from_name_only(imported, imported_version_uri));
+                    children.push(Self::from_name_only(
+                        imported,
+                        imported_version_uri,
+                        cache.is_library_module(imported),
+                    ));
                 }
             }
         }
@@ -476,23 +481,30 @@ impl<'a> Node<'a> {
             name: module.name(),
             base_uri: module.base_uri(),
             version_uri,
+            is_library: module.is_library_module(),
             children: Some(children),
         }
     }
 
-    fn from_name_only(module: &'a Identifier, version_uri: Option<&'a HeaderValue<Url>>) -> Self {
-        Self::from_name(module, None, version_uri)
+    fn from_name_only(
+        module: &'a Identifier,
+        version_uri: Option<&'a HeaderValue<Url>>,
+        is_library: bool,
+    ) -> Self {
+        Self::from_name(module, None, version_uri, is_library)
     }
 
     fn from_name(
         module: &'a Identifier,
         base_uri: Option<&'a HeaderValue<Url>>,
         version_uri: Option<&'a HeaderValue<Url>>,
+        is_library: bool,
     ) -> Self {
         Self {
             name: module,
             base_uri,
             version_uri,
+            is_library,
             children: None,
         }
     }
@@ -525,7 +537,7 @@ impl<'a> Node<'a> {
             if is_root {
                 style = style.bold();
             }
-            if is_library_module(self.name) {
+            if self.is_library {
                 style = style.dimmed().italic();
             }
 
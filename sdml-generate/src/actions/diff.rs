@@ -0,0 +1,1061 @@
+/*!
+Compute a structural difference between two versions of a module.
+
+Unlike a text diff, elements are compared by identity, definitions and members by name, variants
+by name, and annotations by property name, so that reordering or reformatting a module produces no
+changes. Each change is classified as either *breaking*, meaning that data or code written against
+the old module may not be valid against the new one, or *compatible*.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_generate::actions::diff::{diff_modules, write_diff};
+use std::io::stdout;
+# use sdml_core::model::identifiers::Identifier;
+# fn load_module() -> Module { Module::empty(Identifier::new_unchecked("example")) }
+
+let old_module = load_module();
+let new_module = load_module();
+
+let diff = diff_modules(&old_module, &new_module);
+assert!(!diff.is_breaking());
+write_diff(&diff, &mut stdout()).expect("write to stdout failed");
+```
+
+*/
+
+use sdml_core::error::Error;
+use sdml_core::model::annotations::{Annotation, HasAnnotations};
+use sdml_core::model::constraints::{Constraint, ConstraintBody};
+use sdml_core::model::definitions::{
+    Definition, EnumDef, HasMembers, HasVariants, StructureBody, TypeClassDef, UnionDef,
+};
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::members::{Cardinality, Member, MemberKind, Ordering, Uniqueness};
+use sdml_core::model::modules::{HeaderValue, Import, Module};
+use sdml_core::model::{HasBody, HasName, HasNameReference, HasOptionalBody, HasSourceSpan};
+use sdml_core::stdlib::xsd;
+use semver::Version;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The set of changes between an old, and a new, version of a module.
+///
+#[derive(Clone, Debug, Serialize)]
+pub struct ModuleDiff {
+    #[serde(serialize_with = "serialize_display")]
+    old_module: Identifier,
+    #[serde(serialize_with = "serialize_display")]
+    new_module: Identifier,
+    changes: Vec<Change>,
+}
+
+///
+/// A single change to an element of a module.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Change {
+    change: ChangeKind,
+    element: ElementKind,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_value: Option<String>,
+    compatibility: Compatibility,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+///
+/// The kind of model element that a [`Change`] applies to.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ElementKind {
+    Module,
+    Header,
+    Import,
+    Definition,
+    BaseType,
+    Member,
+    Cardinality,
+    Type,
+    Variant,
+    Annotation,
+    Constraint,
+}
+
+///
+/// Whether a [`Change`] may invalidate data, or code, written against the old module.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    Compatible,
+    Breaking,
+}
+
+///
+/// The semantic version increment required by, or provided by, a set of changes.
+///
+/// Following the Cargo convention, for versions before `1.0.0` a breaking change requires a new
+/// minor version and any other change a new patch version.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionBump {
+    #[default]
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Compare `old` and `new`, returning the changes required to turn the first into the second.
+///
+pub fn diff_modules(old: &Module, new: &Module) -> ModuleDiff {
+    let mut diff = ModuleDiff {
+        old_module: old.name().clone(),
+        new_module: new.name().clone(),
+        changes: Default::default(),
+    };
+    diff.module(old, new);
+    diff
+}
+
+///
+/// Write `diff` as text, one line per change followed by a summary line.
+///
+pub fn write_diff<W: Write>(diff: &ModuleDiff, w: &mut W) -> Result<(), Error> {
+    for change in diff.changes() {
+        writeln!(w, "{change}")?;
+    }
+    if diff.is_empty() {
+        writeln!(w, "No changes.")?;
+    } else {
+        writeln!(
+            w,
+            "{} changes, {} breaking.",
+            diff.len(),
+            diff.breaking_changes().count()
+        )?;
+    }
+    Ok(())
+}
+
+///
+/// Write `diff` as a JSON object.
+///
+pub fn write_diff_json<W: Write>(diff: &ModuleDiff, pretty: bool, w: &mut W) -> Result<(), Error> {
+    let result = if pretty {
+        serde_json::to_writer_pretty(w, diff)
+    } else {
+        serde_json::to_writer(w, diff)
+    };
+    result.map_err(|e| crate::errors::into_generator_error("JSON", e))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ModuleDiff {
+    pub fn old_module(&self) -> &Identifier {
+        &self.old_module
+    }
+
+    pub fn new_module(&self) -> &Identifier {
+        &self.new_module
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter()
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes().filter(|change| change.is_breaking())
+    }
+
+    ///
+    /// Returns `true` if any change in this diff is breaking.
+    ///
+    pub fn is_breaking(&self) -> bool {
+        self.breaking_changes().next().is_some()
+    }
+
+    ///
+    /// Returns the version increment required by the changes in this diff; changes to the
+    /// version headers themselves are ignored. Breaking changes require a major version,
+    /// additions a minor version, and any other change a patch version.
+    ///
+    pub fn required_bump(&self) -> VersionBump {
+        self.changes()
+            .filter(|change| {
+                !(change.element == ElementKind::Header
+                    && (change.path == HEADER_VERSION_INFO || change.path == HEADER_VERSION_URI))
+            })
+            .map(|change| match (change.compatibility, change.change) {
+                (Compatibility::Breaking, _) => VersionBump::Major,
+                (_, ChangeKind::Added) => VersionBump::Minor,
+                _ => VersionBump::Patch,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn push(
+        &mut self,
+        change: ChangeKind,
+        element: ElementKind,
+        path: impl Into<String>,
+        values: (Option<String>, Option<String>),
+        compatibility: Compatibility,
+    ) {
+        self.changes.push(Change {
+            change,
+            element,
+            path: path.into(),
+            old_value: values.0,
+            new_value: values.1,
+            compatibility,
+        })
+    }
+
+    fn module(&mut self, old: &Module, new: &Module) {
+        if old.name() != new.name() {
+            self.push(
+                ChangeKind::Changed,
+                ElementKind::Module,
+                "",
+                changed(old.name(), new.name()),
+                Compatibility::Breaking,
+            );
+        }
+        self.header(
+            "base",
+            declared(old.base_uri()),
+            declared(new.base_uri()),
+            Compatibility::Breaking,
+        );
+        self.header(
+            HEADER_VERSION_INFO,
+            declared(old.version_info()),
+            declared(new.version_info()),
+            Compatibility::Compatible,
+        );
+        self.header(
+            HEADER_VERSION_URI,
+            declared(old.version_uri()),
+            declared(new.version_uri()),
+            Compatibility::Compatible,
+        );
+
+        let old_imports = module_imports(old);
+        let new_imports = module_imports(new);
+        for import in old_imports.difference(&new_imports) {
+            self.push(
+                ChangeKind::Removed,
+                ElementKind::Import,
+                import,
+                (None, None),
+                Compatibility::Compatible,
+            );
+        }
+        for import in new_imports.difference(&old_imports) {
+            self.push(
+                ChangeKind::Added,
+                ElementKind::Import,
+                import,
+                (None, None),
+                Compatibility::Compatible,
+            );
+        }
+
+        self.annotations("", old.body().annotations(), new.body().annotations());
+
+        let old_definitions = by_name(old.body().definitions(), |defn| defn.name());
+        let new_definitions = by_name(new.body().definitions(), |defn| defn.name());
+        for (name, old_defn) in &old_definitions {
+            match new_definitions.get(name) {
+                Some(new_defn) => self.definition(old_defn, new_defn),
+                None => self.push(
+                    ChangeKind::Removed,
+                    ElementKind::Definition,
+                    name.to_string(),
+                    (Some(definition_kind(old_defn).to_string()), None),
+                    Compatibility::Breaking,
+                ),
+            }
+        }
+        for (name, new_defn) in &new_definitions {
+            if !old_definitions.contains_key(name) {
+                self.push(
+                    ChangeKind::Added,
+                    ElementKind::Definition,
+                    name.to_string(),
+                    (None, Some(definition_kind(new_defn).to_string())),
+                    Compatibility::Compatible,
+                );
+            }
+        }
+    }
+
+    fn header(
+        &mut self,
+        name: &str,
+        old: Option<String>,
+        new: Option<String>,
+        compatibility: Compatibility,
+    ) {
+        let change = match (&old, &new) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            (Some(old), Some(new)) if old != new => ChangeKind::Changed,
+            _ => return,
+        };
+        self.push(change, ElementKind::Header, name, (old, new), compatibility);
+    }
+
+    fn definition(&mut self, old: &Definition, new: &Definition) {
+        let path = old.name().to_string();
+        if definition_kind(old) != definition_kind(new) {
+            self.push(
+                ChangeKind::Changed,
+                ElementKind::Definition,
+                path,
+                changed(definition_kind(old), definition_kind(new)),
+                Compatibility::Breaking,
+            );
+            return;
+        }
+        match (old, new) {
+            (Definition::Datatype(old), Definition::Datatype(new)) => {
+                let old_base = format!(
+                    "{}{}",
+                    if old.is_opaque() { "opaque " } else { "" },
+                    old.base_type()
+                );
+                let new_base = format!(
+                    "{}{}",
+                    if new.is_opaque() { "opaque " } else { "" },
+                    new.base_type()
+                );
+                if old_base != new_base {
+                    self.push(
+                        ChangeKind::Changed,
+                        ElementKind::BaseType,
+                        &path,
+                        (Some(old_base), Some(new_base)),
+                        Compatibility::Breaking,
+                    );
+                }
+                self.annotations(
+                    &path,
+                    annotations_of(old.body()),
+                    annotations_of(new.body()),
+                );
+            }
+            (Definition::Entity(old), Definition::Entity(new)) => {
+                match (old.body(), new.body()) {
+                    (Some(old_body), Some(new_body)) => {
+                        self.member(&path, old_body.identity(), new_body.identity());
+                    }
+                    (Some(old_body), None) => self.removed_member(&path, old_body.identity()),
+                    (None, Some(new_body)) => self.added_member(&path, new_body.identity()),
+                    (None, None) => {}
+                }
+                self.members(
+                    &path,
+                    old.body()
+                        .map(|b| b.members().collect())
+                        .unwrap_or_default(),
+                    new.body()
+                        .map(|b| b.members().collect())
+                        .unwrap_or_default(),
+                );
+                self.annotations(
+                    &path,
+                    annotations_of(old.body()),
+                    annotations_of(new.body()),
+                );
+            }
+            (Definition::Enum(old), Definition::Enum(new)) => self.enum_variants(&path, old, new),
+            (Definition::Event(old), Definition::Event(new)) => {
+                if old.event_source() != new.event_source() {
+                    self.push(
+                        ChangeKind::Changed,
+                        ElementKind::BaseType,
+                        &path,
+                        changed(old.event_source(), new.event_source()),
+                        Compatibility::Breaking,
+                    );
+                }
+                self.structure_body(&path, old.body(), new.body());
+            }
+            (Definition::Property(old), Definition::Property(new)) => {
+                let old_member = Member::new_definition(old.member_def().clone());
+                let new_member = Member::new_definition(new.member_def().clone());
+                self.member_target(&path, &old_member, &new_member);
+                self.annotations(
+                    &path,
+                    annotations_of(old.member_def().body()),
+                    annotations_of(new.member_def().body()),
+                );
+            }
+            (Definition::Rdf(old), Definition::Rdf(new)) => {
+                self.annotations(&path, old.body().annotations(), new.body().annotations());
+            }
+            (Definition::Structure(old), Definition::Structure(new)) => {
+                self.structure_body(&path, old.body(), new.body());
+            }
+            (Definition::TypeClass(old), Definition::TypeClass(new)) => {
+                self.type_class_methods(&path, old, new)
+            }
+            (Definition::Union(old), Definition::Union(new)) => {
+                self.union_variants(&path, old, new)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn structure_body(
+        &mut self,
+        path: &str,
+        old: Option<&StructureBody>,
+        new: Option<&StructureBody>,
+    ) {
+        self.members(
+            path,
+            old.map(|b| b.members().collect()).unwrap_or_default(),
+            new.map(|b| b.members().collect()).unwrap_or_default(),
+        );
+        self.annotations(path, annotations_of(old), annotations_of(new));
+    }
+
+    fn members(&mut self, path: &str, old: Vec<&Member>, new: Vec<&Member>) {
+        let old_members = by_name(old.into_iter(), |member| member.name());
+        let new_members = by_name(new.into_iter(), |member| member.name());
+        for (name, old_member) in &old_members {
+            match new_members.get(name) {
+                Some(new_member) => self.member(path, old_member, new_member),
+                None => self.removed_member(path, old_member),
+            }
+        }
+        for (name, new_member) in &new_members {
+            if !old_members.contains_key(name) {
+                self.added_member(path, new_member);
+            }
+        }
+    }
+
+    fn added_member(&mut self, path: &str, member: &Member) {
+        // A new member is only compatible with existing data if it is optional.
+        let compatibility = match member.kind() {
+            MemberKind::Definition(defn) if defn.target_cardinality().min_occurs() == 0 => {
+                Compatibility::Compatible
+            }
+            _ => Compatibility::Breaking,
+        };
+        self.push(
+            ChangeKind::Added,
+            ElementKind::Member,
+            format!("{path}.{}", member.name()),
+            (None, Some(member_target(member))),
+            compatibility,
+        );
+    }
+
+    fn removed_member(&mut self, path: &str, member: &Member) {
+        self.push(
+            ChangeKind::Removed,
+            ElementKind::Member,
+            format!("{path}.{}", member.name()),
+            (Some(member_target(member)), None),
+            Compatibility::Breaking,
+        );
+    }
+
+    fn member(&mut self, path: &str, old: &Member, new: &Member) {
+        let path = format!("{path}.{}", old.name());
+        self.member_target(&path, old, new);
+        let old_body = old.as_definition().and_then(|defn| defn.body());
+        let new_body = new.as_definition().and_then(|defn| defn.body());
+        self.annotations(&path, annotations_of(old_body), annotations_of(new_body));
+    }
+
+    fn member_target(&mut self, path: &str, old: &Member, new: &Member) {
+        match (old.kind(), new.kind()) {
+            (MemberKind::Definition(old), MemberKind::Definition(new)) => {
+                let (old_type, new_type) =
+                    (old.target_type().to_string(), new.target_type().to_string());
+                if old_type != new_type {
+                    self.push(
+                        ChangeKind::Changed,
+                        ElementKind::Type,
+                        path,
+                        (Some(old_type), Some(new_type)),
+                        Compatibility::Breaking,
+                    );
+                }
+                let (old_card, new_card) = (old.target_cardinality(), new.target_cardinality());
+                if old_card.to_string() != new_card.to_string() {
+                    self.push(
+                        ChangeKind::Changed,
+                        ElementKind::Cardinality,
+                        path,
+                        changed(old_card, new_card),
+                        cardinality_compatibility(old_card, new_card),
+                    );
+                }
+            }
+            (MemberKind::Reference(old), MemberKind::Reference(new)) if old == new => {}
+            _ => self.push(
+                ChangeKind::Changed,
+                ElementKind::Member,
+                path,
+                (Some(member_target(old)), Some(member_target(new))),
+                Compatibility::Breaking,
+            ),
+        }
+    }
+
+    fn enum_variants(&mut self, path: &str, old: &EnumDef, new: &EnumDef) {
+        let old_variants = by_name(old.body().into_iter().flat_map(|b| b.variants()), |v| {
+            v.name()
+        });
+        let new_variants = by_name(new.body().into_iter().flat_map(|b| b.variants()), |v| {
+            v.name()
+        });
+        for (name, old_variant) in &old_variants {
+            let variant_path = format!("{path}.{name}");
+            match new_variants.get(name) {
+                Some(new_variant) => self.annotations(
+                    &variant_path,
+                    annotations_of(old_variant.body()),
+                    annotations_of(new_variant.body()),
+                ),
+                None => self.push(
+                    ChangeKind::Removed,
+                    ElementKind::Variant,
+                    variant_path,
+                    (None, None),
+                    Compatibility::Breaking,
+                ),
+            }
+        }
+        for name in new_variants.keys() {
+            if !old_variants.contains_key(name) {
+                self.push(
+                    ChangeKind::Added,
+                    ElementKind::Variant,
+                    format!("{path}.{name}"),
+                    (None, None),
+                    Compatibility::Compatible,
+                );
+            }
+        }
+        self.annotations(path, annotations_of(old.body()), annotations_of(new.body()));
+    }
+
+    fn union_variants(&mut self, path: &str, old: &UnionDef, new: &UnionDef) {
+        let old_variants = by_name(old.body().into_iter().flat_map(|b| b.variants()), |v| {
+            v.name()
+        });
+        let new_variants = by_name(new.body().into_iter().flat_map(|b| b.variants()), |v| {
+            v.name()
+        });
+        for (name, old_variant) in &old_variants {
+            let variant_path = format!("{path}.{name}");
+            match new_variants.get(name) {
+                Some(new_variant) => {
+                    if old_variant.name_reference() != new_variant.name_reference() {
+                        self.push(
+                            ChangeKind::Changed,
+                            ElementKind::Type,
+                            &variant_path,
+                            changed(old_variant.name_reference(), new_variant.name_reference()),
+                            Compatibility::Breaking,
+                        );
+                    }
+                    self.annotations(
+                        &variant_path,
+                        annotations_of(old_variant.body()),
+                        annotations_of(new_variant.body()),
+                    );
+                }
+                None => self.push(
+                    ChangeKind::Removed,
+                    ElementKind::Variant,
+                    variant_path,
+                    (Some(old_variant.name_reference().to_string()), None),
+                    Compatibility::Breaking,
+                ),
+            }
+        }
+        for (name, new_variant) in &new_variants {
+            if !old_variants.contains_key(name) {
+                self.push(
+                    ChangeKind::Added,
+                    ElementKind::Variant,
+                    format!("{path}.{name}"),
+                    (None, Some(new_variant.name_reference().to_string())),
+                    Compatibility::Compatible,
+                );
+            }
+        }
+        self.annotations(path, annotations_of(old.body()), annotations_of(new.body()));
+    }
+
+    fn type_class_methods(&mut self, path: &str, old: &TypeClassDef, new: &TypeClassDef) {
+        let old_methods = by_name(old.body().into_iter().flat_map(|b| b.methods()), |m| {
+            m.name()
+        });
+        let new_methods = by_name(new.body().into_iter().flat_map(|b| b.methods()), |m| {
+            m.name()
+        });
+        for name in old_methods.keys() {
+            if !new_methods.contains_key(name) {
+                self.push(
+                    ChangeKind::Removed,
+                    ElementKind::Member,
+                    format!("{path}.{name}"),
+                    (None, None),
+                    Compatibility::Breaking,
+                );
+            }
+        }
+        for name in new_methods.keys() {
+            if !old_methods.contains_key(name) {
+                self.push(
+                    ChangeKind::Added,
+                    ElementKind::Member,
+                    format!("{path}.{name}"),
+                    (None, None),
+                    Compatibility::Compatible,
+                );
+            }
+        }
+        self.annotations(path, annotations_of(old.body()), annotations_of(new.body()));
+    }
+
+    fn annotations<'a>(
+        &mut self,
+        path: &str,
+        old: impl Iterator<Item = &'a Annotation>,
+        new: impl Iterator<Item = &'a Annotation>,
+    ) {
+        let (old_properties, old_constraints) = annotation_values(old);
+        let (new_properties, new_constraints) = annotation_values(new);
+
+        for name in old_properties
+            .keys()
+            .chain(new_properties.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let old_values = old_properties.get(name);
+            let new_values = new_properties.get(name);
+            if old_values == new_values {
+                continue;
+            }
+            // Datatype facets restrict the values of a datatype, adding or changing one may
+            // reject existing values.
+            let facet = is_datatype_facet(name);
+            let (change, compatibility) = match (old_values, new_values) {
+                (None, _) if facet => (ChangeKind::Added, Compatibility::Breaking),
+                (None, _) => (ChangeKind::Added, Compatibility::Compatible),
+                (_, None) => (ChangeKind::Removed, Compatibility::Compatible),
+                _ if facet => (ChangeKind::Changed, Compatibility::Breaking),
+                _ => (ChangeKind::Changed, Compatibility::Compatible),
+            };
+            self.push(
+                change,
+                ElementKind::Annotation,
+                format!("{path}@{name}"),
+                (
+                    old_values.map(|values| values.join(", ")),
+                    new_values.map(|values| values.join(", ")),
+                ),
+                compatibility,
+            );
+        }
+
+        for name in old_constraints
+            .keys()
+            .chain(new_constraints.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let old_value = old_constraints.get(name);
+            let new_value = new_constraints.get(name);
+            let (change, compatibility) = match (old_value, new_value) {
+                (None, _) => (ChangeKind::Added, Compatibility::Breaking),
+                (_, None) => (ChangeKind::Removed, Compatibility::Compatible),
+                (Some(old_value), Some(new_value)) if old_value != new_value => {
+                    (ChangeKind::Changed, Compatibility::Breaking)
+                }
+                _ => continue,
+            };
+            self.push(
+                change,
+                ElementKind::Constraint,
+                format!("{path}@{name}"),
+                (None, None),
+                compatibility,
+            );
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let marker = match self.change {
+            ChangeKind::Added => "+",
+            ChangeKind::Removed => "-",
+            ChangeKind::Changed => "~",
+        };
+        let path = if self.path.is_empty() {
+            "module"
+        } else {
+            &self.path
+        };
+        write!(f, "{marker} {path}: {} {}", self.change, self.element)?;
+        match (&self.old_value, &self.new_value) {
+            (Some(old), Some(new)) => write!(f, " from `{old}` to `{new}`")?,
+            (Some(old), None) => write!(f, " `{old}`")?,
+            (None, Some(new)) => write!(f, " `{new}`")?,
+            (None, None) => {}
+        }
+        write!(f, " ({})", self.compatibility)
+    }
+}
+
+impl Change {
+    pub const fn change(&self) -> ChangeKind {
+        self.change
+    }
+
+    pub const fn element(&self) -> ElementKind {
+        self.element
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn old_value(&self) -> Option<&String> {
+        self.old_value.as_ref()
+    }
+
+    pub fn new_value(&self) -> Option<&String> {
+        self.new_value.as_ref()
+    }
+
+    pub const fn compatibility(&self) -> Compatibility {
+        self.compatibility
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility == Compatibility::Breaking
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for VersionBump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "none",
+                Self::Patch => "patch",
+                Self::Minor => "minor",
+                Self::Major => "major",
+            }
+        )
+    }
+}
+
+impl VersionBump {
+    ///
+    /// Returns the increment provided by moving from the version `old` to `new`.
+    ///
+    pub fn between(old: &Version, new: &Version) -> Self {
+        if new <= old {
+            Self::None
+        } else if old.major == 0 && new.major == 0 {
+            if new.minor > old.minor {
+                Self::Major
+            } else if new.patch > old.patch {
+                Self::Minor
+            } else {
+                Self::None
+            }
+        } else if new.major > old.major {
+            Self::Major
+        } else if new.minor > old.minor {
+            Self::Minor
+        } else if new.patch > old.patch {
+            Self::Patch
+        } else {
+            Self::None
+        }
+    }
+
+    ///
+    /// Returns the smallest version after `version` that provides this increment.
+    ///
+    pub fn apply(&self, version: &Version) -> Version {
+        let (major, minor, patch) = (version.major, version.minor, version.patch);
+        match (self, major) {
+            (Self::None, _) => version.clone(),
+            (Self::Major, 0) => Version::new(0, minor + 1, 0),
+            (_, 0) => Version::new(0, minor, patch + 1),
+            (Self::Major, _) => Version::new(major + 1, 0, 0),
+            (Self::Minor, _) => Version::new(major, minor + 1, 0),
+            (Self::Patch, _) => Version::new(major, minor, patch + 1),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Added => "added",
+                Self::Removed => "removed",
+                Self::Changed => "changed",
+            }
+        )
+    }
+}
+
+impl Display for ElementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Module => "module",
+                Self::Header => "header",
+                Self::Import => "import",
+                Self::Definition => "definition",
+                Self::BaseType => "base type",
+                Self::Member => "member",
+                Self::Cardinality => "cardinality",
+                Self::Type => "type",
+                Self::Variant => "variant",
+                Self::Annotation => "annotation",
+                Self::Constraint => "constraint",
+            }
+        )
+    }
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Compatible => "compatible",
+                Self::Breaking => "breaking",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const HEADER_VERSION_INFO: &str = "version_info";
+const HEADER_VERSION_URI: &str = "version_uri";
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn serialize_display<S: Serializer>(
+    value: &impl Display,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+///
+/// Return the value of a header only if it was declared in the module source, the loader
+/// provides a default base URI for modules that do not declare one.
+///
+fn declared<T: Display>(value: Option<&HeaderValue<T>>) -> Option<String> {
+    value
+        .filter(|value| value.has_source_span())
+        .map(|value| value.to_string())
+}
+
+fn changed(old: impl Display, new: impl Display) -> (Option<String>, Option<String>) {
+    (Some(old.to_string()), Some(new.to_string()))
+}
+
+fn by_name<'a, T>(
+    values: impl Iterator<Item = &'a T>,
+    name: impl Fn(&'a T) -> &'a Identifier,
+) -> BTreeMap<&'a Identifier, &'a T>
+where
+    T: 'a,
+{
+    values.map(|value| (name(value), value)).collect()
+}
+
+fn annotations_of<T: HasAnnotations>(body: Option<&T>) -> impl Iterator<Item = &Annotation> {
+    body.into_iter().flat_map(|body| body.annotations())
+}
+
+#[allow(single_use_lifetimes)]
+fn annotation_values<'a>(
+    annotations: impl Iterator<Item = &'a Annotation>,
+) -> (BTreeMap<String, Vec<String>>, BTreeMap<String, String>) {
+    let mut properties: BTreeMap<String, Vec<String>> = Default::default();
+    let mut constraints: BTreeMap<String, String> = Default::default();
+    for annotation in annotations {
+        match annotation {
+            Annotation::Property(property) => properties
+                .entry(property.name_reference().to_string())
+                .or_default()
+                .push(property.value().to_string()),
+            Annotation::Constraint(constraint) => {
+                constraints.insert(constraint.name().to_string(), constraint_text(constraint));
+            }
+        }
+    }
+    properties.values_mut().for_each(|values| values.sort());
+    (properties, constraints)
+}
+
+///
+/// A representation of a constraint's body, ignoring source spans, used for comparison.
+///
+fn constraint_text(constraint: &Constraint) -> String {
+    match constraint.body() {
+        ConstraintBody::Informal(body) => format!(
+            "{:?}@{}",
+            body.value(),
+            body.language().map(|l| l.to_string()).unwrap_or_default()
+        ),
+        ConstraintBody::Formal(body) => {
+            let mut value = serde_json::to_value(body).unwrap_or_default();
+            remove_spans(&mut value);
+            value.to_string()
+        }
+    }
+}
+
+fn remove_spans(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("span");
+            map.values_mut().for_each(remove_spans);
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(remove_spans),
+        _ => {}
+    }
+}
+
+fn is_datatype_facet(name: &str) -> bool {
+    name.strip_prefix(xsd::MODULE_NAME)
+        .and_then(|name| name.strip_prefix(':'))
+        .map(xsd::is_constraining_facet_str)
+        .unwrap_or_default()
+}
+
+fn module_imports(module: &Module) -> BTreeSet<String> {
+    module
+        .body()
+        .imports()
+        .flat_map(|statement| statement.imports())
+        .map(|import| match import {
+            Import::Module(import) => import.name().to_string(),
+            Import::Member(import) => import.to_string(),
+        })
+        .collect()
+}
+
+fn member_target(member: &Member) -> String {
+    match member.kind() {
+        MemberKind::Reference(name) => format!("ref {name}"),
+        MemberKind::Definition(defn) => {
+            format!("{} {}", defn.target_cardinality(), defn.target_type())
+        }
+    }
+}
+
+fn definition_kind(definition: &Definition) -> &'static str {
+    match definition {
+        Definition::Datatype(_) => "datatype",
+        Definition::Entity(_) => "entity",
+        Definition::Enum(_) => "enum",
+        Definition::Event(_) => "event",
+        Definition::Property(_) => "property",
+        Definition::Rdf(_) => "rdf",
+        Definition::Structure(_) => "structure",
+        Definition::TypeClass(_) => "class",
+        Definition::Union(_) => "union",
+    }
+}
+
+///
+/// A cardinality change is compatible only if it widens the allowed range of values and does
+/// not add an ordering, or uniqueness, requirement.
+///
+fn cardinality_compatibility(old: &Cardinality, new: &Cardinality) -> Compatibility {
+    let narrower_min = new.min_occurs() > old.min_occurs();
+    let narrower_max = match (old.max_occurs(), new.max_occurs()) {
+        (None, Some(_)) => true,
+        (Some(old), Some(new)) => new < old,
+        _ => false,
+    };
+    let now_ordered =
+        new.ordering() == Some(Ordering::Ordered) && old.ordering() != Some(Ordering::Ordered);
+    let now_unique = new.uniqueness() == Some(Uniqueness::Unique)
+        && old.uniqueness() != Some(Uniqueness::Unique);
+    if narrower_min || narrower_max || now_ordered || now_unique {
+        Compatibility::Breaking
+    } else {
+        Compatibility::Compatible
+    }
+}
@@ -0,0 +1,396 @@
+/*!
+Flatten a module, and the definitions it uses from its imports, into one self-contained module.
+
+The flattened module contains every definition of the original module and every user
+definition, transitively, referenced by them; definitions from library modules, either the
+standard library or those registered with the module store, are not inlined and their modules
+remain imported. An inlined definition whose name collides with one
+already in the flattened module is renamed by prefixing its module's name, so `fleet:Vehicle`
+may become `FleetVehicle`. All references to inlined definitions, qualified or not, are rewritten
+to the local name; this includes the types of value constructors and qualified names in formal
+constraints.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::actions::flatten::flatten_module;
+use sdml_generate::convert::source::SourceGenerator;
+use sdml_generate::Generator;
+use std::io::stdout;
+# use sdml_core::model::identifiers::Identifier;
+# fn load_module() -> (Module, InMemoryModuleCache) { (Module::empty(Identifier::new_unchecked("example")), InMemoryModuleCache::default()) }
+
+let (module, cache) = load_module();
+
+let flattened = flatten_module(&module, &cache).expect("could not flatten module");
+let mut generator = SourceGenerator::default();
+generator.generate(&flattened, &cache, None, &mut stdout())
+         .expect("write to stdout failed");
+```
+
+*/
+
+use sdml_core::error::Error;
+use sdml_core::model::annotations::HasAnnotations;
+use sdml_core::model::constraints::Constraint;
+use sdml_core::model::definitions::{Definition, TypeClassDef};
+use sdml_core::model::identifiers::{Identifier, IdentifierReference, QualifiedIdentifier};
+use sdml_core::model::modules::{ImportStatement, Module, ModuleBody};
+use sdml_core::model::values::ValueConstructor;
+use sdml_core::model::walk::visit::{walk_annotations, walk_constraint, Visitor};
+use sdml_core::model::walk::visit_mut::{
+    walk_annotations_mut, walk_constraint_mut, walk_definition_mut, VisitorMut,
+};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib::{is_builtin_type_name, sdml};
+use sdml_core::store::ModuleStore;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use tracing::{trace, warn};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Create a new module, with the same name and header as `module`, containing its definitions
+/// and every user definition from the modules in `cache` that they reference.
+///
+pub fn flatten_module(module: &Module, cache: &impl ModuleStore) -> Result<Module, Error> {
+    trace!("flatten_module({})", module.name());
+    let mut flattener = Flattener {
+        cache,
+        names: Default::default(),
+        used: Default::default(),
+        definitions: Default::default(),
+        imports: Default::default(),
+    };
+
+    for definition in module.body().definitions() {
+        let name = definition.name().clone();
+        flattener.names.insert(
+            QualifiedIdentifier::new(module.name().clone(), name.clone()),
+            name.clone(),
+        );
+        flattener.used.insert(name);
+        flattener.definitions.push((module, definition));
+    }
+
+    let mut names = ReferenceNames::default();
+    walk_annotations(&mut names, module.body())?;
+    flattener.add_references(module, names.names);
+    let mut next = 0;
+    while next < flattener.definitions.len() {
+        let (from, definition) = flattener.definitions[next];
+        let mut names = ReferenceNames::default();
+        names.visit_definition(definition)?;
+        flattener.add_references(from, names.names);
+        next += 1;
+    }
+
+    let mut body = ModuleBody::default();
+    for import in flattener.imports.iter().filter(|i| *i != module.name()) {
+        body.add_to_imports(ImportStatement::new_module(import.clone()));
+    }
+    for annotation in module.body().annotations() {
+        let mut annotation = annotation.clone();
+        flattener
+            .rewriter(module)
+            .visit_annotation_mut(&mut annotation)?;
+        body.add_to_annotations(annotation);
+    }
+    for (from, definition) in &flattener.definitions {
+        let mut definition = (*definition).clone();
+        let local = &flattener.names
+            [&QualifiedIdentifier::new(from.name().clone(), definition.name().clone())];
+        if local != definition.name() {
+            definition.set_name(local.clone());
+        }
+        flattener
+            .rewriter(from)
+            .visit_definition_mut(&mut definition)?;
+        body.add_to_definitions(definition)?;
+    }
+
+    let mut flattened = Module::new(module.name().clone(), body);
+    if let Some(base_uri) = module.base_uri() {
+        flattened.set_base_uri(base_uri.clone());
+    }
+    if let Some(version_info) = module.version_info() {
+        flattened.set_version_info(version_info.clone());
+    }
+    if let Some(version_uri) = module.version_uri() {
+        flattened.set_version_uri(version_uri.clone());
+    }
+    Ok(flattened)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Flattener<'a, S> {
+    cache: &'a S,
+    /// The local name of every inlined definition
+    names: HashMap<QualifiedIdentifier, Identifier>,
+    used: HashSet<Identifier>,
+    /// The inlined definitions, in the order they are written
+    definitions: Vec<(&'a Module, &'a Definition)>,
+    /// Modules that cannot be inlined and remain imported
+    imports: BTreeSet<Identifier>,
+}
+
+///
+/// Collects, in sorted order so that renamed definitions are named the same way each time, every
+/// name referenced by the nodes visited.
+///
+#[derive(Debug, Default)]
+struct ReferenceNames {
+    names: BTreeSet<String>,
+    in_constraint: bool,
+}
+
+///
+/// Rewrites every reference, from the module `from`, to an inlined or external definition.
+///
+#[derive(Debug)]
+struct Rewriter<'r, 'a, S> {
+    flattener: &'r Flattener<'a, S>,
+    from: &'r Module,
+    in_constraint: bool,
+}
+
+#[derive(Debug)]
+enum Target {
+    /// A user definition that is, or will be, inlined
+    Inline(QualifiedIdentifier),
+    /// A definition in a library, or unloaded, module
+    External(QualifiedIdentifier),
+    /// A builtin type, or a name that cannot be resolved
+    Unchanged,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a, S: ModuleStore> Flattener<'a, S> {
+    fn add_references(&mut self, from: &'a Module, names: BTreeSet<String>) {
+        for name in names {
+            let Ok(reference) = name.parse::<IdentifierReference>() else {
+                continue;
+            };
+            match self.target(from, &reference) {
+                Target::Inline(target) => {
+                    if !self.names.contains_key(&target) {
+                        self.inline(target);
+                    }
+                }
+                // builtin types are reported as `sdml:` members but need no import.
+                Target::External(target)
+                    if target.module().as_ref() == sdml::MODULE_NAME
+                        && is_builtin_type_name(target.member()) => {}
+                Target::External(target) => {
+                    self.imports.insert(target.module().clone());
+                }
+                Target::Unchanged => {}
+            }
+        }
+    }
+
+    fn inline(&mut self, target: QualifiedIdentifier) {
+        let Some((module, definition)) = self.cache.get(target.module()).and_then(|module| {
+            module
+                .resolve_local(target.member())
+                .map(|definition| (module, definition))
+        }) else {
+            return;
+        };
+        let mut local = target.member().clone();
+        if self.used.contains(&local) {
+            local = prefixed_name(target.module(), target.member());
+            let mut suffix = 2;
+            while self.used.contains(&local) {
+                local = Identifier::new_unchecked(&format!(
+                    "{}{suffix}",
+                    prefixed_name(target.module(), target.member())
+                ));
+                suffix += 1;
+            }
+        }
+        self.used.insert(local.clone());
+        self.names.insert(target, local);
+        self.definitions.push((module, definition));
+    }
+
+    fn target(&self, from: &Module, reference: &IdentifierReference) -> Target {
+        let target = match reference {
+            IdentifierReference::QualifiedIdentifier(name) => name.clone(),
+            IdentifierReference::Identifier(name) => {
+                if from.resolve_local(name).is_some() {
+                    QualifiedIdentifier::new(from.name().clone(), name.clone())
+                } else if let Some(imported) = from
+                    .imported_types()
+                    .into_iter()
+                    .find(|imported| imported.member() == name)
+                {
+                    imported.clone()
+                } else {
+                    if !is_builtin_type_name(name) {
+                        warn!("Could not resolve `{name}` in module `{}`", from.name());
+                    }
+                    return Target::Unchanged;
+                }
+            }
+        };
+        match self.cache.get(target.module()) {
+            _ if self.cache.is_library_module(target.module()) => Target::External(target),
+            None => {
+                warn!(
+                    "Module `{}` is not loaded, it will be imported",
+                    target.module()
+                );
+                Target::External(target)
+            }
+            Some(module) if module.resolve_local(target.member()).is_some() => {
+                Target::Inline(target)
+            }
+            Some(_) => Target::Unchanged,
+        }
+    }
+
+    fn rewriter<'r>(&'r self, from: &'r Module) -> Rewriter<'r, 'a, S> {
+        Rewriter {
+            flattener: self,
+            from,
+            in_constraint: false,
+        }
+    }
+
+    ///
+    /// Return the new reference for `reference`, from the module `from`, if it changes.
+    ///
+    fn rewrite(
+        &self,
+        from: &Module,
+        reference: &IdentifierReference,
+    ) -> Option<IdentifierReference> {
+        match self.target(from, reference) {
+            Target::Inline(target) => self
+                .names
+                .get(&target)
+                .map(|local| IdentifierReference::from(local.clone())),
+            // imports are not copied, so a member import must become a qualified reference.
+            Target::External(target) if reference.module().is_none() => {
+                Some(IdentifierReference::from(target))
+            }
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Prefix `member` with `module` converted to the same case convention as `member`.
+///
+fn prefixed_name(module: &Identifier, member: &Identifier) -> Identifier {
+    if member.as_ref().starts_with(char::is_uppercase) {
+        let prefix: String = module
+            .as_ref()
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect();
+        Identifier::new_unchecked(&format!("{prefix}{member}"))
+    } else {
+        Identifier::new_unchecked(&format!("{module}_{member}"))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Visitor<'_> for ReferenceNames {
+    fn visit_constraint(&mut self, node: &Constraint) -> Result<(), Error> {
+        self.in_constraint = true;
+        let result = walk_constraint(self, node);
+        self.in_constraint = false;
+        result
+    }
+
+    fn visit_type_class_def(&mut self, node: &TypeClassDef) -> Result<(), Error> {
+        match node.body() {
+            Some(body) => walk_annotations(self, body),
+            None => Ok(()),
+        }
+    }
+
+    fn visit_value_constructor(&mut self, node: &ValueConstructor) -> Result<(), Error> {
+        self.names.insert(node.type_name().to_string());
+        Ok(())
+    }
+
+    fn visit_identifier_reference(&mut self, node: &IdentifierReference) -> Result<(), Error> {
+        if is_definition_reference(node, self.in_constraint) {
+            self.names.insert(node.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<S: ModuleStore> VisitorMut for Rewriter<'_, '_, S> {
+    fn visit_definition_mut(&mut self, node: &mut Definition) -> Result<(), Error> {
+        walk_definition_mut(self, node)
+    }
+
+    fn visit_constraint_mut(&mut self, node: &mut Constraint) -> Result<(), Error> {
+        self.in_constraint = true;
+        let result = walk_constraint_mut(self, node);
+        self.in_constraint = false;
+        result
+    }
+
+    fn visit_type_class_def_mut(&mut self, node: &mut TypeClassDef) -> Result<(), Error> {
+        match node.body_mut() {
+            Some(body) => walk_annotations_mut(self, body),
+            None => Ok(()),
+        }
+    }
+
+    fn visit_value_constructor_mut(&mut self, node: &mut ValueConstructor) -> Result<(), Error> {
+        if let Some(reference) = self.flattener.rewrite(self.from, node.type_name()) {
+            *node.type_name_mut() = reference;
+        }
+        Ok(())
+    }
+
+    fn visit_identifier_reference_mut(
+        &mut self,
+        node: &mut IdentifierReference,
+    ) -> Result<(), Error> {
+        if is_definition_reference(node, self.in_constraint) {
+            if let Some(reference) = self.flattener.rewrite(self.from, node) {
+                *node = reference;
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Within a formal constraint an unqualified name is a variable, and only qualified names, and
+/// the types of value constructors, refer to definitions.
+///
+fn is_definition_reference(reference: &IdentifierReference, in_constraint: bool) -> bool {
+    !in_constraint || reference.is_qualified_identifier()
+}
@@ -0,0 +1,708 @@
+/*!
+Format module source, working from the tree-sitter concrete syntax tree so that comments and
+blank-line grouping are preserved.
+
+Unlike the [`SourceGenerator`](crate::convert::source::SourceGenerator), which regenerates
+source from the model, this formatter only changes layout:
+
+- each import, annotation, definition, member and variant is written on its own line, indented
+  by its nesting within `is`/`of` ... `end` bodies;
+- tokens within a line are separated by a single space, except where the punctuation is
+  conventionally attached, as in `{0..1}` or `xsd:date("2023-08-04")`;
+- comments are kept, either on their own line or trailing the line they followed;
+- runs of blank lines are reduced to a single blank line;
+- import statements are sorted within each group of consecutive imports, as are the names
+  within a bracketed import;
+- the `=` of consecutive annotation properties are aligned;
+- the bodies of formal constraints, and lists of values that span multiple lines, are kept as
+  written but re-indented.
+
+Formatting is idempotent, formatting already formatted source returns it unchanged.
+
+# Example
+
+```rust
+use sdml_generate::actions::format::{format_source, FormatOptions};
+
+let source = r#"module example is
+import [ xsd dc ]
+   entity Thing is
+  @dc:title = "thing" ; a comment
+  @dc:description = "a thing"
+identity id -> {1..1} xsd:string
+end
+end
+"#;
+
+let formatted = format_source(0, source, &FormatOptions::default()).unwrap();
+
+assert_eq!(formatted, r#"module example is
+  import [ dc xsd ]
+  entity Thing is
+    @dc:title       = "thing" ; a comment
+    @dc:description = "a thing"
+    identity id -> {1..1} xsd:string
+  end
+end
+"#);
+```
+
+*/
+
+use sdml_core::syntax::{
+    NODE_KIND_ACTUAL_ARGUMENTS, NODE_KIND_ANNOTATION, NODE_KIND_ANNOTATION_ONLY_BODY,
+    NODE_KIND_ANNOTATION_PROPERTY, NODE_KIND_BINARY, NODE_KIND_CONTROLLED_LANGUAGE_TAG,
+    NODE_KIND_ENTITY_BODY, NODE_KIND_ENUM_BODY, NODE_KIND_FORMAL_CONSTRAINT,
+    NODE_KIND_FUNCTION_COMPOSITION, NODE_KIND_FUNCTION_SIGNATURE, NODE_KIND_IMPORT_STATEMENT,
+    NODE_KIND_IRI, NODE_KIND_LANGUAGE_TAG, NODE_KIND_LINE_COMMENT, NODE_KIND_MEMBER_IMPORT,
+    NODE_KIND_MODULE, NODE_KIND_MODULE_BODY, NODE_KIND_MODULE_IMPORT,
+    NODE_KIND_QUALIFIED_IDENTIFIER, NODE_KIND_QUOTED_STRING,
+    NODE_KIND_SEQUENCE_OF_PREDICATE_VALUES, NODE_KIND_SEQUENCE_OF_VALUES, NODE_KIND_STRING,
+    NODE_KIND_STRUCTURED_BODY, NODE_KIND_TYPE_CLASS_ARGUMENTS, NODE_KIND_TYPE_CLASS_BODY,
+    NODE_KIND_UNION_BODY, NODE_KIND_VALUE_CONSTRUCTOR,
+};
+use sdml_errors::diagnostics::functions::found_error_node;
+use sdml_errors::{Error, FileId};
+use tree_sitter::{Node, Parser};
+use tree_sitter_sdml::language;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options that control the layout produced by [`format_source`].
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    indentation: usize,
+    sort_imports: bool,
+    align_annotations: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format the module `source`, returning the formatted text. If the source contains syntax
+/// errors it is not formatted and the error is returned as a diagnostic for `file_id`.
+///
+pub fn format_source<S: AsRef<str>>(
+    file_id: FileId,
+    source: S,
+    options: &FormatOptions,
+) -> Result<String, Error> {
+    let source = source.as_ref();
+    let mut parser = Parser::new();
+    parser
+        .set_language(&language())
+        .expect("Error loading SDML grammar");
+    let tree = parser.parse(source, None).unwrap();
+
+    let root = tree.root_node();
+    if root.has_error() || root.kind() != NODE_KIND_MODULE {
+        let node = first_error_node(root).unwrap_or(root);
+        return Err(found_error_node(file_id, node.byte_range(), NODE_KIND_MODULE).into());
+    }
+
+    let formatter = Formatter { source, options };
+    let mut writer = LineWriter::new(source, 0);
+    formatter.write_node(&mut writer, root);
+
+    let mut formatted = String::with_capacity(source.len());
+    for (level, line) in writer.finish() {
+        let line = line.trim_end();
+        if !line.is_empty() {
+            formatted.push_str(&options.indentation_str(level));
+            formatted.push_str(line);
+        }
+        formatted.push('\n');
+    }
+    Ok(formatted)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indentation: 2,
+            sort_imports: true,
+            align_annotations: true,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn with_indentation(self, indentation: usize) -> Self {
+        Self {
+            indentation,
+            ..self
+        }
+    }
+
+    pub fn with_sort_imports(self, sort_imports: bool) -> Self {
+        Self {
+            sort_imports,
+            ..self
+        }
+    }
+
+    pub fn with_align_annotations(self, align_annotations: bool) -> Self {
+        Self {
+            align_annotations,
+            ..self
+        }
+    }
+
+    pub fn indentation(&self) -> usize {
+        self.indentation
+    }
+
+    pub fn sort_imports(&self) -> bool {
+        self.sort_imports
+    }
+
+    pub fn align_annotations(&self) -> bool {
+        self.align_annotations
+    }
+
+    fn indentation_str(&self, level: usize) -> String {
+        let n = level * self.indentation;
+        format!("{:n$}", "")
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Formatter<'a> {
+    source: &'a str,
+    options: &'a FormatOptions,
+}
+
+///
+/// Lays out the tokens of a single item, such as a definition, onto one or more lines.
+///
+#[derive(Debug)]
+struct LineWriter<'a> {
+    source: &'a str,
+    level: usize,
+    lines: Vec<(usize, String)>,
+    current: String,
+    current_level: usize,
+    previous: Option<&'a str>,
+    last_end: Option<usize>,
+    break_next: bool,
+    started: bool,
+}
+
+///
+/// The formatted content of a body between its opening keyword and `end`.
+///
+#[derive(Debug)]
+enum Entry {
+    Blank,
+    Comment(String),
+    Item {
+        kind: ItemKind,
+        lines: Vec<(usize, String)>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ItemKind {
+    Import(Vec<String>),
+    Annotation,
+    Other,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Formatter<'a> {
+    fn text(&self, node: Node<'_>) -> &'a str {
+        &self.source[node.byte_range()]
+    }
+
+    fn write_node(&self, writer: &mut LineWriter<'a>, node: Node<'_>) {
+        let kind = node.kind();
+        if kind == NODE_KIND_LINE_COMMENT {
+            writer.comment(self.text(node), node.start_byte(), node.end_byte());
+        } else if is_body(kind) {
+            self.write_body(writer, node);
+        } else if kind == NODE_KIND_FORMAL_CONSTRAINT && is_multi_line(node) {
+            self.write_formal_constraint(writer, node);
+        } else if (kind == NODE_KIND_BINARY
+            || kind == NODE_KIND_SEQUENCE_OF_VALUES
+            || kind == NODE_KIND_SEQUENCE_OF_PREDICATE_VALUES)
+            && is_multi_line(node)
+        {
+            self.write_verbatim(writer, node);
+        } else if is_atom(kind) || node.child_count() == 0 {
+            writer.token(self.text(node), node, is_attached(node));
+        } else {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.write_node(writer, child);
+            }
+        }
+    }
+
+    fn write_body(&self, writer: &mut LineWriter<'a>, node: Node<'_>) {
+        let mut cursor = node.walk();
+        let children = node.children(&mut cursor).collect::<Vec<_>>();
+        let (Some(opening), Some(end)) = (children.first(), children.last()) else {
+            return;
+        };
+        writer.token(self.text(*opening), *opening, false);
+        let inner = &children[1..children.len() - 1];
+        if inner.is_empty() {
+            writer.token(self.text(*end), *end, false);
+            return;
+        }
+
+        let mut entries: Vec<Entry> = Default::default();
+        let mut last_end = opening.end_byte();
+        for child in inner {
+            let newlines = self.source[last_end..child.start_byte()]
+                .matches('\n')
+                .count();
+            if child.kind() == NODE_KIND_LINE_COMMENT && newlines == 0 {
+                let comment = self.text(*child);
+                match entries.last_mut() {
+                    Some(Entry::Item { lines, .. }) => {
+                        let (_, line) = lines.last_mut().unwrap();
+                        line.push(' ');
+                        line.push_str(comment);
+                    }
+                    _ => writer.comment(comment, child.start_byte(), child.end_byte()),
+                }
+            } else {
+                if newlines > 1 {
+                    entries.push(Entry::Blank);
+                }
+                if child.kind() == NODE_KIND_LINE_COMMENT {
+                    entries.push(Entry::Comment(self.text(*child).to_string()));
+                } else {
+                    entries.push(self.format_item(*child, writer.level + 1));
+                }
+            }
+            last_end = child.end_byte();
+        }
+        if self.source[last_end..end.start_byte()]
+            .matches('\n')
+            .count()
+            > 1
+        {
+            entries.push(Entry::Blank);
+        }
+
+        if self.options.sort_imports {
+            sort_imports(&mut entries);
+        }
+        if self.options.align_annotations {
+            align_annotations(&mut entries);
+        }
+
+        writer.end_line();
+        for entry in entries {
+            match entry {
+                Entry::Blank => writer.lines.push((0, String::new())),
+                Entry::Comment(comment) => writer.lines.push((writer.level + 1, comment)),
+                Entry::Item { lines, .. } => writer.lines.extend(lines),
+            }
+        }
+        writer.new_line(writer.level);
+        writer.token(self.text(*end), *end, false);
+    }
+
+    fn format_item(&self, node: Node<'_>, level: usize) -> Entry {
+        if node.kind() == NODE_KIND_IMPORT_STATEMENT {
+            if let Some(entry) = self.format_import(node, level) {
+                return entry;
+            }
+        }
+        let kind = if node.kind() == NODE_KIND_ANNOTATION
+            && node
+                .named_child(0)
+                .map(|child| child.kind() == NODE_KIND_ANNOTATION_PROPERTY)
+                .unwrap_or_default()
+        {
+            ItemKind::Annotation
+        } else if node.kind() == NODE_KIND_IMPORT_STATEMENT {
+            ItemKind::Import(vec![self.text(node).to_string()])
+        } else {
+            ItemKind::Other
+        };
+        let mut writer = LineWriter::new(self.source, level);
+        self.write_node(&mut writer, node);
+        Entry::Item {
+            kind,
+            lines: writer.finish(),
+        }
+    }
+
+    ///
+    /// Format an import statement, sorting the names within a bracketed import. Returns `None`
+    /// if the statement contains comments and so has to be laid out as written.
+    ///
+    fn format_import(&self, node: Node<'_>, level: usize) -> Option<Entry> {
+        let mut cursor = node.walk();
+        let children = node.children(&mut cursor).collect::<Vec<_>>();
+        if children
+            .iter()
+            .any(|child| child.kind() == NODE_KIND_LINE_COMMENT)
+        {
+            return None;
+        }
+
+        let mut names = children
+            .iter()
+            .filter(|child| {
+                child.kind() == NODE_KIND_MEMBER_IMPORT || child.kind() == NODE_KIND_MODULE_IMPORT
+            })
+            .map(|child| {
+                let mut writer = LineWriter::new(self.source, 0);
+                self.write_node(&mut writer, *child);
+                writer.finish().remove(0).1
+            })
+            .collect::<Vec<_>>();
+        let bracketed = children.iter().any(|child| child.kind() == "[");
+        if bracketed && self.options.sort_imports {
+            names.sort();
+        }
+
+        let imported = if bracketed {
+            format!("[ {} ]", names.join(" "))
+        } else {
+            names.join(" ")
+        };
+        Some(Entry::Item {
+            kind: ItemKind::Import(names),
+            lines: vec![(level, format!("{} {imported}", self.text(children[0])))],
+        })
+    }
+
+    ///
+    /// The environment and sentence of a formal constraint are kept as written, with the
+    /// indentation of each line adjusted to the nesting of the constraint.
+    ///
+    fn write_formal_constraint(&self, writer: &mut LineWriter<'a>, node: Node<'_>) {
+        let (Some(opening), Some(end)) = (node.child(0), node.child(node.child_count() - 1)) else {
+            return;
+        };
+        writer.token(self.text(opening), opening, false);
+        writer.end_line();
+        let inner = &self.source[opening.end_byte()..end.start_byte()];
+        writer.verbatim_lines(inner, writer.level + 1);
+        writer.new_line(writer.level);
+        writer.token(self.text(end), end, false);
+    }
+
+    ///
+    /// Multi-line lists of values are kept as written, with the indentation of each line
+    /// adjusted to the nesting of the list.
+    ///
+    fn write_verbatim(&self, writer: &mut LineWriter<'a>, node: Node<'_>) {
+        let text = self.text(node);
+        let (first, rest) = text.split_once('\n').unwrap_or((text, ""));
+        writer.token(first.trim_end(), node, false);
+        writer.end_line();
+        let (rest, closing) = match rest.rsplit_once('\n') {
+            Some((rest, last)) if last.trim_start().starts_with(']') => (rest, last.trim()),
+            _ => (rest, ""),
+        };
+        writer.verbatim_lines(&format!("\n{rest}"), writer.level + 1);
+        writer.new_line(writer.level);
+        if !closing.is_empty() {
+            writer.current.push_str(closing);
+            writer.previous = Some("]");
+        }
+        writer.last_end = Some(node.end_byte());
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> LineWriter<'a> {
+    fn new(source: &'a str, level: usize) -> Self {
+        Self {
+            source,
+            level,
+            lines: Default::default(),
+            current: Default::default(),
+            current_level: level,
+            previous: None,
+            last_end: None,
+            break_next: false,
+            started: false,
+        }
+    }
+
+    fn finish(mut self) -> Vec<(usize, String)> {
+        self.end_line();
+        self.lines
+    }
+
+    fn end_line(&mut self) {
+        if !self.current.is_empty() {
+            let line = std::mem::take(&mut self.current);
+            self.lines.push((self.current_level, line));
+        }
+    }
+
+    fn new_line(&mut self, level: usize) {
+        self.end_line();
+        self.current_level = level;
+        self.previous = None;
+        self.break_next = false;
+    }
+
+    fn newlines_before(&self, start: usize) -> usize {
+        self.last_end
+            .map(|end| self.source[end..start].matches('\n').count())
+            .unwrap_or_default()
+    }
+
+    fn token(&mut self, text: &'a str, node: Node<'_>, attached: bool) {
+        if self.break_next {
+            if self.newlines_before(node.start_byte()) > 1 {
+                self.end_line();
+                self.lines.push((0, String::new()));
+            }
+            let level = if self.started {
+                self.level + 1
+            } else {
+                self.level
+            };
+            self.new_line(level);
+        } else if let Some(previous) = self.previous {
+            if !attached && needs_space(previous, text, node) {
+                self.current.push(' ');
+            }
+        }
+        self.current.push_str(text);
+        self.started = true;
+        self.previous = Some(text);
+        self.last_end = Some(node.end_byte());
+    }
+
+    fn comment(&mut self, text: &str, start: usize, end: usize) {
+        let newlines = self.newlines_before(start);
+        if !self.current.is_empty() && newlines == 0 {
+            self.current.push(' ');
+            self.current.push_str(text);
+        } else {
+            self.end_line();
+            if newlines > 1 && !self.lines.is_empty() {
+                self.lines.push((0, String::new()));
+            }
+            let level = if self.started {
+                self.level + 1
+            } else {
+                self.level
+            };
+            self.lines.push((level, text.to_string()));
+        }
+        self.last_end = Some(end);
+        self.break_next = true;
+    }
+
+    ///
+    /// Add `text` as lines at `level`, keeping the indentation of each line relative to the
+    /// least indented line. The first line of `text` is the remainder of a line already
+    /// written, and so has its indentation removed. Leading and trailing blank lines are
+    /// removed, and runs of blank lines are reduced to one.
+    ///
+    fn verbatim_lines(&mut self, text: &str, level: usize) {
+        let lines = text.lines().map(str::trim_end).collect::<Vec<_>>();
+        let least = lines
+            .iter()
+            .skip(1)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+            .min()
+            .unwrap_or_default();
+        let mut written = false;
+        let mut blank = false;
+        for (i, line) in lines.iter().enumerate() {
+            let line = if i == 0 {
+                line.trim_start()
+            } else {
+                &line[least.min(line.len())..]
+            };
+            if line.is_empty() {
+                blank = true;
+            } else {
+                if blank && written {
+                    self.lines.push((0, String::new()));
+                }
+                blank = false;
+                written = true;
+                self.lines.push((level, line.to_string()));
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn first_error_node(node: Node<'_>) -> Option<Node<'_>> {
+    if node.is_error() || node.is_missing() {
+        Some(node)
+    } else {
+        let mut cursor = node.walk();
+        let children = node.children(&mut cursor).collect::<Vec<_>>();
+        children.into_iter().find_map(first_error_node)
+    }
+}
+
+fn is_multi_line(node: Node<'_>) -> bool {
+    node.start_position().row != node.end_position().row
+}
+
+fn is_body(kind: &str) -> bool {
+    [
+        NODE_KIND_MODULE_BODY,
+        NODE_KIND_ANNOTATION_ONLY_BODY,
+        NODE_KIND_ENTITY_BODY,
+        NODE_KIND_ENUM_BODY,
+        NODE_KIND_STRUCTURED_BODY,
+        NODE_KIND_TYPE_CLASS_BODY,
+        NODE_KIND_UNION_BODY,
+    ]
+    .contains(&kind)
+}
+
+///
+/// Nodes written exactly as they appear in the source, these either contain no whitespace or
+/// the whitespace is significant.
+///
+fn is_atom(kind: &str) -> bool {
+    [
+        NODE_KIND_FORMAL_CONSTRAINT,
+        NODE_KIND_FUNCTION_COMPOSITION,
+        NODE_KIND_IRI,
+        NODE_KIND_QUALIFIED_IDENTIFIER,
+        NODE_KIND_QUOTED_STRING,
+        NODE_KIND_STRING,
+    ]
+    .contains(&kind)
+}
+
+fn is_attached(node: Node<'_>) -> bool {
+    node.kind() == NODE_KIND_LANGUAGE_TAG || node.kind() == NODE_KIND_CONTROLLED_LANGUAGE_TAG
+}
+
+fn needs_space(previous: &str, current: &str, node: Node<'_>) -> bool {
+    let parent = node
+        .parent()
+        .map(|parent| parent.kind())
+        .unwrap_or_default();
+    !(matches!(previous, "(" | "{" | "@" | ".." | "?")
+        || matches!(current, ")" | "}" | "," | "..")
+        || (previous.ends_with('[') && current == "]")
+        || (current == "("
+            && [
+                NODE_KIND_ACTUAL_ARGUMENTS,
+                NODE_KIND_FUNCTION_SIGNATURE,
+                NODE_KIND_TYPE_CLASS_ARGUMENTS,
+                NODE_KIND_VALUE_CONSTRUCTOR,
+            ]
+            .contains(&parent)))
+}
+
+///
+/// Sort each run of import statements, not separated by blank lines or other items, along with
+/// any comments on the lines preceding each statement.
+///
+fn sort_imports(entries: &mut Vec<Entry>) {
+    fn flush(sorted: &mut Vec<Entry>, run: &mut Vec<(Vec<String>, Vec<Entry>)>) {
+        run.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        sorted.extend(run.drain(..).flat_map(|(_, entries)| entries));
+    }
+
+    let mut sorted = Vec::with_capacity(entries.len());
+    let mut run: Vec<(Vec<String>, Vec<Entry>)> = Default::default();
+    let mut comments: Vec<Entry> = Default::default();
+    for entry in entries.drain(..) {
+        match entry {
+            Entry::Comment(_) => comments.push(entry),
+            Entry::Item {
+                kind: ItemKind::Import(ref names),
+                ..
+            } => {
+                let key = names.clone();
+                comments.push(entry);
+                run.push((key, std::mem::take(&mut comments)));
+            }
+            _ => {
+                flush(&mut sorted, &mut run);
+                sorted.append(&mut comments);
+                sorted.push(entry);
+            }
+        }
+    }
+    flush(&mut sorted, &mut run);
+    sorted.append(&mut comments);
+    *entries = sorted;
+}
+
+///
+/// Align the `=` of each run of consecutive annotation properties.
+///
+fn align_annotations(entries: &mut [Entry]) {
+    let mut start = 0;
+    while start < entries.len() {
+        let end = start
+            + entries[start..]
+                .iter()
+                .take_while(|entry| {
+                    matches!(
+                        entry,
+                        Entry::Item {
+                            kind: ItemKind::Annotation,
+                            ..
+                        }
+                    )
+                })
+                .count();
+        if end - start > 1 {
+            let width = entries[start..end]
+                .iter()
+                .filter_map(annotation_name_width)
+                .max()
+                .unwrap_or_default();
+            for entry in &mut entries[start..end] {
+                if let (Some(name_width), Entry::Item { lines, .. }) =
+                    (annotation_name_width(entry), entry)
+                {
+                    let (_, line) = &mut lines[0];
+                    let at = line.find(' ').unwrap();
+                    let padding = format!("{:n$}", "", n = width - name_width);
+                    line.insert_str(at, &padding);
+                }
+            }
+        }
+        start = end + 1;
+    }
+}
+
+fn annotation_name_width(entry: &Entry) -> Option<usize> {
+    match entry {
+        Entry::Item { lines, .. } => lines
+            .first()
+            .and_then(|(_, line)| line.split_once(' '))
+            .map(|(name, _)| name.chars().count()),
+        _ => None,
+    }
+}
@@ -9,6 +9,13 @@ This module provides a set of *actions* that can be performed on an SDML file.
 #[cfg(feature = "deps")]
 pub mod deps;
 
+#[cfg(feature = "json")]
+pub mod diff;
+
+pub mod flatten;
+
+pub mod format;
+
 #[cfg(feature = "hl")]
 pub mod highlight;
 
@@ -25,6 +25,38 @@ pub fn write_ctags<W: Write>(
     file_name: Option<&PathBuf>,
     w: &mut W,
 ) -> Result<(), Error> {
+    let mut tags = module_ctags(module, file_name);
+    write_ctag_lines(&mut tags, w)
+}
+
+///
+/// Write a single CTag file for all `modules`, using each module's source file name.
+///
+pub fn write_ctags_for_modules<W: Write>(modules: &[&Module], w: &mut W) -> Result<(), Error> {
+    let mut tags = modules
+        .iter()
+        .flat_map(|module| module_ctags(module, module.source_file()))
+        .collect();
+    write_ctag_lines(&mut tags, w)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Macros
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn module_ctags(module: &Module, file_name: Option<&PathBuf>) -> Vec<(String, String)> {
     let file_name: String = file_name
         .map(|file| file.to_string_lossy().into_owned())
         .unwrap_or_else(|| module.name().to_string());
@@ -85,6 +117,10 @@ pub fn write_ctags<W: Write>(
         }
     }
 
+    tags
+}
+
+fn write_ctag_lines<W: Write>(tags: &mut Vec<(String, String)>, w: &mut W) -> Result<(), Error> {
     tags.sort_by_cached_key(|v| v.0.clone());
 
     for line in tags {
@@ -94,22 +130,6 @@ pub fn write_ctags<W: Write>(
     Ok(())
 }
 
-// ------------------------------------------------------------------------------------------------
-// Private Macros
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Private Functions
-// ------------------------------------------------------------------------------------------------
-
 #[inline(always)]
 fn ctag_line(named: &impl HasName, file_name: &str) -> (String, String) {
     ctag_line_from(named.name(), file_name)
@@ -0,0 +1,468 @@
+/*!
+This module provides a generator that creates Apache Avro schemas for the events and structures
+of a module.
+
+Each event and structure is an Avro `record` with a field for each member, and the types of
+members are mapped as follows.
+
+* **structures** and **events** are records, defined in full where first used and referenced by
+  their full name after that.
+* **enums** are Avro `enum`s with a symbol for each variant.
+* **entities** are represented by the type of their identity member, as records carry the keys of
+  entities rather than their state.
+* **unions** are Avro unions of the types of their variants.
+* **datatypes** are the Avro type of their simple base type; dates, date-times, times, and
+  decimals use the Avro logical types `date`, `timestamp-millis`, `time-millis`, and `decimal`,
+  with the precision and scale of a decimal taken from any `xsd:totalDigits` and
+  `xsd:fractionDigits` facets.
+* **mapping types** are Avro `map`s.
+
+A member with the cardinality `{0..1}` has a union of `"null"` and its type, with the default
+`null`, and a member with a maximum cardinality greater than one is an `array`; one that may be
+empty has the default `[]`.
+
+The namespace of each named type is derived from the base URI of the module that defines it,
+the reversed host name followed by the path, so that a type in the module with the base
+`https://example.com/rentals#` has the namespace `com.example.rentals`. A module without a base
+URI uses its name as the namespace.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::avro::{AvroGenerator, AvroGeneratorOptions};
+
+fn print_event_schema(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = AvroGenerator::default();
+    let options = AvroGeneratorOptions::default()
+        .pretty_print(true)
+        .with_record("VehicleRented");
+    let schema = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{schema}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, facet_name, member_def, module_named, resolve_datatype, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::errors::generator_error;
+use crate::Generator;
+use sdml_core::error::Error;
+use sdml_core::model::annotations::AnnotationProperty;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::members::{Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use serde_json::{json, Map, Value as JsonValue};
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for the Avro schemas of a module's events and structures.
+///
+#[derive(Debug, Default)]
+pub struct AvroGenerator {}
+
+///
+/// Options for the Avro generator. By default the output is a JSON array of the schemas of all
+/// events and structures in the module; with a record name it is the single, self-contained,
+/// schema for that event or structure as expected by a schema registry.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AvroGeneratorOptions {
+    pretty_print: bool,
+    record: Option<String>,
+}
+
+///
+/// The precision of a decimal without an `xsd:totalDigits` facet.
+///
+pub const DEFAULT_DECIMAL_PRECISION: u64 = 38;
+
+///
+/// The scale of a decimal without an `xsd:fractionDigits` facet.
+///
+pub const DEFAULT_DECIMAL_SCALE: u64 = 9;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl AvroGeneratorOptions {
+    pub fn pretty_print(self, pretty_print: bool) -> Self {
+        Self {
+            pretty_print,
+            ..self
+        }
+    }
+
+    ///
+    /// Generate only the schema for the event or structure named `record`.
+    ///
+    pub fn with_record<S>(self, record: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            record: Some(record.into()),
+            ..self
+        }
+    }
+
+    pub fn record(&self) -> Option<&String> {
+        self.record.as_ref()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for AvroGenerator {
+    type Options = AvroGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let mut context = Context {
+            cache,
+            defined: Default::default(),
+        };
+        let schema = match &options.record {
+            Some(record) => module
+                .body()
+                .definitions()
+                .filter(|definition| definition.name().as_ref() == record)
+                .find_map(|definition| context.record_definition(module, definition))
+                .ok_or_else(|| {
+                    generator_error(
+                        GENERATOR_NAME,
+                        format!(
+                            "module {} has no event or structure named {record}",
+                            module.name()
+                        ),
+                    )
+                })?,
+            None => JsonValue::Array(
+                module
+                    .body()
+                    .definitions()
+                    .filter_map(|definition| context.record_definition(module, definition))
+                    .filter(|schema| schema.is_object())
+                    .collect(),
+            ),
+        };
+
+        if options.pretty_print {
+            serde_json::to_writer_pretty(&mut *writer, &schema).map_err(into_generator_error)?;
+            writeln!(writer)?;
+        } else {
+            serde_json::to_writer(writer, &schema).map_err(into_generator_error)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    cache: &'a S,
+    /// The full names of the named types already defined.
+    defined: HashSet<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    ///
+    /// Return the record schema for `definition` if it is an event or structure; if the record
+    /// has already been defined this is its full name.
+    ///
+    fn record_definition(&mut self, module: &Module, definition: &Definition) -> Option<JsonValue> {
+        let body = match definition {
+            Definition::Event(event) => event.body(),
+            Definition::Structure(structure) => structure.body(),
+            _ => return None,
+        };
+        Some(self.record(module, definition.name().as_ref(), body))
+    }
+
+    fn record(&mut self, module: &Module, name: &str, body: Option<&StructureBody>) -> JsonValue {
+        let namespace = namespace(module);
+        let full_name = format!("{namespace}.{name}");
+        if !self.defined.insert(full_name.clone()) {
+            return full_name.into();
+        }
+
+        let mut schema = Map::default();
+        schema.insert("type".into(), "record".into());
+        schema.insert("name".into(), name.into());
+        schema.insert("namespace".into(), namespace.into());
+        if let Some(description) = body.and_then(description) {
+            schema.insert("doc".into(), description.into());
+        }
+        let fields: Vec<JsonValue> = body
+            .map(|body| {
+                body.members()
+                    .map(|member| self.field(module, member))
+                    .collect()
+            })
+            .unwrap_or_default();
+        schema.insert("fields".into(), fields.into());
+        schema.into()
+    }
+
+    fn field(&mut self, module: &Module, member: &Member) -> JsonValue {
+        let mut field = Map::default();
+        field.insert("name".into(), member.name().to_string().into());
+        match member_def(member, module, self.cache) {
+            Some(member_def) => {
+                let cardinality = member_def.target_cardinality();
+                let schema = self.type_schema(module, member_def.target_type());
+                if cardinality.max_occurs() != Some(1) {
+                    field.insert("type".into(), json!({ "type": "array", "items": schema }));
+                    if cardinality.min_occurs() == 0 {
+                        field.insert("default".into(), json!([]));
+                    }
+                } else if cardinality.min_occurs() == 0 {
+                    field.insert("type".into(), nullable(schema));
+                    field.insert("default".into(), JsonValue::Null);
+                } else {
+                    field.insert("type".into(), schema);
+                }
+                if let Some(description) = member_def.body().and_then(description) {
+                    field.insert("doc".into(), description.into());
+                }
+            }
+            None => {
+                field.insert("type".into(), "string".into());
+            }
+        }
+        field.into()
+    }
+
+    fn type_schema(&mut self, module: &Module, type_ref: &TypeReference) -> JsonValue {
+        match resolve_type(type_ref, module, self.cache) {
+            ResolvedType::Unknown => "string".into(),
+            ResolvedType::Simple(simple_type) => simple_type_schema(simple_type, &[]),
+            ResolvedType::Mapping(mapping) => {
+                json!({ "type": "map", "values": self.type_schema(module, mapping.range()) })
+            }
+            ResolvedType::Definition(name, definition) => {
+                self.definition_schema(module, &name, definition)
+            }
+            ResolvedType::Unresolved(name) => {
+                let namespace = module_named(name.module(), module, self.cache)
+                    .map(namespace)
+                    .unwrap_or_else(|| name.module().to_string());
+                format!("{namespace}.{}", name.member()).into()
+            }
+        }
+    }
+
+    fn definition_schema(
+        &mut self,
+        module: &Module,
+        name: &QualifiedIdentifier,
+        definition: &Definition,
+    ) -> JsonValue {
+        let module = module_named(name.module(), module, self.cache).unwrap_or(module);
+        match definition {
+            Definition::Event(event) => self.record(module, name.member().as_ref(), event.body()),
+            Definition::Structure(structure) => {
+                self.record(module, name.member().as_ref(), structure.body())
+            }
+            Definition::Entity(entity) => match entity.body() {
+                Some(body) => match member_def(body.identity(), module, self.cache) {
+                    Some(identity) => self.type_schema(module, identity.target_type()),
+                    None => "string".into(),
+                },
+                None => "string".into(),
+            },
+            Definition::Enum(enum_def) => {
+                let namespace = namespace(module);
+                let full_name = format!("{namespace}.{}", name.member());
+                if !self.defined.insert(full_name.clone()) {
+                    return full_name.into();
+                }
+                let mut schema = Map::default();
+                schema.insert("type".into(), "enum".into());
+                schema.insert("name".into(), name.member().to_string().into());
+                schema.insert("namespace".into(), namespace.into());
+                if let Some(description) = enum_def.body().and_then(description) {
+                    schema.insert("doc".into(), description.into());
+                }
+                let symbols: Vec<JsonValue> = enum_def
+                    .body()
+                    .map(|body| {
+                        body.variants()
+                            .map(|variant| variant.name().to_string().into())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                schema.insert("symbols".into(), symbols.into());
+                schema.into()
+            }
+            Definition::Union(union_def) => {
+                let mut variants: Vec<JsonValue> = Vec::default();
+                if let Some(body) = union_def.body() {
+                    for variant in body.variants() {
+                        let schema = self.type_schema(
+                            module,
+                            &TypeReference::Type(variant.name_reference().clone()),
+                        );
+                        // Avro unions may not contain unions, or repeat a type.
+                        for schema in flatten_union(schema) {
+                            if !variants.contains(&schema) {
+                                variants.push(schema);
+                            }
+                        }
+                    }
+                }
+                variants.into()
+            }
+            Definition::Datatype(datatype) => {
+                let resolved = resolve_datatype(datatype, module, self.cache);
+                simple_type_schema(resolved.simple_type, &resolved.facets)
+            }
+            Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => {
+                "string".into()
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the namespace for the named types in `module`, derived from its base URI.
+///
+fn namespace(module: &Module) -> String {
+    module
+        .base_uri()
+        .and_then(|base_uri| {
+            let url = base_uri.as_ref();
+            let mut parts: Vec<&str> = url.host_str()?.split('.').rev().collect();
+            if let Some(segments) = url.path_segments() {
+                parts.extend(segments);
+            }
+            let parts: Vec<String> = parts
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    let part: String = part
+                        .chars()
+                        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                        .collect();
+                    if part.starts_with(|c: char| c.is_ascii_digit()) {
+                        format!("_{part}")
+                    } else {
+                        part
+                    }
+                })
+                .collect();
+            (!parts.is_empty()).then(|| parts.join("."))
+        })
+        .unwrap_or_else(|| module.name().to_string())
+}
+
+fn simple_type_schema(simple_type: SimpleType, facets: &[&AnnotationProperty]) -> JsonValue {
+    match simple_type {
+        SimpleType::Boolean => "boolean".into(),
+        SimpleType::String | SimpleType::Iri | SimpleType::Duration => "string".into(),
+        SimpleType::Integer
+        | SimpleType::Long
+        | SimpleType::Unsigned
+        | SimpleType::UnsignedLong
+        | SimpleType::UnsignedInt => "long".into(),
+        SimpleType::Int
+        | SimpleType::Short
+        | SimpleType::Byte
+        | SimpleType::UnsignedShort
+        | SimpleType::UnsignedByte => "int".into(),
+        SimpleType::Double => "double".into(),
+        SimpleType::Float => "float".into(),
+        SimpleType::Binary => "bytes".into(),
+        SimpleType::Date => json!({ "type": "int", "logicalType": "date" }),
+        SimpleType::DateTime => json!({ "type": "long", "logicalType": "timestamp-millis" }),
+        SimpleType::Time => json!({ "type": "int", "logicalType": "time-millis" }),
+        SimpleType::Decimal => {
+            let precision =
+                facet_value(facets, stdlib::xsd::TOTAL_DIGITS).unwrap_or(DEFAULT_DECIMAL_PRECISION);
+            let scale = facet_value(facets, stdlib::xsd::FRACTION_DIGITS)
+                .unwrap_or(DEFAULT_DECIMAL_SCALE)
+                .min(precision);
+            json!({
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": precision,
+                "scale": scale
+            })
+        }
+    }
+}
+
+fn facet_value(facets: &[&AnnotationProperty], name: &str) -> Option<u64> {
+    facets
+        .iter()
+        .find(|facet| facet_name(facet) == Some(name))
+        .and_then(|facet| match facet.value() {
+            Value::Simple(SimpleValue::Unsigned(v)) => Some(*v),
+            Value::Simple(SimpleValue::Integer(v)) => u64::try_from(*v).ok(),
+            _ => None,
+        })
+}
+
+fn flatten_union(schema: JsonValue) -> Vec<JsonValue> {
+    match schema {
+        JsonValue::Array(variants) => variants,
+        schema => vec![schema],
+    }
+}
+
+fn nullable(schema: JsonValue) -> JsonValue {
+    let mut variants = flatten_union(schema);
+    variants.retain(|variant| variant != "null");
+    variants.insert(0, "null".into());
+    variants.into()
+}
+
+#[inline(always)]
+fn into_generator_error(e: serde_json::Error) -> Error {
+    crate::errors::into_generator_error(GENERATOR_NAME, e)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const GENERATOR_NAME: &str = "Avro";
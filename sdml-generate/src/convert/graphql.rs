@@ -0,0 +1,421 @@
+/*!
+This module provides a generator that creates a GraphQL schema, in the schema definition
+language (SDL), for the definitions of a module.
+
+The definitions of a module are mapped as follows.
+
+* **entities**, **structures**, and **events** are object `type`s with a field for each member;
+  the identity of an entity is a field of type `ID!`.
+* **enums** are `enum` types with a value for each variant.
+* **unions** of object types are `union` types, a union that includes any other type is a custom
+  `scalar` as GraphQL unions may only contain object types.
+* **datatypes** are not themselves types, a member of a datatype has the scalar type of the
+  datatype's simple base type.
+* **properties**, **rdf** definitions, and **type classes** have no corresponding GraphQL types
+  and are not included.
+
+A member with a maximum cardinality greater than one is a list, and a member with a minimum
+cardinality greater than zero is non-null; so `{0..1} T` is `T`, `{1..1} T` is `T!`, `{0..} T`
+is `[T!]`, and `{1..} T` is `[T!]!`. The `skos:definition`, or `dc:description`, of a definition
+or member is its GraphQL description.
+
+The simple types of the standard library map to the built-in scalars where they can, and to the
+custom scalars `Long`, `BigInt`, `Date`, `DateTime`, `Time`, `Duration`, `IRI`, and `Binary`
+otherwise; as the built-in `Int` is a signed 32-bit value, integer types that may not fit in 32
+bits are `Long` if they fit in 64 bits and `BigInt` if not. Mapping types are the custom scalar
+`JSON`. Any of these may be replaced, and any datatype given its own
+scalar, using [`GraphQlGeneratorOptions::with_scalar`]. All custom scalars used are declared at
+the start of the schema.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::graphql::{GraphQlGenerator, GraphQlGeneratorOptions};
+
+fn print_schema(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = GraphQlGenerator::default();
+    let options = GraphQlGeneratorOptions::default()
+        .with_scalar("xsd:long", "BigInt")
+        .with_scalar("rentals:Vin", "VIN");
+    let schema = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{schema}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, member_def, module_named, qualify, resolve_datatype, resolve_reference,
+    ResolvedType, SimpleType,
+};
+use crate::Generator;
+use heck::ToLowerCamelCase;
+use sdml_core::error::Error;
+use sdml_core::model::annotations::HasAnnotations;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::members::{Cardinality, Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::store::ModuleStore;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for the GraphQL schema of a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct GraphQlGenerator {}
+
+///
+/// Options for the GraphQL generator, the mapping from type names to scalar names overrides the
+/// default scalar for a library type, such as `xsd:long`, or for a datatype such as
+/// `rentals:Vin`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphQlGeneratorOptions {
+    scalars: BTreeMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl GraphQlGeneratorOptions {
+    ///
+    /// Map the type with the qualified name `type_name` to the scalar named `scalar`.
+    ///
+    pub fn with_scalar<S1, S2>(mut self, type_name: S1, scalar: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.scalars.insert(type_name.into(), scalar.into());
+        self
+    }
+
+    pub fn scalar(&self, type_name: &str) -> Option<&String> {
+        self.scalars.get(type_name)
+    }
+
+    pub fn scalars(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.scalars.iter()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for GraphQlGenerator {
+    type Options = GraphQlGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let mut context = Context {
+            module,
+            cache,
+            options: &options,
+            custom_scalars: Default::default(),
+        };
+        let blocks: Vec<String> = module
+            .body()
+            .definitions()
+            .filter_map(|definition| context.definition(definition))
+            .collect();
+
+        writeln!(writer, "# Module: {}", module.name())?;
+        if !context.custom_scalars.is_empty() {
+            writeln!(writer)?;
+            for scalar in &context.custom_scalars {
+                writeln!(writer, "scalar {scalar}")?;
+            }
+        }
+        for block in blocks {
+            writeln!(writer, "\n{block}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    module: &'a Module,
+    cache: &'a S,
+    options: &'a GraphQlGeneratorOptions,
+    custom_scalars: BTreeSet<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    ///
+    /// Return the GraphQL type definition for `definition`, if it has one.
+    ///
+    fn definition(&mut self, definition: &Definition) -> Option<String> {
+        let name = definition.name();
+        let block = match definition {
+            Definition::Entity(entity) => {
+                let body = entity.body()?;
+                let mut fields = vec![self.field(body.identity(), true)];
+                fields.extend(body.members().map(|member| self.field(member, false)));
+                with_description(body, 0, object_type(name.as_ref(), fields))
+            }
+            Definition::Structure(structure) => self.structure(name.as_ref(), structure.body()),
+            Definition::Event(event) => self.structure(name.as_ref(), event.body()),
+            Definition::Enum(enum_def) => {
+                let values = enum_def
+                    .body()
+                    .map(|body| {
+                        body.variants()
+                            .map(|variant| match variant.body() {
+                                Some(body) => with_description(body, 1, variant.name().to_string()),
+                                None => format!("  {}", variant.name()),
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let block = if values.is_empty() {
+                    format!("enum {name}")
+                } else {
+                    format!("enum {name} {{\n{}\n}}", values.join("\n"))
+                };
+                match enum_def.body() {
+                    Some(body) => with_description(body, 0, block),
+                    None => block,
+                }
+            }
+            Definition::Union(union_def) => {
+                let variants: Vec<(String, bool)> = union_def
+                    .body()
+                    .map(|body| {
+                        body.variants()
+                            .map(|variant| {
+                                let resolved = resolve_reference(
+                                    variant.name_reference(),
+                                    self.module,
+                                    self.cache,
+                                );
+                                let is_object = matches!(
+                                    resolved,
+                                    ResolvedType::Definition(
+                                        _,
+                                        Definition::Entity(_)
+                                            | Definition::Structure(_)
+                                            | Definition::Event(_)
+                                    ) | ResolvedType::Unresolved(_)
+                                );
+                                (
+                                    qualify(variant.name_reference(), self.module)
+                                        .member()
+                                        .to_string(),
+                                    is_object,
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let block = if !variants.is_empty() && variants.iter().all(|(_, object)| *object) {
+                    format!(
+                        "union {name} = {}",
+                        variants
+                            .into_iter()
+                            .map(|(name, _)| name)
+                            .collect::<Vec<_>>()
+                            .join(" | ")
+                    )
+                } else {
+                    format!("scalar {name}")
+                };
+                match union_def.body() {
+                    Some(body) => with_description(body, 0, block),
+                    None => block,
+                }
+            }
+            Definition::Datatype(_)
+            | Definition::Property(_)
+            | Definition::Rdf(_)
+            | Definition::TypeClass(_) => return None,
+        };
+        Some(block)
+    }
+
+    fn structure(&mut self, name: &str, body: Option<&StructureBody>) -> String {
+        match body {
+            Some(body) => {
+                let fields = body
+                    .members()
+                    .map(|member| self.field(member, false))
+                    .collect();
+                with_description(body, 0, object_type(name, fields))
+            }
+            None => format!("type {name}"),
+        }
+    }
+
+    ///
+    /// Return the field definition for `member`, an identity member is always an `ID!`.
+    ///
+    fn field(&mut self, member: &Member, is_identity: bool) -> String {
+        let name = member.name().as_ref().to_lower_camel_case();
+        let Some(member_def) = member_def(member, self.module, self.cache) else {
+            return format!("  {name}: String");
+        };
+        let field = if is_identity {
+            format!("{name}: ID!")
+        } else {
+            let type_name = self.type_name(member_def.target_type());
+            format!(
+                "{name}: {}",
+                wrapped_type(type_name, member_def.target_cardinality())
+            )
+        };
+        match member_def.body() {
+            Some(body) => with_description(body, 1, field),
+            None => format!("  {field}"),
+        }
+    }
+
+    ///
+    /// Return the name of the GraphQL type for `type_ref`, recording any custom scalar used.
+    ///
+    fn type_name(&mut self, type_ref: &TypeReference) -> String {
+        let reference = match type_ref {
+            TypeReference::Unknown => return "String".to_string(),
+            TypeReference::MappingType(_) => return self.scalar("JSON".to_string()),
+            TypeReference::Type(reference) => reference,
+        };
+        let name = qualify(reference, self.module);
+        if let Some(scalar) = self.options.scalar(&name.to_string()) {
+            return self.scalar(scalar.clone());
+        }
+        match resolve_reference(reference, self.module, self.cache) {
+            ResolvedType::Simple(simple_type) => self.scalar(default_scalar(simple_type)),
+            ResolvedType::Definition(name, Definition::Datatype(datatype)) => {
+                let datatype_module =
+                    module_named(name.module(), self.module, self.cache).unwrap_or(self.module);
+                let resolved = resolve_datatype(datatype, datatype_module, self.cache);
+                let scalar = resolved
+                    .base_type
+                    .and_then(|base_type| self.options.scalar(&base_type.to_string()).cloned())
+                    .unwrap_or_else(|| default_scalar(resolved.simple_type));
+                self.scalar(scalar)
+            }
+            ResolvedType::Definition(
+                _,
+                Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_),
+            ) => "String".to_string(),
+            ResolvedType::Definition(name, _) | ResolvedType::Unresolved(name) => {
+                name.member().to_string()
+            }
+            ResolvedType::Unknown | ResolvedType::Mapping(_) => "String".to_string(),
+        }
+    }
+
+    fn scalar(&mut self, scalar: String) -> String {
+        if !BUILT_IN_SCALARS.contains(&scalar.as_str()) {
+            self.custom_scalars.insert(scalar.clone());
+        }
+        scalar
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn default_scalar(simple_type: SimpleType) -> String {
+    match simple_type {
+        SimpleType::Boolean => "Boolean",
+        SimpleType::String => "String",
+        SimpleType::Int
+        | SimpleType::Short
+        | SimpleType::Byte
+        | SimpleType::UnsignedShort
+        | SimpleType::UnsignedByte => "Int",
+        SimpleType::Long | SimpleType::UnsignedInt => "Long",
+        SimpleType::Integer | SimpleType::Unsigned | SimpleType::UnsignedLong => "BigInt",
+        SimpleType::Decimal | SimpleType::Double | SimpleType::Float => "Float",
+        SimpleType::Date => "Date",
+        SimpleType::DateTime => "DateTime",
+        SimpleType::Time => "Time",
+        SimpleType::Duration => "Duration",
+        SimpleType::Iri => "IRI",
+        SimpleType::Binary => "Binary",
+    }
+    .to_string()
+}
+
+fn wrapped_type(type_name: String, cardinality: &Cardinality) -> String {
+    let required = if cardinality.min_occurs() > 0 {
+        "!"
+    } else {
+        ""
+    };
+    if cardinality.max_occurs() == Some(1) {
+        format!("{type_name}{required}")
+    } else {
+        format!("[{type_name}!]{required}")
+    }
+}
+
+fn object_type(name: &str, fields: Vec<String>) -> String {
+    if fields.is_empty() {
+        format!("type {name}")
+    } else {
+        format!("type {name} {{\n{}\n}}", fields.join("\n"))
+    }
+}
+
+///
+/// Return `item`, indented by `depth` levels, preceded by the description of `annotated` if it
+/// has one.
+///
+fn with_description<T: HasAnnotations>(annotated: &T, depth: usize, item: String) -> String {
+    let indent = "  ".repeat(depth);
+    match description(annotated) {
+        Some(description) if description.contains('\n') => format!(
+            "{indent}\"\"\"\n{}\n{indent}\"\"\"\n{indent}{item}",
+            description
+                .replace("\"\"\"", "\\\"\"\"")
+                .lines()
+                .map(|line| format!("{indent}{line}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        Some(description) => format!(
+            "{indent}\"{}\"\n{indent}{item}",
+            description.replace('\\', "\\\\").replace('"', "\\\"")
+        ),
+        None => format!("{indent}{item}"),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const BUILT_IN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
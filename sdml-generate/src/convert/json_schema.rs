@@ -0,0 +1,441 @@
+/*!
+This module provides a generator that creates a [JSON Schema](https://json-schema.org/) (draft
+2020-12) document describing the instance data of a module's definitions.
+
+Each definition is added to the `$defs` object of the schema document, as follows.
+
+* **datatypes** are mapped to the JSON type of their simple base type, with any XML Schema facets
+  such as `xsd:pattern` or `xsd:minInclusive` mapped to the corresponding schema keywords.
+* **entities**, **events**, and **structures** are mapped to objects with a property for each
+  member; the identity of an entity, and any member with a minimum cardinality greater than zero,
+  are required.
+* **enums** are mapped to strings with an `enum` of the variant names.
+* **unions** are mapped to a `oneOf` of the variant types.
+* **properties**, **rdf** definitions, and **type classes** have no instance data and are not
+  included.
+
+A member with a maximum cardinality greater than one is an array, with the cardinality bounds
+and uniqueness mapped to `minItems`, `maxItems`, and `uniqueItems`; a mapping type is an object
+with its range type as `additionalProperties`. References to definitions in another module use
+a `$ref` to the schema generated for that module, named `{module}.schema.json`.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::json_schema::{JsonSchemaGenerator, JsonSchemaGeneratorOptions};
+
+fn print_schema(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = JsonSchemaGenerator::default();
+    let options = JsonSchemaGeneratorOptions::default().pretty_print(true);
+    let schema = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{schema}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, facet_name, member_def, resolve_datatype, resolve_reference, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::Generator;
+use sdml_core::error::Error;
+use sdml_core::model::annotations::AnnotationProperty;
+use sdml_core::model::definitions::{
+    DatatypeDef, Definition, EnumDef, HasMembers, HasVariants, StructureBody, UnionDef,
+};
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::members::Member;
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SequenceMember, SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use serde_json::{json, Map, Value as JsonValue};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for a JSON Schema describing the instance data of a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct JsonSchemaGenerator {}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsonSchemaGeneratorOptions {
+    pretty_print: bool,
+}
+
+///
+/// The URI of the JSON Schema dialect used by generated schemas.
+///
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+///
+/// The file extension used for generated schemas, and in references to the schema of another
+/// module.
+///
+pub const JSON_SCHEMA_FILE_EXTENSION: &str = "schema.json";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl JsonSchemaGeneratorOptions {
+    pub fn pretty_print(self, pretty_print: bool) -> Self {
+        Self { pretty_print }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for JsonSchemaGenerator {
+    type Options = JsonSchemaGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let schema = module_schema(module, cache);
+        if options.pretty_print {
+            serde_json::to_writer_pretty(&mut *writer, &schema).map_err(into_generator_error)?;
+            writeln!(writer)?;
+        } else {
+            serde_json::to_writer(writer, &schema).map_err(into_generator_error)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn module_schema(module: &Module, cache: &impl ModuleStore) -> JsonValue {
+    let mut schema = Map::default();
+    schema.insert("$schema".into(), JSON_SCHEMA_DIALECT.into());
+    schema.insert("title".into(), module.name().to_string().into());
+    if let Some(description) = description(module.body()) {
+        schema.insert("description".into(), description.into());
+    }
+
+    let mut definitions = Map::default();
+    for definition in module.body().definitions() {
+        let definition_schema = match definition {
+            Definition::Datatype(v) => Some(datatype_schema(v, module, cache)),
+            Definition::Entity(v) => {
+                let mut members = Vec::default();
+                if let Some(body) = v.body() {
+                    members.push((body.identity(), true));
+                    members.extend(body.members().map(|member| (member, false)));
+                }
+                Some(object_schema(members, module, cache))
+            }
+            Definition::Enum(v) => Some(enum_schema(v)),
+            Definition::Event(v) => Some(structure_schema(v.body(), module, cache)),
+            Definition::Structure(v) => Some(structure_schema(v.body(), module, cache)),
+            Definition::Union(v) => Some(union_schema(v, module, cache)),
+            Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => None,
+        };
+        if let Some(mut definition_schema) = definition_schema {
+            if let Some(description) = definition_description(definition) {
+                definition_schema.insert("description".into(), description.into());
+            }
+            definitions.insert(
+                definition.name().to_string(),
+                JsonValue::Object(definition_schema),
+            );
+        }
+    }
+    schema.insert("$defs".into(), JsonValue::Object(definitions));
+
+    JsonValue::Object(schema)
+}
+
+fn definition_description(definition: &Definition) -> Option<String> {
+    match definition {
+        Definition::Datatype(v) => v.body().and_then(description),
+        Definition::Entity(v) => v.body().and_then(description),
+        Definition::Enum(v) => v.body().and_then(description),
+        Definition::Event(v) => v.body().and_then(description),
+        Definition::Structure(v) => v.body().and_then(description),
+        Definition::Union(v) => v.body().and_then(description),
+        _ => None,
+    }
+}
+
+fn datatype_schema(
+    datatype: &DatatypeDef,
+    module: &Module,
+    cache: &impl ModuleStore,
+) -> Map<String, JsonValue> {
+    let resolved = resolve_datatype(datatype, module, cache);
+    let mut schema = simple_type_schema(resolved.simple_type);
+    for facet in resolved.facets {
+        add_facet(&mut schema, facet);
+    }
+    schema
+}
+
+fn add_facet(schema: &mut Map<String, JsonValue>, facet: &AnnotationProperty) {
+    let Some(name) = facet_name(facet) else {
+        return;
+    };
+    let value = facet.value();
+    match name {
+        stdlib::xsd::PATTERN | stdlib::xsd::MIN_LENGTH | stdlib::xsd::MAX_LENGTH => {
+            schema.insert(name.into(), value_to_json(value));
+        }
+        stdlib::xsd::LENGTH => {
+            schema.insert(stdlib::xsd::MIN_LENGTH.into(), value_to_json(value));
+            schema.insert(stdlib::xsd::MAX_LENGTH.into(), value_to_json(value));
+        }
+        stdlib::xsd::MIN_INCLUSIVE => {
+            schema.insert("minimum".into(), value_to_json(value));
+        }
+        stdlib::xsd::MAX_INCLUSIVE => {
+            schema.insert("maximum".into(), value_to_json(value));
+        }
+        stdlib::xsd::MIN_EXCLUSIVE => {
+            schema.insert("exclusiveMinimum".into(), value_to_json(value));
+        }
+        stdlib::xsd::MAX_EXCLUSIVE => {
+            schema.insert("exclusiveMaximum".into(), value_to_json(value));
+        }
+        stdlib::xsd::ENUMERATION => {
+            let values = match value_to_json(value) {
+                JsonValue::Array(values) => values,
+                value => vec![value],
+            };
+            schema.insert("enum".into(), JsonValue::Array(values));
+        }
+        // the digits and white space facets have no corresponding keywords.
+        _ => {}
+    }
+}
+
+fn structure_schema(
+    body: Option<&StructureBody>,
+    module: &Module,
+    cache: &impl ModuleStore,
+) -> Map<String, JsonValue> {
+    let members = body
+        .map(|body| body.members().map(|member| (member, false)).collect())
+        .unwrap_or_default();
+    object_schema(members, module, cache)
+}
+
+///
+/// Return an object schema with a property for each member, the boolean paired with each member
+/// is `true` if the member is always required, as the identity of an entity is.
+///
+fn object_schema(
+    members: Vec<(&Member, bool)>,
+    module: &Module,
+    cache: &impl ModuleStore,
+) -> Map<String, JsonValue> {
+    let mut properties = Map::default();
+    let mut required = Vec::default();
+    for (member, is_identity) in members {
+        let name = member.name().to_string();
+        let Some(member_def) = member_def(member, module, cache) else {
+            properties.insert(name, JsonValue::Object(Map::default()));
+            continue;
+        };
+        let cardinality = member_def.target_cardinality();
+        let item_schema = type_schema(
+            resolve_type(member_def.target_type(), module, cache),
+            module,
+            cache,
+        );
+        let mut member_schema = if cardinality.max_occurs() == Some(1) {
+            item_schema
+        } else {
+            let mut array_schema = Map::default();
+            array_schema.insert("type".into(), "array".into());
+            array_schema.insert("items".into(), JsonValue::Object(item_schema));
+            if cardinality.min_occurs() > 0 {
+                array_schema.insert("minItems".into(), cardinality.min_occurs().into());
+            }
+            if let Some(max) = cardinality.max_occurs() {
+                array_schema.insert("maxItems".into(), max.into());
+            }
+            if cardinality.is_unique() == Some(true) {
+                array_schema.insert("uniqueItems".into(), true.into());
+            }
+            array_schema
+        };
+        if let Some(description) = member_def.body().and_then(description) {
+            member_schema.insert("description".into(), description.into());
+        }
+        if is_identity || cardinality.min_occurs() > 0 {
+            required.push(JsonValue::String(name.clone()));
+        }
+        properties.insert(name, JsonValue::Object(member_schema));
+    }
+
+    let mut schema = Map::default();
+    schema.insert("type".into(), "object".into());
+    schema.insert("properties".into(), JsonValue::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".into(), JsonValue::Array(required));
+    }
+    schema
+}
+
+fn enum_schema(enum_def: &EnumDef) -> Map<String, JsonValue> {
+    let variants: Vec<JsonValue> = enum_def
+        .body()
+        .map(|body| {
+            body.variants()
+                .map(|variant| variant.name().to_string().into())
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut schema = Map::default();
+    schema.insert("type".into(), "string".into());
+    schema.insert("enum".into(), JsonValue::Array(variants));
+    schema
+}
+
+fn union_schema(
+    union_def: &UnionDef,
+    module: &Module,
+    cache: &impl ModuleStore,
+) -> Map<String, JsonValue> {
+    let variants: Vec<JsonValue> = union_def
+        .body()
+        .map(|body| {
+            body.variants()
+                .map(|variant| {
+                    let resolved = resolve_reference(variant.name_reference(), module, cache);
+                    JsonValue::Object(type_schema(resolved, module, cache))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut schema = Map::default();
+    schema.insert("oneOf".into(), JsonValue::Array(variants));
+    schema
+}
+
+fn type_schema(
+    resolved: ResolvedType<'_>,
+    module: &Module,
+    cache: &impl ModuleStore,
+) -> Map<String, JsonValue> {
+    match resolved {
+        ResolvedType::Unknown => Map::default(),
+        ResolvedType::Simple(simple_type) => simple_type_schema(simple_type),
+        ResolvedType::Definition(name, _) | ResolvedType::Unresolved(name) => {
+            definition_ref(&name, module)
+        }
+        ResolvedType::Mapping(mapping) => {
+            // the keys of a JSON object are always strings, so only the range is described.
+            let range = resolve_type(mapping.range(), module, cache);
+            let mut schema = Map::default();
+            schema.insert("type".into(), "object".into());
+            schema.insert(
+                "additionalProperties".into(),
+                JsonValue::Object(type_schema(range, module, cache)),
+            );
+            schema
+        }
+    }
+}
+
+fn definition_ref(name: &QualifiedIdentifier, module: &Module) -> Map<String, JsonValue> {
+    let reference = if name.module() == module.name() {
+        format!("#/$defs/{}", name.member())
+    } else {
+        format!(
+            "{}.{JSON_SCHEMA_FILE_EXTENSION}#/$defs/{}",
+            name.module(),
+            name.member()
+        )
+    };
+    let mut schema = Map::default();
+    schema.insert("$ref".into(), reference.into());
+    schema
+}
+
+fn simple_type_schema(simple_type: SimpleType) -> Map<String, JsonValue> {
+    let schema = match simple_type {
+        SimpleType::Boolean => json!({ "type": "boolean" }),
+        SimpleType::String => json!({ "type": "string" }),
+        SimpleType::Decimal | SimpleType::Double | SimpleType::Float => {
+            json!({ "type": "number" })
+        }
+        SimpleType::Date => json!({ "type": "string", "format": "date" }),
+        SimpleType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        SimpleType::Time => json!({ "type": "string", "format": "time" }),
+        SimpleType::Duration => json!({ "type": "string", "format": "duration" }),
+        SimpleType::Iri => json!({ "type": "string", "format": "uri" }),
+        SimpleType::Binary => json!({ "type": "string", "contentEncoding": "base64" }),
+        v if v.is_unsigned() => json!({ "type": "integer", "minimum": 0 }),
+        _ => json!({ "type": "integer" }),
+    };
+    match schema {
+        JsonValue::Object(schema) => schema,
+        _ => unreachable!(),
+    }
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Simple(v) => simple_value_to_json(v),
+        Value::ValueConstructor(v) => simple_value_to_json(v.value()),
+        Value::Mapping(v) => json!({ v.domain().to_string(): value_to_json(v.range()) }),
+        Value::Reference(v) => v.to_string().into(),
+        Value::List(v) => JsonValue::Array(
+            v.iter()
+                .map(|member| match member {
+                    SequenceMember::Simple(v) => simple_value_to_json(v),
+                    SequenceMember::ValueConstructor(v) => simple_value_to_json(v.value()),
+                    SequenceMember::Reference(v) => v.to_string().into(),
+                    SequenceMember::Mapping(v) => {
+                        json!({ v.domain().to_string(): value_to_json(v.range()) })
+                    }
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn simple_value_to_json(value: &SimpleValue) -> JsonValue {
+    match value {
+        SimpleValue::Boolean(v) => (*v).into(),
+        SimpleValue::Double(v) => v.into_inner().into(),
+        SimpleValue::Decimal(v) => v
+            .to_string()
+            .parse::<serde_json::Number>()
+            .map(JsonValue::Number)
+            .unwrap_or_else(|_| v.to_string().into()),
+        SimpleValue::Integer(v) => (*v).into(),
+        SimpleValue::Unsigned(v) => (*v).into(),
+        SimpleValue::String(v) => v.value().to_string().into(),
+        SimpleValue::IriReference(v) => v.to_string().into(),
+        SimpleValue::Binary(v) => v.to_string().into(),
+    }
+}
+
+#[inline(always)]
+fn into_generator_error(e: serde_json::Error) -> Error {
+    crate::errors::into_generator_error("JSON Schema", e)
+}
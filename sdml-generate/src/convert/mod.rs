@@ -7,14 +7,34 @@ s-expressions.
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "json")]
+pub mod avro;
+
 pub mod doc;
 
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "json")]
+pub mod json_schema;
+
+pub mod graphql;
+
+pub mod protobuf;
+
+pub mod python;
+
 pub mod rdf;
 
+pub mod rust;
+
 #[cfg(feature = "s-expr")]
 pub mod sexpr;
 
 pub mod source;
+
+pub mod sql;
+
+pub mod typescript;
+
+pub(crate) mod types;
@@ -0,0 +1,751 @@
+/*!
+This module provides a generator that creates a Protocol Buffers (proto3) schema for the
+definitions of a module.
+
+The definitions of a module are mapped as follows.
+
+* **entities**, **structures**, and **events** are messages with a field for each member.
+* **enums** are enums with a value for each variant, following the zero-valued
+  `{ENUM}_UNSPECIFIED` value that proto3 requires; a variant named `Unspecified` is this zero
+  value rather than an additional one.
+* **unions** are messages containing a single `oneof` with a field for each variant.
+* **datatypes** are not themselves messages, a member of a datatype has the scalar type of the
+  datatype's simple base type.
+* **properties**, **rdf** definitions, and **type classes** are not included.
+
+A member with a maximum cardinality greater than one is a `repeated` field, one with the
+cardinality `{0..1}` is an `optional` field, and a member with a mapping type is a `map<>` field.
+Definitions in other modules are referenced by their package, the module name, with an import of
+the file `{module}.proto`.
+
+# Field Numbers
+
+The number of a field, or of an enum value, never changes once assigned so that regenerating the
+schema remains compatible with existing messages. A number may be given explicitly with the
+annotation `sdml:fieldNumber`, which takes precedence; otherwise the number recorded in the
+[`FieldNumbers`] passed in the options is used; otherwise the field is new and is given the lowest
+number not already used in the message. The generator's
+[`field_numbers`](ProtobufGenerator::field_numbers) then contain the numbers from the options with
+any new assignments added, and should be saved for the next generation. The numbers of fields that
+have been removed from a message are declared `reserved` and are never reused.
+
+```sdml
+structure Address is
+  street -> string is
+    @sdml:fieldNumber = 3
+  end
+end
+```
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::protobuf::{FieldNumbers, ProtobufGenerator, ProtobufGeneratorOptions};
+
+fn print_schema(module: &Module, cache: &InMemoryModuleCache, saved: &str) -> String {
+    let field_numbers: FieldNumbers = saved.parse().unwrap();
+    let mut generator = ProtobufGenerator::default();
+    let options = ProtobufGeneratorOptions::default().with_field_numbers(field_numbers);
+    let schema = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{schema}");
+    // save the updated numbers
+    generator.field_numbers().to_string()
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, member_def, module_named, resolve_datatype, resolve_reference, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::errors::generator_error;
+use crate::Generator;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use sdml_core::error::Error;
+use sdml_core::model::annotations::HasAnnotations;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::identifiers::{IdentifierReference, QualifiedIdentifier};
+use sdml_core::model::members::{Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasNameReference, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for the Protocol Buffers schema of a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct ProtobufGenerator {
+    field_numbers: FieldNumbers,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtobufGeneratorOptions {
+    field_numbers: FieldNumbers,
+}
+
+///
+/// The numbers assigned to the fields of each message, and the values of each enum, keyed by the
+/// full name of the message or enum and the SDML name of the member or variant.
+///
+/// The text form, used to persist the numbers between generations, has a line for each field
+/// with the form `package.Message.field = number`; blank lines and lines starting with `#` are
+/// ignored.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldNumbers {
+    numbers: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ProtobufGenerator {
+    ///
+    /// The field numbers used by the last generated schema, including those of removed fields.
+    ///
+    pub fn field_numbers(&self) -> &FieldNumbers {
+        &self.field_numbers
+    }
+}
+
+impl Generator for ProtobufGenerator {
+    type Options = ProtobufGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let mut context = Context {
+            module,
+            cache,
+            previous: &options.field_numbers,
+            numbers: options.field_numbers.clone(),
+            imports: Default::default(),
+        };
+        let mut blocks: Vec<String> = Default::default();
+        for definition in module.body().definitions() {
+            if let Some(block) = context.definition(definition)? {
+                blocks.push(block);
+            }
+        }
+
+        writeln!(writer, "syntax = \"proto3\";")?;
+        writeln!(writer, "\npackage {};", module.name())?;
+        if !context.imports.is_empty() {
+            writeln!(writer)?;
+            for import in &context.imports {
+                writeln!(writer, "import \"{import}\";")?;
+            }
+        }
+        for block in blocks {
+            writeln!(writer, "\n{block}")?;
+        }
+
+        self.field_numbers = context.numbers;
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ProtobufGeneratorOptions {
+    pub fn with_field_numbers(self, field_numbers: FieldNumbers) -> Self {
+        Self { field_numbers }
+    }
+
+    pub fn field_numbers(&self) -> &FieldNumbers {
+        &self.field_numbers
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for FieldNumbers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (message, fields) in &self.numbers {
+            let mut fields: Vec<(&String, &u32)> = fields.iter().collect();
+            fields.sort_by_key(|(_, number)| **number);
+            for (field, number) in fields {
+                writeln!(f, "{message}.{field} = {number}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FieldNumbers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut field_numbers = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line.split_once('=').and_then(|(name, number)| {
+                let (message, field) = name.trim().rsplit_once('.')?;
+                let number = number.trim().parse::<u32>().ok()?;
+                Some((message, field, number))
+            });
+            match parsed {
+                Some((message, field, number)) if !message.is_empty() && !field.is_empty() => {
+                    field_numbers.insert(message, field, number)
+                }
+                _ => {
+                    return Err(generator_error(
+                        GENERATOR_NAME,
+                        format!(
+                            "line {} of the field numbers is not `Message.field = number`",
+                            i + 1
+                        ),
+                    ))
+                }
+            }
+        }
+        Ok(field_numbers)
+    }
+}
+
+impl FieldNumbers {
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    pub fn get(&self, message: &str, field: &str) -> Option<u32> {
+        self.numbers
+            .get(message)
+            .and_then(|fields| fields.get(field))
+            .copied()
+    }
+
+    pub fn insert<S1, S2>(&mut self, message: S1, field: S2, number: u32)
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.numbers
+            .entry(message.into())
+            .or_default()
+            .insert(field.into(), number);
+    }
+
+    pub fn remove(&mut self, message: &str, field: &str) -> Option<u32> {
+        self.numbers
+            .get_mut(message)
+            .and_then(|fields| fields.remove(field))
+    }
+
+    pub fn fields(&self, message: &str) -> impl Iterator<Item = (&String, u32)> {
+        self.numbers
+            .get(message)
+            .into_iter()
+            .flat_map(|fields| fields.iter().map(|(field, number)| (field, *number)))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    module: &'a Module,
+    cache: &'a S,
+    previous: &'a FieldNumbers,
+    numbers: FieldNumbers,
+    imports: BTreeSet<String>,
+}
+
+#[derive(Debug)]
+struct Field {
+    key: String,
+    annotated: Option<u32>,
+    comment: Option<String>,
+    label: &'static str,
+    type_name: String,
+    name: String,
+}
+
+#[derive(Debug)]
+struct Numbered {
+    numbers: Vec<u32>,
+    reserved: Vec<(String, u32)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    ///
+    /// Return the message or enum for `definition`, if it has one.
+    ///
+    fn definition(&mut self, definition: &Definition) -> Result<Option<String>, Error> {
+        let name = definition.name().to_string();
+        let block = match definition {
+            Definition::Entity(entity) => {
+                let mut fields = Vec::default();
+                if let Some(body) = entity.body() {
+                    fields.push(self.field(body.identity())?);
+                    for member in body.members() {
+                        fields.push(self.field(member)?);
+                    }
+                }
+                let block = self.message(&name, fields, false)?;
+                with_comment(entity.body().and_then(description), "", block)
+            }
+            Definition::Structure(structure) => self.structure(&name, structure.body())?,
+            Definition::Event(event) => self.structure(&name, event.body())?,
+            Definition::Enum(enum_def) => {
+                let prefix = name.to_shouty_snake_case();
+                let unspecified = format!("{prefix}_UNSPECIFIED");
+                let mut unspecified_comment = None;
+                let mut values = Vec::default();
+                if let Some(body) = enum_def.body() {
+                    for variant in body.variants() {
+                        let value = Field {
+                            key: variant.name().to_string(),
+                            annotated: variant.body().map(field_number).transpose()?.flatten(),
+                            comment: variant.body().and_then(description),
+                            label: "",
+                            type_name: String::default(),
+                            name: format!(
+                                "{prefix}_{}",
+                                variant.name().as_ref().to_shouty_snake_case()
+                            ),
+                        };
+                        if value.name == unspecified {
+                            // a variant named `Unspecified` is itself the zero value.
+                            if let Some(number) = value.annotated.filter(|number| *number != 0) {
+                                return Err(generator_error(
+                                    GENERATOR_NAME,
+                                    format!(
+                                        "{number} is not a valid number for {name}.{}, it is the zero value",
+                                        value.key
+                                    ),
+                                ));
+                            }
+                            unspecified_comment = value.comment;
+                        } else {
+                            values.push(value);
+                        }
+                    }
+                }
+                let numbered = self.number(&name, &values, true)?;
+                let mut lines = reserved_lines(&numbered, |name| {
+                    format!("{prefix}_{}", name.to_shouty_snake_case())
+                });
+                if !lines.is_empty() {
+                    lines.push(String::default());
+                }
+                lines.push(with_comment(
+                    unspecified_comment,
+                    "  ",
+                    format!("{unspecified} = 0;"),
+                ));
+                for (value, number) in values.iter().zip(numbered.numbers) {
+                    lines.push(with_comment(
+                        value.comment.clone(),
+                        "  ",
+                        format!("{} = {number};", value.name),
+                    ));
+                }
+                with_comment(
+                    enum_def.body().and_then(description),
+                    "",
+                    format!("enum {name} {{\n{}\n}}", lines.join("\n")),
+                )
+            }
+            Definition::Union(union_def) => {
+                let mut fields = Vec::default();
+                if let Some(body) = union_def.body() {
+                    for variant in body.variants() {
+                        fields.push(Field {
+                            key: variant.name().to_string(),
+                            annotated: variant.body().map(field_number).transpose()?.flatten(),
+                            comment: variant.body().and_then(description),
+                            label: "",
+                            type_name: self.reference_type(variant.name_reference()),
+                            name: variant.name().as_ref().to_snake_case(),
+                        });
+                    }
+                }
+                let block = self.message(&name, fields, true)?;
+                with_comment(union_def.body().and_then(description), "", block)
+            }
+            Definition::Datatype(_)
+            | Definition::Property(_)
+            | Definition::Rdf(_)
+            | Definition::TypeClass(_) => return Ok(None),
+        };
+        Ok(Some(block))
+    }
+
+    fn structure(&mut self, name: &str, body: Option<&StructureBody>) -> Result<String, Error> {
+        let mut fields = Vec::default();
+        if let Some(body) = body {
+            for member in body.members() {
+                fields.push(self.field(member)?);
+            }
+        }
+        let block = self.message(name, fields, false)?;
+        Ok(with_comment(body.and_then(description), "", block))
+    }
+
+    ///
+    /// Return the message `name` with `fields`, which are enclosed in a `oneof` for a union.
+    ///
+    fn message(&mut self, name: &str, fields: Vec<Field>, is_union: bool) -> Result<String, Error> {
+        let numbered = self.number(name, &fields, false)?;
+        let mut lines = reserved_lines(&numbered, |name| name.to_snake_case());
+        let indent = if is_union { "    " } else { "  " };
+        let fields: Vec<String> = fields
+            .into_iter()
+            .zip(numbered.numbers)
+            .map(|(field, number)| {
+                with_comment(
+                    field.comment,
+                    indent,
+                    format!(
+                        "{}{} {} = {number};",
+                        field.label, field.type_name, field.name
+                    ),
+                )
+            })
+            .collect();
+        if !lines.is_empty() && !fields.is_empty() {
+            lines.push(String::default());
+        }
+        if is_union {
+            if !fields.is_empty() {
+                lines.push(format!("  oneof value {{\n{}\n  }}", fields.join("\n")));
+            }
+        } else {
+            lines.extend(fields);
+        }
+        Ok(if lines.is_empty() {
+            format!("message {name} {{}}")
+        } else {
+            format!("message {name} {{\n{}\n}}", lines.join("\n"))
+        })
+    }
+
+    fn field(&mut self, member: &Member) -> Result<Field, Error> {
+        let name = member.name();
+        let mut field = Field {
+            key: name.to_string(),
+            annotated: None,
+            comment: None,
+            label: "",
+            type_name: "string".to_string(),
+            name: name.as_ref().to_snake_case(),
+        };
+        if let Some(member_def) = member_def(member, self.module, self.cache) {
+            let cardinality = member_def.target_cardinality();
+            field.type_name = self.type_name(member_def.target_type());
+            field.label = if matches!(member_def.target_type(), TypeReference::MappingType(_)) {
+                ""
+            } else if cardinality.max_occurs() != Some(1) {
+                "repeated "
+            } else if cardinality.min_occurs() == 0 {
+                "optional "
+            } else {
+                ""
+            };
+            if let Some(body) = member_def.body() {
+                field.annotated = field_number(body)?;
+                field.comment = description(body);
+            }
+        }
+        Ok(field)
+    }
+
+    fn type_name(&mut self, type_ref: &TypeReference) -> String {
+        match type_ref {
+            TypeReference::Unknown => "string".to_string(),
+            TypeReference::Type(reference) => self.reference_type(reference),
+            TypeReference::MappingType(mapping) => {
+                let key = self.type_name(mapping.domain());
+                let key = if MAP_KEY_TYPES.contains(&key.as_str()) {
+                    key
+                } else {
+                    "string".to_string()
+                };
+                let value = match resolve_type(mapping.range(), self.module, self.cache) {
+                    ResolvedType::Mapping(_) => {
+                        self.imports.insert(STRUCT_IMPORT.to_string());
+                        "google.protobuf.Struct".to_string()
+                    }
+                    _ => self.type_name(mapping.range()),
+                };
+                format!("map<{key}, {value}>")
+            }
+        }
+    }
+
+    fn reference_type(&mut self, reference: &IdentifierReference) -> String {
+        match resolve_reference(reference, self.module, self.cache) {
+            ResolvedType::Simple(simple_type) => self.scalar(simple_type),
+            ResolvedType::Definition(name, Definition::Datatype(datatype)) => {
+                let datatype_module =
+                    module_named(name.module(), self.module, self.cache).unwrap_or(self.module);
+                let resolved = resolve_datatype(datatype, datatype_module, self.cache);
+                self.scalar(resolved.simple_type)
+            }
+            ResolvedType::Definition(
+                _,
+                Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_),
+            )
+            | ResolvedType::Unknown
+            | ResolvedType::Mapping(_) => "string".to_string(),
+            ResolvedType::Definition(name, _) | ResolvedType::Unresolved(name) => {
+                self.message_type(&name)
+            }
+        }
+    }
+
+    fn message_type(&mut self, name: &QualifiedIdentifier) -> String {
+        if name.module() == self.module.name() {
+            name.member().to_string()
+        } else {
+            self.imports.insert(format!("{}.proto", name.module()));
+            name.to_string().replace(':', ".")
+        }
+    }
+
+    fn scalar(&mut self, simple_type: SimpleType) -> String {
+        match simple_type {
+            SimpleType::Boolean => "bool",
+            SimpleType::String
+            | SimpleType::Decimal
+            | SimpleType::Date
+            | SimpleType::Time
+            | SimpleType::Iri => "string",
+            SimpleType::Integer | SimpleType::Long => "int64",
+            SimpleType::Int | SimpleType::Short | SimpleType::Byte => "int32",
+            SimpleType::Unsigned | SimpleType::UnsignedLong => "uint64",
+            SimpleType::UnsignedInt | SimpleType::UnsignedShort | SimpleType::UnsignedByte => {
+                "uint32"
+            }
+            SimpleType::Double => "double",
+            SimpleType::Float => "float",
+            SimpleType::Binary => "bytes",
+            SimpleType::DateTime => {
+                self.imports.insert(TIMESTAMP_IMPORT.to_string());
+                "google.protobuf.Timestamp"
+            }
+            SimpleType::Duration => {
+                self.imports.insert(DURATION_IMPORT.to_string());
+                "google.protobuf.Duration"
+            }
+        }
+        .to_string()
+    }
+
+    ///
+    /// Number the fields of the message, or values of the enum, `name`. Each field keeps an
+    /// annotated or previously assigned number and new fields are given the lowest numbers not
+    /// used; previous numbers of fields no longer present are returned as reserved.
+    ///
+    fn number(&mut self, name: &str, fields: &[Field], is_enum: bool) -> Result<Numbered, Error> {
+        let message = format!("{}.{name}", self.module.name());
+        let previous: BTreeMap<&String, u32> = self.previous.fields(&message).collect();
+
+        let mut numbers: Vec<Option<u32>> = Vec::default();
+        let mut used: BTreeMap<u32, &str> = Default::default();
+        for field in fields {
+            if let Some(number) = field.annotated {
+                if number == 0
+                    || (!is_enum
+                        && (number > MAX_FIELD_NUMBER
+                            || (FIRST_RESERVED_NUMBER..=LAST_RESERVED_NUMBER).contains(&number)))
+                {
+                    return Err(generator_error(
+                        GENERATOR_NAME,
+                        format!("{number} is not a valid number for {name}.{}", field.key),
+                    ));
+                }
+            }
+            let number = field
+                .annotated
+                .or_else(|| previous.get(&field.key).copied());
+            if let Some(number) = number {
+                if let Some(other) = used.insert(number, &field.key) {
+                    return Err(generator_error(
+                        GENERATOR_NAME,
+                        format!(
+                            "{name}.{other} and {name}.{} are both numbered {number}",
+                            field.key
+                        ),
+                    ));
+                }
+            }
+            numbers.push(number);
+        }
+
+        let mut reserved = Vec::default();
+        for (key, number) in &previous {
+            if !fields.iter().any(|field| &&field.key == key) {
+                if used.contains_key(number) {
+                    // an annotation has explicitly reused the number of a removed field.
+                    self.numbers.remove(&message, key);
+                } else {
+                    reserved.push(((*key).clone(), *number));
+                }
+            }
+        }
+
+        let taken: BTreeSet<u32> = used
+            .keys()
+            .chain(reserved.iter().map(|(_, number)| number))
+            .copied()
+            .collect();
+        let mut next = 1;
+        let numbers: Vec<u32> = numbers
+            .into_iter()
+            .map(|number| {
+                number.unwrap_or_else(|| {
+                    while taken.contains(&next)
+                        || (!is_enum
+                            && (FIRST_RESERVED_NUMBER..=LAST_RESERVED_NUMBER).contains(&next))
+                    {
+                        next += 1;
+                    }
+                    next += 1;
+                    next - 1
+                })
+            })
+            .collect();
+
+        for (field, number) in fields.iter().zip(&numbers) {
+            self.numbers.insert(&message, &field.key, *number);
+        }
+        reserved.sort_by_key(|(_, number)| *number);
+        Ok(Numbered { numbers, reserved })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the value of any `sdml:fieldNumber` annotation on `annotated`.
+///
+fn field_number<T: HasAnnotations>(annotated: &T) -> Result<Option<u32>, Error> {
+    let Some(property) = annotated.annotation_properties().find(|property| {
+        matches!(
+            property.name_reference(),
+            IdentifierReference::QualifiedIdentifier(name)
+                if name.module().as_ref() == stdlib::sdml::MODULE_NAME
+                    && name.member().as_ref() == stdlib::sdml::FIELD_NUMBER
+        )
+    }) else {
+        return Ok(None);
+    };
+    let number = match property.value() {
+        Value::Simple(SimpleValue::Unsigned(v)) => u32::try_from(*v).ok(),
+        Value::Simple(SimpleValue::Integer(v)) => u32::try_from(*v).ok(),
+        _ => None,
+    };
+    match number {
+        Some(number) => Ok(Some(number)),
+        None => Err(generator_error(
+            GENERATOR_NAME,
+            format!(
+                "the value of {} must be a positive integer, not {}",
+                property.name_reference(),
+                property.value()
+            ),
+        )),
+    }
+}
+
+fn reserved_lines<F>(numbered: &Numbered, field_name: F) -> Vec<String>
+where
+    F: Fn(&str) -> String,
+{
+    if numbered.reserved.is_empty() {
+        Vec::default()
+    } else {
+        vec![
+            format!(
+                "  reserved {};",
+                numbered
+                    .reserved
+                    .iter()
+                    .map(|(_, number)| number.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            format!(
+                "  reserved {};",
+                numbered
+                    .reserved
+                    .iter()
+                    .map(|(name, _)| format!("\"{}\"", field_name(name)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ]
+    }
+}
+
+fn with_comment(comment: Option<String>, indent: &str, item: String) -> String {
+    match comment {
+        Some(comment) => format!(
+            "{}\n{indent}{item}",
+            comment
+                .lines()
+                .map(|line| format!("{indent}// {line}").trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        None => format!("{indent}{item}"),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const GENERATOR_NAME: &str = "Protocol Buffers";
+
+const FIRST_RESERVED_NUMBER: u32 = 19_000;
+const LAST_RESERVED_NUMBER: u32 = 19_999;
+const MAX_FIELD_NUMBER: u32 = 536_870_911;
+
+const MAP_KEY_TYPES: &[&str] = &[
+    "int32", "int64", "uint32", "uint64", "sint32", "sint64", "fixed32", "fixed64", "sfixed32",
+    "sfixed64", "bool", "string",
+];
+
+const TIMESTAMP_IMPORT: &str = "google/protobuf/timestamp.proto";
+const DURATION_IMPORT: &str = "google/protobuf/duration.proto";
+const STRUCT_IMPORT: &str = "google/protobuf/struct.proto";
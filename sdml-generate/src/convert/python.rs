@@ -0,0 +1,863 @@
+/*!
+This module provides a generator that creates a Python module of types for the definitions of a
+module, either as Pydantic (v2) models or as standard library dataclasses.
+
+The definitions of a module are mapped as follows.
+
+* **entities**, **structures**, and **events** are classes with a field for each member; a member
+  with the cardinality `{0..1}` is an `Optional` field defaulting to `None`, and a member with a
+  maximum cardinality greater than one is a `list`, or a `set` if its values are unique and
+  unordered.
+* **enums** are `Enum` classes with a string value for each variant.
+* **unions** are tagged, each variant is a class, named for the union and the variant such as
+  `PayeeAddress`, with the variant name in the field `type` and its value in the field `value`;
+  the union is a type alias of these classes, for Pydantic a discriminated union on `type`. This
+  is the same representation as the Rust and TypeScript generators.
+* **datatypes** are, for Pydantic, `Annotated` type aliases of their simple base type with a
+  `Field` for the datatype's facets (`pattern`, `min_length`, `max_length`, `ge`, `gt`, `le`,
+  `lt`, `max_digits`, and `decimal_places`) and, for dataclasses, a `NewType`.
+* **properties**, **rdf** definitions, and **type classes** are not included.
+
+Field names are in snake case and, for Pydantic, any field whose name differs from the member
+name has an `alias` of the member name. The bounds of a member's cardinality are also `Field`
+constraints for Pydantic, and a `skos:definition`, or else `dc:description`, annotation is the
+docstring of a class, field, or type alias.
+
+A member that refers to an entity has the type of that entity's identity, rather than the entity
+itself, and a mapping type is a `dict`. Types defined in other modules are imported from the
+sibling module `.{module}`.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::python::{PythonGenerator, PythonGeneratorOptions, PythonStyle};
+
+fn print_dataclasses(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = PythonGenerator::default();
+    let options = PythonGeneratorOptions::default().with_style(PythonStyle::Dataclass);
+    let source = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{source}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, facet_name, member_def, module_named, resolve_datatype, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::Generator;
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use sdml_core::error::Error;
+use sdml_core::model::annotations::AnnotationProperty;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::members::{Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for a Python module of types for a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct PythonGenerator {}
+
+///
+/// The kind of Python classes generated for entities, structures, and events.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PythonStyle {
+    /// Pydantic (v2) models, with datatype facets as `Field` constraints.
+    #[default]
+    Pydantic,
+    /// Standard library dataclasses.
+    Dataclass,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PythonGeneratorOptions {
+    style: PythonStyle,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl PythonGeneratorOptions {
+    pub fn with_style(self, style: PythonStyle) -> Self {
+        Self { style }
+    }
+
+    pub fn style(&self) -> PythonStyle {
+        self.style
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for PythonGenerator {
+    type Options = PythonGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let context = Context {
+            module,
+            cache,
+            imports: Default::default(),
+        };
+        let items: Vec<Item> = module
+            .body()
+            .definitions()
+            .filter_map(|definition| context.item(definition))
+            .collect();
+
+        let mut imports = context.imports.into_inner();
+        let rendered: Vec<String> = items
+            .iter()
+            .map(|item| item.render(options.style, &mut imports))
+            .collect();
+
+        match description(module.body()) {
+            Some(documentation) => {
+                writeln!(writer, "\"\"\"")?;
+                writeln!(writer, "Types for the SDML module `{}`.", module.name())?;
+                writeln!(writer)?;
+                writeln!(writer, "{}", docstring_text(&documentation))?;
+                writeln!(writer, "\"\"\"")?;
+            }
+            None => writeln!(
+                writer,
+                "\"\"\"Types for the SDML module `{}`.\"\"\"",
+                module.name()
+            )?,
+        }
+        writeln!(writer, "\nfrom __future__ import annotations")?;
+
+        // Standard library, then third-party, then sibling module imports, as groups.
+        let groups: [Vec<(&String, &BTreeSet<String>)>; 3] = [
+            imports
+                .iter()
+                .filter(|(from, _)| !from.starts_with('.') && *from != PYDANTIC)
+                .collect(),
+            imports
+                .iter()
+                .filter(|(from, _)| *from == PYDANTIC)
+                .collect(),
+            imports
+                .iter()
+                .filter(|(from, _)| from.starts_with('.'))
+                .collect(),
+        ];
+        for group in groups.iter().filter(|group| !group.is_empty()) {
+            writeln!(writer)?;
+            for (from, names) in group {
+                let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                writeln!(writer, "from {from} import {}", names.join(", "))?;
+            }
+        }
+        for item in rendered {
+            writeln!(writer, "\n\n{item}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// The names imported from each Python module.
+type Imports = BTreeMap<String, BTreeSet<String>>;
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    module: &'a Module,
+    cache: &'a S,
+    imports: RefCell<Imports>,
+}
+
+#[derive(Clone, Debug)]
+enum PyType {
+    Any,
+    Scalar(&'static str),
+    Named { name: String, hashable: bool },
+    Optional(Box<PyType>),
+    List(Box<PyType>),
+    Set(Box<PyType>),
+    Dict(Box<PyType>, Box<PyType>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FieldDefault {
+    Required,
+    None,
+    List,
+    Set,
+}
+
+#[derive(Debug)]
+struct Field {
+    name: String,
+    documentation: Option<String>,
+    py_type: PyType,
+    default: FieldDefault,
+    min_length: Option<u32>,
+    max_length: Option<u32>,
+}
+
+#[derive(Debug)]
+enum ItemKind {
+    Class(Vec<Field>),
+    Enum(Vec<(String, Option<String>)>),
+    Union(Vec<(String, Option<String>, PyType)>),
+    Datatype {
+        base: PyType,
+        constraints: Vec<(&'static str, String)>,
+    },
+}
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    documentation: Option<String>,
+    kind: ItemKind,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    fn item(&self, definition: &Definition) -> Option<Item> {
+        let name = definition.name().to_string();
+        let (documentation, kind) = match definition {
+            Definition::Entity(entity) => {
+                let mut fields = Vec::default();
+                if let Some(body) = entity.body() {
+                    fields.push(self.field(body.identity()));
+                    fields.extend(body.members().map(|member| self.field(member)));
+                }
+                (entity.body().and_then(description), ItemKind::Class(fields))
+            }
+            Definition::Structure(structure) => self.class(structure.body()),
+            Definition::Event(event) => self.class(event.body()),
+            Definition::Enum(enum_def) => (
+                enum_def.body().and_then(description),
+                ItemKind::Enum(
+                    enum_def
+                        .body()
+                        .map(|body| {
+                            body.variants()
+                                .map(|variant| {
+                                    (
+                                        variant.name().to_string(),
+                                        variant.body().and_then(description),
+                                    )
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ),
+            ),
+            Definition::Union(union_def) => (
+                union_def.body().and_then(description),
+                ItemKind::Union(
+                    union_def
+                        .body()
+                        .map(|body| {
+                            body.variants()
+                                .map(|variant| {
+                                    (
+                                        variant.name().to_string(),
+                                        variant.body().and_then(description),
+                                        self.py_type(
+                                            &TypeReference::Type(variant.name_reference().clone()),
+                                            self.module,
+                                        ),
+                                    )
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ),
+            ),
+            Definition::Datatype(datatype) => {
+                let resolved = resolve_datatype(datatype, self.module, self.cache);
+                (
+                    datatype.body().and_then(description),
+                    ItemKind::Datatype {
+                        base: self.scalar(resolved.simple_type),
+                        constraints: facet_constraints(resolved.simple_type, &resolved.facets),
+                    },
+                )
+            }
+            Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => return None,
+        };
+        Some(Item {
+            name,
+            documentation,
+            kind,
+        })
+    }
+
+    fn class(&self, body: Option<&StructureBody>) -> (Option<String>, ItemKind) {
+        (
+            body.and_then(description),
+            ItemKind::Class(
+                body.map(|body| body.members().map(|member| self.field(member)).collect())
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+
+    fn field(&self, member: &Member) -> Field {
+        let mut field = Field {
+            name: member.name().to_string(),
+            documentation: None,
+            py_type: PyType::Any,
+            default: FieldDefault::Required,
+            min_length: None,
+            max_length: None,
+        };
+        if let Some(member_def) = member_def(member, self.module, self.cache) {
+            let cardinality = member_def.target_cardinality();
+            let min = cardinality.min_occurs();
+            let py_type = self.py_type(member_def.target_type(), self.module);
+            if cardinality.max_occurs() == Some(1) {
+                if min == 0 {
+                    field.py_type = PyType::Optional(Box::new(py_type));
+                    field.default = FieldDefault::None;
+                } else {
+                    field.py_type = py_type;
+                }
+            } else {
+                let set = cardinality.is_unique() == Some(true)
+                    && cardinality.is_ordered() != Some(true)
+                    && py_type.is_hashable();
+                field.py_type = if set {
+                    PyType::Set(Box::new(py_type))
+                } else {
+                    PyType::List(Box::new(py_type))
+                };
+                if min == 0 {
+                    field.default = if set {
+                        FieldDefault::Set
+                    } else {
+                        FieldDefault::List
+                    };
+                } else {
+                    field.min_length = Some(min);
+                }
+                field.max_length = cardinality.max_occurs();
+            }
+            field.documentation = member_def.body().and_then(description);
+        }
+        field
+    }
+
+    ///
+    /// Return the Python type for `type_ref`, which is used in `module`.
+    ///
+    fn py_type(&self, type_ref: &TypeReference, module: &Module) -> PyType {
+        match resolve_type(type_ref, module, self.cache) {
+            ResolvedType::Unknown => PyType::Any,
+            ResolvedType::Simple(simple_type) => self.scalar(simple_type),
+            ResolvedType::Mapping(mapping) => PyType::Dict(
+                Box::new(self.py_type(mapping.domain(), module)),
+                Box::new(self.py_type(mapping.range(), module)),
+            ),
+            ResolvedType::Definition(name, definition) => match definition {
+                Definition::Entity(entity) => {
+                    let module = module_named(name.module(), module, self.cache).unwrap_or(module);
+                    entity
+                        .body()
+                        .and_then(|body| member_def(body.identity(), module, self.cache))
+                        .map(|identity| self.py_type(identity.target_type(), module))
+                        .unwrap_or(PyType::Any)
+                }
+                Definition::Datatype(_) | Definition::Enum(_) => self.named_type(&name, true),
+                Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => {
+                    PyType::Any
+                }
+                _ => self.named_type(&name, false),
+            },
+            ResolvedType::Unresolved(name) => self.named_type(&name, false),
+        }
+    }
+
+    fn named_type(&self, name: &QualifiedIdentifier, hashable: bool) -> PyType {
+        if name.module() != self.module.name() {
+            self.import(
+                &format!(".{}", name.module().as_ref().to_snake_case()),
+                name.member().as_ref(),
+            );
+        }
+        PyType::Named {
+            name: name.member().to_string(),
+            hashable,
+        }
+    }
+
+    fn scalar(&self, simple_type: SimpleType) -> PyType {
+        let (from, name) = match simple_type {
+            SimpleType::Boolean => (None, "bool"),
+            SimpleType::String | SimpleType::Iri => (None, "str"),
+            SimpleType::Integer
+            | SimpleType::Long
+            | SimpleType::Int
+            | SimpleType::Short
+            | SimpleType::Byte
+            | SimpleType::Unsigned
+            | SimpleType::UnsignedLong
+            | SimpleType::UnsignedInt
+            | SimpleType::UnsignedShort
+            | SimpleType::UnsignedByte => (None, "int"),
+            SimpleType::Decimal => (Some("decimal"), "Decimal"),
+            SimpleType::Double | SimpleType::Float => (None, "float"),
+            SimpleType::Date => (Some("datetime"), "date"),
+            SimpleType::DateTime => (Some("datetime"), "datetime"),
+            SimpleType::Time => (Some("datetime"), "time"),
+            SimpleType::Duration => (Some("datetime"), "timedelta"),
+            SimpleType::Binary => (None, "bytes"),
+        };
+        if let Some(from) = from {
+            self.import(from, name);
+        }
+        PyType::Scalar(name)
+    }
+
+    fn import(&self, from: &str, name: &str) {
+        self.imports
+            .borrow_mut()
+            .entry(from.to_string())
+            .or_default()
+            .insert(name.to_string());
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Item {
+    fn render(&self, style: PythonStyle, imports: &mut Imports) -> String {
+        let name = &self.name;
+        let mut lines = Vec::default();
+        match &self.kind {
+            ItemKind::Class(fields) => {
+                if style == PythonStyle::Pydantic {
+                    import(imports, PYDANTIC, "BaseModel");
+                    lines.push(format!("class {name}(BaseModel):"));
+                } else {
+                    import(imports, "dataclasses", "dataclass");
+                    lines.push("@dataclass(kw_only=True)".into());
+                    lines.push(format!("class {name}:"));
+                }
+                let header = lines.len();
+                push_docstring(&mut lines, self.documentation.as_deref(), "    ");
+                let aliased = style == PythonStyle::Pydantic
+                    && fields
+                        .iter()
+                        .any(|field| field_name(&field.name) != field.name);
+                if aliased {
+                    import(imports, PYDANTIC, "ConfigDict");
+                    if lines.len() > header {
+                        lines.push(String::default());
+                    }
+                    lines.push("    model_config = ConfigDict(populate_by_name=True)".into());
+                }
+                if !fields.is_empty() && lines.len() > header {
+                    lines.push(String::default());
+                }
+                for field in fields {
+                    lines.push(format!("    {}", field.render(style, imports)));
+                    push_docstring(&mut lines, field.documentation.as_deref(), "    ");
+                }
+                if lines.len() == header {
+                    lines.push("    pass".into());
+                }
+            }
+            ItemKind::Enum(variants) => {
+                import(imports, "enum", "Enum");
+                lines.push(format!("class {name}(str, Enum):"));
+                push_docstring(&mut lines, self.documentation.as_deref(), "    ");
+                if !variants.is_empty() && lines.len() > 1 {
+                    lines.push(String::default());
+                }
+                for (variant, documentation) in variants {
+                    if let Some(documentation) = documentation {
+                        lines.extend(
+                            documentation
+                                .lines()
+                                .map(|line| format!("    #: {line}").trim_end().to_string()),
+                        );
+                    }
+                    lines.push(format!(
+                        "    {} = {}",
+                        identifier(&variant.to_shouty_snake_case()),
+                        string_literal(variant)
+                    ));
+                }
+                if lines.len() == 1 {
+                    lines.push("    pass".into());
+                }
+            }
+            ItemKind::Union(variants) => {
+                let mut classes = Vec::default();
+                for (variant, documentation, py_type) in variants {
+                    let class_name = format!("{name}{}", variant.to_upper_camel_case());
+                    if style == PythonStyle::Pydantic {
+                        import(imports, PYDANTIC, "BaseModel");
+                        lines.push(format!("class {class_name}(BaseModel):"));
+                    } else {
+                        import(imports, "dataclasses", "dataclass");
+                        lines.push("@dataclass(kw_only=True)".into());
+                        lines.push(format!("class {class_name}:"));
+                    }
+                    push_docstring(&mut lines, documentation.as_deref(), "    ");
+                    if documentation.is_some() {
+                        lines.push(String::default());
+                    }
+                    import(imports, "typing", "Literal");
+                    let variant = string_literal(variant);
+                    lines.push(format!("    type: Literal[{variant}] = {variant}"));
+                    lines.push(format!("    value: {}", py_type.render(false, imports)));
+                    lines.extend([String::default(), String::default()]);
+                    classes.push(class_name);
+                }
+                match classes.as_slice() {
+                    [] => {
+                        import(imports, "typing", "NoReturn");
+                        lines.push(format!("{name} = NoReturn"));
+                    }
+                    [class_name] => lines.push(format!("{name} = {class_name}")),
+                    _ if style == PythonStyle::Pydantic => {
+                        import(imports, "typing", "Annotated");
+                        import(imports, "typing", "Union");
+                        import(imports, PYDANTIC, "Field");
+                        lines.push(format!(
+                            "{name} = Annotated[Union[{}], Field(discriminator=\"type\")]",
+                            classes.join(", ")
+                        ));
+                    }
+                    _ => {
+                        import(imports, "typing", "Union");
+                        lines.push(format!("{name} = Union[{}]", classes.join(", ")));
+                    }
+                }
+                push_docstring(&mut lines, self.documentation.as_deref(), "");
+            }
+            ItemKind::Datatype { base, constraints } => {
+                let base = base.render(false, imports);
+                if style == PythonStyle::Dataclass {
+                    import(imports, "typing", "NewType");
+                    lines.push(format!(
+                        "{name} = NewType({}, {base})",
+                        string_literal(name)
+                    ));
+                } else if constraints.is_empty() {
+                    lines.push(format!("{name} = {base}"));
+                } else {
+                    import(imports, "typing", "Annotated");
+                    import(imports, PYDANTIC, "Field");
+                    let constraints: Vec<String> = constraints
+                        .iter()
+                        .map(|(name, value)| format!("{name}={value}"))
+                        .collect();
+                    lines.push(format!(
+                        "{name} = Annotated[{base}, Field({})]",
+                        constraints.join(", ")
+                    ));
+                }
+                push_docstring(&mut lines, self.documentation.as_deref(), "");
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+impl Field {
+    fn render(&self, style: PythonStyle, imports: &mut Imports) -> String {
+        let name = field_name(&self.name);
+        let declaration = format!("{name}: {}", self.py_type.render(false, imports));
+        if style == PythonStyle::Dataclass {
+            return match self.default {
+                FieldDefault::Required => declaration,
+                FieldDefault::None => format!("{declaration} = None"),
+                FieldDefault::List | FieldDefault::Set => {
+                    import(imports, "dataclasses", "field");
+                    format!(
+                        "{declaration} = field(default_factory={})",
+                        if self.default == FieldDefault::Set {
+                            "set"
+                        } else {
+                            "list"
+                        }
+                    )
+                }
+            };
+        }
+
+        let mut arguments = Vec::default();
+        match self.default {
+            FieldDefault::Required => {}
+            FieldDefault::None => arguments.push("default=None".to_string()),
+            FieldDefault::List => arguments.push("default_factory=list".into()),
+            FieldDefault::Set => arguments.push("default_factory=set".into()),
+        }
+        if name != self.name {
+            arguments.push(format!("alias={}", string_literal(&self.name)));
+        }
+        if let Some(min_length) = self.min_length {
+            arguments.push(format!("min_length={min_length}"));
+        }
+        if let Some(max_length) = self.max_length {
+            arguments.push(format!("max_length={max_length}"));
+        }
+        match arguments.as_slice() {
+            [] => declaration,
+            [argument] if argument == "default=None" => format!("{declaration} = None"),
+            _ => {
+                import(imports, PYDANTIC, "Field");
+                format!("{declaration} = Field({})", arguments.join(", "))
+            }
+        }
+    }
+}
+
+impl PyType {
+    fn is_hashable(&self) -> bool {
+        match self {
+            Self::Scalar(_) => true,
+            Self::Named { hashable, .. } => *hashable,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Render this type, `quote` named types when they are used outside of an annotation and so
+    /// may be forward references.
+    ///
+    fn render(&self, quote: bool, imports: &mut Imports) -> String {
+        match self {
+            Self::Any => {
+                import(imports, "typing", "Any");
+                "Any".into()
+            }
+            Self::Scalar(name) => name.to_string(),
+            Self::Named { name, .. } if quote => string_literal(name),
+            Self::Named { name, .. } => name.clone(),
+            Self::Optional(inner) => {
+                import(imports, "typing", "Optional");
+                format!("Optional[{}]", inner.render(quote, imports))
+            }
+            Self::List(element) => format!("list[{}]", element.render(quote, imports)),
+            Self::Set(element) => format!("set[{}]", element.render(quote, imports)),
+            Self::Dict(key, value) => format!(
+                "dict[{}, {}]",
+                key.render(quote, imports),
+                value.render(quote, imports)
+            ),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the Pydantic `Field` constraints for the datatype facets that apply to values of the
+/// `simple_type`.
+///
+fn facet_constraints(
+    simple_type: SimpleType,
+    facets: &[&AnnotationProperty],
+) -> Vec<(&'static str, String)> {
+    let is_text = matches!(
+        simple_type,
+        SimpleType::String | SimpleType::Iri | SimpleType::Binary
+    );
+    let is_number = matches!(
+        simple_type,
+        SimpleType::Integer
+            | SimpleType::Long
+            | SimpleType::Int
+            | SimpleType::Short
+            | SimpleType::Byte
+            | SimpleType::Unsigned
+            | SimpleType::UnsignedLong
+            | SimpleType::UnsignedInt
+            | SimpleType::UnsignedShort
+            | SimpleType::UnsignedByte
+            | SimpleType::Decimal
+            | SimpleType::Double
+            | SimpleType::Float
+    );
+    let mut constraints = Vec::default();
+    for facet in facets {
+        let value = facet.value();
+        let Some(name) = facet_name(facet) else {
+            continue;
+        };
+        match name {
+            stdlib::xsd::LENGTH if is_text => {
+                if let Some(length) = number_literal(value) {
+                    constraints.push(("min_length", length.clone()));
+                    constraints.push(("max_length", length));
+                }
+            }
+            stdlib::xsd::MIN_LENGTH if is_text => {
+                constraints.extend(number_literal(value).map(|v| ("min_length", v)))
+            }
+            stdlib::xsd::MAX_LENGTH if is_text => {
+                constraints.extend(number_literal(value).map(|v| ("max_length", v)))
+            }
+            stdlib::xsd::PATTERN if simple_type == SimpleType::String => {
+                // XML Schema patterns match the entire value.
+                if let Some(pattern) = value.as_string() {
+                    constraints.push((
+                        "pattern",
+                        pattern_literal(&format!("^(?:{})$", pattern.value())),
+                    ));
+                }
+            }
+            stdlib::xsd::MIN_INCLUSIVE if is_number => {
+                constraints.extend(number_literal(value).map(|v| ("ge", v)))
+            }
+            stdlib::xsd::MIN_EXCLUSIVE if is_number => {
+                constraints.extend(number_literal(value).map(|v| ("gt", v)))
+            }
+            stdlib::xsd::MAX_INCLUSIVE if is_number => {
+                constraints.extend(number_literal(value).map(|v| ("le", v)))
+            }
+            stdlib::xsd::MAX_EXCLUSIVE if is_number => {
+                constraints.extend(number_literal(value).map(|v| ("lt", v)))
+            }
+            stdlib::xsd::TOTAL_DIGITS if simple_type == SimpleType::Decimal => {
+                constraints.extend(number_literal(value).map(|v| ("max_digits", v)))
+            }
+            stdlib::xsd::FRACTION_DIGITS if simple_type == SimpleType::Decimal => {
+                constraints.extend(number_literal(value).map(|v| ("decimal_places", v)))
+            }
+            _ => {}
+        }
+    }
+    constraints
+}
+
+fn number_literal(value: &Value) -> Option<String> {
+    match value {
+        Value::Simple(
+            v @ (SimpleValue::Integer(_)
+            | SimpleValue::Unsigned(_)
+            | SimpleValue::Decimal(_)
+            | SimpleValue::Double(_)),
+        ) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn import(imports: &mut Imports, from: &str, name: &str) {
+    imports
+        .entry(from.to_string())
+        .or_default()
+        .insert(name.to_string());
+}
+
+///
+/// Return the Python field name for the member `name`.
+///
+fn field_name(name: &str) -> String {
+    identifier(&name.to_snake_case())
+}
+
+///
+/// Return `name` as a Python identifier, with a trailing underscore for keywords.
+///
+fn identifier(name: &str) -> String {
+    if PYTHON_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+///
+/// Return a regular expression as a raw string literal, where it can be written as one.
+///
+fn pattern_literal(pattern: &str) -> String {
+    if pattern.contains('"') || pattern.contains('\n') || pattern.ends_with('\\') {
+        string_literal(pattern)
+    } else {
+        format!("r\"{pattern}\"")
+    }
+}
+
+fn docstring_text(documentation: &str) -> String {
+    let text = documentation
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\"\"");
+    if text.ends_with('"') {
+        format!("{}\\\"", &text[..text.len() - 1])
+    } else {
+        text
+    }
+}
+
+fn push_docstring(lines: &mut Vec<String>, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        let text = docstring_text(documentation);
+        if text.contains('\n') {
+            lines.push(format!("{indent}\"\"\""));
+            lines.extend(
+                text.lines()
+                    .map(|line| format!("{indent}{line}").trim_end().to_string()),
+            );
+            lines.push(format!("{indent}\"\"\""));
+        } else {
+            lines.push(format!("{indent}\"\"\"{text}\"\"\""));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const PYDANTIC: &str = "pydantic";
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
@@ -53,9 +53,10 @@ pub struct RdfModelOptions {
     repr: RdfRepresentation,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum RdfRepresentation {
     NTriples,
+    #[default]
     Turtle,
 }
 
@@ -1259,12 +1260,6 @@ impl RdfModelOptions {
 
 // ------------------------------------------------------------------------------------------------
 
-impl Default for RdfRepresentation {
-    fn default() -> Self {
-        Self::Turtle
-    }
-}
-
 impl Display for RdfRepresentation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
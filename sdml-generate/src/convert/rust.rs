@@ -0,0 +1,781 @@
+/*!
+This module provides a generator that creates a Rust module containing types for the definitions
+of a module, with `serde` derives for serialization.
+
+The definitions of a module are mapped as follows.
+
+* **structures**, **entities**, and **events** are structs with a public field for each member;
+  a member whose Rust name differs from its SDML name is renamed for `serde`.
+* **enums** are fieldless enums.
+* **unions** are enums with a variant for each type variant, using the adjacently tagged `serde`
+  representation with the variant name in the field `type` and its value in the field `value`;
+  this is the same representation as the TypeScript and Python generators.
+* **datatypes** are newtype structs wrapping the Rust type of their base type.
+* **properties**, **rdf** definitions, and **type classes** are not included.
+
+A member that refers to an entity has the type of that entity's identity, rather than the entity
+itself. A member with the cardinality `{0..1}` is an `Option`, and a member with a maximum
+cardinality greater than one is a `Vec` or, if its values are unique, a `BTreeSet` if they are
+also ordered and a `HashSet` otherwise. Mapping types are `BTreeMap`s.
+
+The simple types of the standard library map to the Rust primitive types where they can, decimals
+and temporal types are `String`s by default, and binary values are `Vec<u8>`. Any library type,
+or datatype, may be mapped to a different Rust type using
+[`RustGeneratorOptions::with_type`]; a datatype with a mapped type has no newtype generated.
+
+Names that are Rust keywords are raw identifiers, except `crate`, `self`, `Self`, and `super`
+which have a `_` suffix, as does a type whose name would shadow a type used by the generated code,
+such as `Option` or `String`; a renamed member or variant keeps its SDML name for `serde`.
+
+The derives of each type include `Eq`, `Hash`, and `Ord` wherever all of the types it contains
+allow them, so that its values may be used in sets; a type directly containing itself is boxed.
+The `skos:definition`, or `dc:description`, of a definition or member is its doc comment.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::rust::{RustGenerator, RustGeneratorOptions};
+
+fn print_types(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = RustGenerator::default();
+    let options = RustGeneratorOptions::default()
+        .with_type("xsd:dateTime", "chrono::DateTime<chrono::Utc>")
+        .with_type("xsd:decimal", "rust_decimal::Decimal");
+    let source = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{source}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, member_def, module_named, qualify, resolve_type, ResolvedType, SimpleType,
+};
+use crate::Generator;
+use heck::ToSnakeCase;
+use sdml_core::error::Error;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::members::{Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::store::ModuleStore;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for a Rust module of types for a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct RustGenerator {}
+
+///
+/// Options for the Rust generator, the mapping from type names to Rust types overrides the
+/// default type for a library type, such as `xsd:dateTime`, or for a datatype such as
+/// `rentals:Vin`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RustGeneratorOptions {
+    types: BTreeMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RustGeneratorOptions {
+    ///
+    /// Map the type with the qualified name `type_name` to the Rust type, or path, `rust_type`.
+    ///
+    pub fn with_type<S1, S2>(mut self, type_name: S1, rust_type: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.types.insert(type_name.into(), rust_type.into());
+        self
+    }
+
+    pub fn rust_type(&self, type_name: &str) -> Option<&String> {
+        self.types.get(type_name)
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.types.iter()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for RustGenerator {
+    type Options = RustGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let context = Context {
+            module,
+            cache,
+            options: &options,
+        };
+        let items: Vec<Item> = module
+            .body()
+            .definitions()
+            .filter_map(|definition| context.item(definition))
+            .collect();
+        let renderer = Renderer::new(&items);
+
+        let mut imports = BTreeSet::default();
+        let rendered: Vec<String> = items
+            .iter()
+            .map(|item| renderer.item(item, &mut imports))
+            .collect();
+
+        writeln!(writer, "//! Types for the SDML module `{}`.", module.name())?;
+        if let Some(documentation) = description(module.body()) {
+            writeln!(writer, "//!")?;
+            for line in documentation.lines() {
+                writeln!(writer, "{}", format!("//! {line}").trim_end())?;
+            }
+        }
+        writeln!(writer, "\nuse serde::{{Deserialize, Serialize}};")?;
+        if !imports.is_empty() {
+            let imports: Vec<&str> = imports.into_iter().collect();
+            if imports.len() == 1 {
+                writeln!(writer, "use std::collections::{};", imports[0])?;
+            } else {
+                writeln!(writer, "use std::collections::{{{}}};", imports.join(", "))?;
+            }
+        }
+        for item in rendered {
+            writeln!(writer, "\n{item}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    module: &'a Module,
+    cache: &'a S,
+    options: &'a RustGeneratorOptions,
+}
+
+///
+/// The Rust type of a member or variant, before the choice of set types and boxing which depend
+/// on the traits of the types generated for the whole module.
+///
+#[derive(Clone, Debug)]
+enum RustType {
+    Scalar(String),
+    Local(String),
+    External(String),
+    Option(Box<RustType>),
+    Vec(Box<RustType>),
+    Set {
+        ordered: bool,
+        element: Box<RustType>,
+    },
+    Map(Box<RustType>, Box<RustType>),
+}
+
+#[derive(Debug)]
+enum Item {
+    Struct {
+        name: String,
+        documentation: Option<String>,
+        fields: Vec<Field>,
+    },
+    Enum {
+        name: String,
+        documentation: Option<String>,
+        variants: Vec<(String, Option<String>)>,
+    },
+    Union {
+        name: String,
+        documentation: Option<String>,
+        variants: Vec<(String, Option<String>, RustType)>,
+    },
+    Newtype {
+        name: String,
+        documentation: Option<String>,
+        inner: RustType,
+    },
+}
+
+#[derive(Debug)]
+struct Field {
+    name: String,
+    documentation: Option<String>,
+    rust_type: RustType,
+    may_be_empty: bool,
+}
+
+///
+/// The traits that may be derived for a type, in increasing order; `Ord` includes `Eq`, `Hash`,
+/// `PartialOrd`, and `Ord`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Traits {
+    PartialEq,
+    Eq,
+    Ord,
+}
+
+#[derive(Debug)]
+struct Renderer<'a> {
+    items: HashMap<&'a str, &'a Item>,
+    traits: HashMap<&'a str, Traits>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    fn item(&self, definition: &Definition) -> Option<Item> {
+        let name = type_identifier(definition.name().as_ref());
+        match definition {
+            Definition::Entity(entity) => {
+                let body = entity.body();
+                let mut fields = Vec::default();
+                if let Some(body) = body {
+                    fields.push(self.field(body.identity()));
+                    fields.extend(body.members().map(|member| self.field(member)));
+                }
+                Some(Item::Struct {
+                    name,
+                    documentation: body.and_then(description),
+                    fields,
+                })
+            }
+            Definition::Structure(structure) => Some(self.structure(name, structure.body())),
+            Definition::Event(event) => Some(self.structure(name, event.body())),
+            Definition::Enum(enum_def) => Some(Item::Enum {
+                name,
+                documentation: enum_def.body().and_then(description),
+                variants: enum_def
+                    .body()
+                    .map(|body| {
+                        body.variants()
+                            .map(|variant| {
+                                (
+                                    variant.name().to_string(),
+                                    variant.body().and_then(description),
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }),
+            Definition::Union(union_def) => Some(Item::Union {
+                name,
+                documentation: union_def.body().and_then(description),
+                variants: union_def
+                    .body()
+                    .map(|body| {
+                        body.variants()
+                            .map(|variant| {
+                                (
+                                    variant.name().to_string(),
+                                    variant.body().and_then(description),
+                                    self.rust_type(
+                                        &TypeReference::Type(variant.name_reference().clone()),
+                                        self.module,
+                                    ),
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }),
+            Definition::Datatype(datatype) => {
+                let qualified = definition.name().with_module(self.module.name().clone());
+                if self.options.rust_type(&qualified.to_string()).is_some() {
+                    None
+                } else {
+                    Some(Item::Newtype {
+                        name,
+                        documentation: datatype.body().and_then(description),
+                        inner: self.rust_type(
+                            &TypeReference::Type(datatype.base_type().clone()),
+                            self.module,
+                        ),
+                    })
+                }
+            }
+            Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => None,
+        }
+    }
+
+    fn structure(&self, name: String, body: Option<&StructureBody>) -> Item {
+        Item::Struct {
+            name,
+            documentation: body.and_then(description),
+            fields: body
+                .map(|body| body.members().map(|member| self.field(member)).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn field(&self, member: &Member) -> Field {
+        let mut field = Field {
+            name: member.name().to_string(),
+            documentation: None,
+            rust_type: RustType::Scalar("String".to_string()),
+            may_be_empty: false,
+        };
+        if let Some(member_def) = member_def(member, self.module, self.cache) {
+            let cardinality = member_def.target_cardinality();
+            let rust_type = Box::new(self.rust_type(member_def.target_type(), self.module));
+            field.rust_type = if cardinality.max_occurs() == Some(1) {
+                if cardinality.min_occurs() == 0 {
+                    RustType::Option(rust_type)
+                } else {
+                    *rust_type
+                }
+            } else if cardinality.is_unique() == Some(true) {
+                RustType::Set {
+                    ordered: cardinality.is_ordered() == Some(true),
+                    element: rust_type,
+                }
+            } else {
+                RustType::Vec(rust_type)
+            };
+            field.may_be_empty = cardinality.min_occurs() == 0;
+            field.documentation = member_def.body().and_then(description);
+        }
+        field
+    }
+
+    ///
+    /// Return the Rust type for `type_ref`, which is used in `module`.
+    ///
+    fn rust_type(&self, type_ref: &TypeReference, module: &Module) -> RustType {
+        if let TypeReference::Type(reference) = type_ref {
+            if let Some(rust_type) = self
+                .options
+                .rust_type(&qualify(reference, module).to_string())
+            {
+                return RustType::Scalar(rust_type.clone());
+            }
+        }
+        match resolve_type(type_ref, module, self.cache) {
+            ResolvedType::Unknown => RustType::Scalar("String".to_string()),
+            ResolvedType::Simple(simple_type) => RustType::Scalar(default_type(simple_type)),
+            ResolvedType::Mapping(mapping) => RustType::Map(
+                Box::new(self.rust_type(mapping.domain(), module)),
+                Box::new(self.rust_type(mapping.range(), module)),
+            ),
+            ResolvedType::Definition(name, definition) => match definition {
+                Definition::Entity(entity) => {
+                    let module = module_named(name.module(), module, self.cache).unwrap_or(module);
+                    entity
+                        .body()
+                        .and_then(|body| member_def(body.identity(), module, self.cache))
+                        .map(|identity| self.rust_type(identity.target_type(), module))
+                        .unwrap_or_else(|| RustType::Scalar("String".to_string()))
+                }
+                Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => {
+                    RustType::Scalar("String".to_string())
+                }
+                _ => self.named_type(&name),
+            },
+            ResolvedType::Unresolved(name) => self.named_type(&name),
+        }
+    }
+
+    fn named_type(&self, name: &QualifiedIdentifier) -> RustType {
+        if name.module() == self.module.name() {
+            RustType::Local(type_identifier(name.member().as_ref()))
+        } else {
+            RustType::External(format!(
+                "super::{}::{}",
+                identifier(&name.module().as_ref().to_snake_case()),
+                type_identifier(name.member().as_ref())
+            ))
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Renderer<'a> {
+    fn new(items: &'a [Item]) -> Self {
+        let items: HashMap<&str, &Item> = items.iter().map(|item| (item.name(), item)).collect();
+        let mut traits: HashMap<&str, Traits> =
+            items.keys().map(|name| (*name, Traits::Ord)).collect();
+        // the traits of each type are reduced until they are consistent with the traits of every
+        // type it contains, which terminates as they may only decrease.
+        loop {
+            let mut changed = false;
+            for (name, item) in &items {
+                let item_traits = item_traits(item, &traits);
+                if traits.insert(name, item_traits) != Some(item_traits) {
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        Self { items, traits }
+    }
+
+    fn item(&self, item: &Item, imports: &mut BTreeSet<&'static str>) -> String {
+        let mut lines: Vec<String> = Vec::default();
+        push_documentation(&mut lines, item.documentation(), "");
+        let name = item.name();
+        let traits = self.traits.get(name).copied().unwrap_or(Traits::PartialEq);
+        lines.push(derives(traits, matches!(item, Item::Enum { .. })));
+        match item {
+            Item::Struct { fields, .. } => {
+                if fields.is_empty() {
+                    lines.push(format!("pub struct {name} {{}}"));
+                } else {
+                    lines.push(format!("pub struct {name} {{"));
+                    for field in fields {
+                        push_documentation(&mut lines, field.documentation.as_deref(), "    ");
+                        let field_name = identifier(&field.name.to_snake_case());
+                        let mut attributes = Vec::default();
+                        if field_name.trim_start_matches("r#") != field.name {
+                            attributes.push(format!("rename = \"{}\"", field.name));
+                        }
+                        match &field.rust_type {
+                            RustType::Option(_) => attributes
+                                .push("default, skip_serializing_if = \"Option::is_none\"".into()),
+                            RustType::Vec(_) | RustType::Set { .. } | RustType::Map(_, _)
+                                if field.may_be_empty =>
+                            {
+                                attributes.push("default".into())
+                            }
+                            _ => {}
+                        }
+                        if !attributes.is_empty() {
+                            lines.push(format!("    #[serde({})]", attributes.join(", ")));
+                        }
+                        lines.push(format!(
+                            "    pub {field_name}: {},",
+                            self.field_type(name, &field.rust_type, imports)
+                        ));
+                    }
+                    lines.push("}".into());
+                }
+            }
+            Item::Enum { variants, .. } => {
+                lines.push(format!("pub enum {name} {{"));
+                for (variant, documentation) in variants {
+                    push_documentation(&mut lines, documentation.as_deref(), "    ");
+                    let variant_name = push_variant_rename(&mut lines, variant);
+                    lines.push(format!("    {variant_name},"));
+                }
+                lines.push("}".into());
+            }
+            Item::Union { variants, .. } => {
+                lines.push("#[serde(tag = \"type\", content = \"value\")]".into());
+                lines.push(format!("pub enum {name} {{"));
+                for (variant, documentation, rust_type) in variants {
+                    push_documentation(&mut lines, documentation.as_deref(), "    ");
+                    let variant_name = push_variant_rename(&mut lines, variant);
+                    lines.push(format!(
+                        "    {variant_name}({}),",
+                        self.field_type(name, rust_type, imports)
+                    ));
+                }
+                lines.push("}".into());
+            }
+            Item::Newtype { inner, .. } => {
+                lines.push(format!(
+                    "pub struct {name}(pub {});",
+                    self.field_type(name, inner, imports)
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
+    ///
+    /// Render the type of a field, or variant, of the type `owner`; a type that contains `owner`
+    /// other than through a collection is boxed.
+    ///
+    fn field_type(
+        &self,
+        owner: &str,
+        rust_type: &RustType,
+        imports: &mut BTreeSet<&'static str>,
+    ) -> String {
+        match rust_type {
+            RustType::Local(name) if self.contains(name, owner, &mut BTreeSet::default()) => {
+                format!("Box<{name}>")
+            }
+            RustType::Option(inner) => {
+                format!("Option<{}>", self.field_type(owner, inner, imports))
+            }
+            _ => self.render(rust_type, imports),
+        }
+    }
+
+    fn render(&self, rust_type: &RustType, imports: &mut BTreeSet<&'static str>) -> String {
+        match rust_type {
+            RustType::Scalar(name) | RustType::Local(name) | RustType::External(name) => {
+                name.clone()
+            }
+            RustType::Option(inner) => format!("Option<{}>", self.render(inner, imports)),
+            RustType::Vec(inner) => format!("Vec<{}>", self.render(inner, imports)),
+            RustType::Set { ordered, element } => {
+                let element_type = self.render(element, imports);
+                if type_traits(element, &self.traits) < Traits::Ord {
+                    format!("Vec<{element_type}>")
+                } else if *ordered {
+                    imports.insert("BTreeSet");
+                    format!("BTreeSet<{element_type}>")
+                } else {
+                    imports.insert("HashSet");
+                    format!("HashSet<{element_type}>")
+                }
+            }
+            RustType::Map(key, value) => {
+                imports.insert("BTreeMap");
+                format!(
+                    "BTreeMap<{}, {}>",
+                    self.render(key, imports),
+                    self.render(value, imports)
+                )
+            }
+        }
+    }
+
+    ///
+    /// Return `true` if the type `name` contains the type `target` other than through a
+    /// collection.
+    ///
+    fn contains<'b>(&'b self, name: &'b str, target: &str, seen: &mut BTreeSet<&'b str>) -> bool {
+        if name == target {
+            return true;
+        }
+        if !seen.insert(name) {
+            return false;
+        }
+        let Some(item) = self.items.get(name) else {
+            return false;
+        };
+        let contained: Vec<&RustType> = match item {
+            Item::Struct { fields, .. } => fields.iter().map(|field| &field.rust_type).collect(),
+            Item::Union { variants, .. } => variants.iter().map(|(_, _, t)| t).collect(),
+            Item::Newtype { inner, .. } => vec![inner],
+            Item::Enum { .. } => Vec::default(),
+        };
+        contained.into_iter().any(|rust_type| {
+            let mut rust_type = rust_type;
+            while let RustType::Option(inner) = rust_type {
+                rust_type = inner;
+            }
+            matches!(rust_type, RustType::Local(local) if self.contains(local, target, seen))
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Item {
+    fn name(&self) -> &str {
+        match self {
+            Self::Struct { name, .. }
+            | Self::Enum { name, .. }
+            | Self::Union { name, .. }
+            | Self::Newtype { name, .. } => name,
+        }
+    }
+
+    fn documentation(&self) -> Option<&str> {
+        match self {
+            Self::Struct { documentation, .. }
+            | Self::Enum { documentation, .. }
+            | Self::Union { documentation, .. }
+            | Self::Newtype { documentation, .. } => documentation.as_deref(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn item_traits(item: &Item, traits: &HashMap<&str, Traits>) -> Traits {
+    match item {
+        Item::Struct { fields, .. } => fields
+            .iter()
+            .map(|field| type_traits(&field.rust_type, traits))
+            .min()
+            .unwrap_or(Traits::Ord),
+        Item::Enum { .. } => Traits::Ord,
+        Item::Union { variants, .. } => variants
+            .iter()
+            .map(|(_, _, rust_type)| type_traits(rust_type, traits))
+            .min()
+            .unwrap_or(Traits::Ord),
+        Item::Newtype { inner, .. } => type_traits(inner, traits),
+    }
+}
+
+fn type_traits(rust_type: &RustType, traits: &HashMap<&str, Traits>) -> Traits {
+    match rust_type {
+        RustType::Scalar(name) if name == "f32" || name == "f64" => Traits::PartialEq,
+        RustType::Scalar(_) | RustType::External(_) => Traits::Ord,
+        RustType::Local(name) => traits.get(name.as_str()).copied().unwrap_or(Traits::Ord),
+        RustType::Option(inner) | RustType::Vec(inner) => type_traits(inner, traits),
+        RustType::Set { ordered, element } => match type_traits(element, traits) {
+            Traits::Ord if !ordered => Traits::Eq,
+            element_traits => element_traits,
+        },
+        RustType::Map(key, value) => type_traits(key, traits).min(type_traits(value, traits)),
+    }
+}
+
+fn derives(traits: Traits, is_fieldless: bool) -> String {
+    let mut derives = vec!["Clone"];
+    if is_fieldless {
+        derives.push("Copy");
+    }
+    derives.extend(["Debug", "PartialEq"]);
+    if traits >= Traits::Eq {
+        derives.push("Eq");
+    }
+    if traits == Traits::Ord {
+        derives.extend(["Hash", "PartialOrd", "Ord"]);
+    }
+    derives.extend(["Serialize", "Deserialize"]);
+    format!("#[derive({})]", derives.join(", "))
+}
+
+fn default_type(simple_type: SimpleType) -> String {
+    match simple_type {
+        SimpleType::Boolean => "bool",
+        SimpleType::Integer | SimpleType::Long => "i64",
+        SimpleType::Int => "i32",
+        SimpleType::Short => "i16",
+        SimpleType::Byte => "i8",
+        SimpleType::Unsigned | SimpleType::UnsignedLong => "u64",
+        SimpleType::UnsignedInt => "u32",
+        SimpleType::UnsignedShort => "u16",
+        SimpleType::UnsignedByte => "u8",
+        SimpleType::Double => "f64",
+        SimpleType::Float => "f32",
+        SimpleType::Binary => "Vec<u8>",
+        SimpleType::String
+        | SimpleType::Decimal
+        | SimpleType::Date
+        | SimpleType::DateTime
+        | SimpleType::Time
+        | SimpleType::Duration
+        | SimpleType::Iri => "String",
+    }
+    .to_string()
+}
+
+fn push_documentation(lines: &mut Vec<String>, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        lines.extend(
+            documentation
+                .lines()
+                .map(|line| format!("{indent}/// {line}").trim_end().to_string()),
+        );
+    }
+}
+
+///
+/// Push the `serde` rename for a variant whose Rust name differs from `variant`, returning the
+/// Rust name.
+///
+fn push_variant_rename(lines: &mut Vec<String>, variant: &str) -> String {
+    let variant_name = identifier(variant);
+    if variant_name.trim_start_matches("r#") != variant {
+        lines.push(format!("    #[serde(rename = \"{variant}\")]"));
+    }
+    variant_name
+}
+
+///
+/// Return `name` as a Rust identifier, using a raw identifier for keywords; the keywords that
+/// cannot be raw identifiers have a `_` suffix instead.
+///
+fn identifier(name: &str) -> String {
+    if matches!(name, "crate" | "self" | "super" | "Self") {
+        format!("{name}_")
+    } else if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+///
+/// Return `name` as the Rust identifier of a type; a type that would shadow one of the types
+/// used by the generated code has a `_` suffix.
+///
+fn type_identifier(name: &str) -> String {
+    if RESERVED_TYPE_NAMES.contains(&name) {
+        format!("{name}_")
+    } else {
+        identifier(name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "BTreeMap",
+    "BTreeSet",
+    "Box",
+    "Deserialize",
+    "HashMap",
+    "HashSet",
+    "Option",
+    "Serialize",
+    "String",
+    "Vec",
+    "bool",
+    "f32",
+    "f64",
+    "i16",
+    "i32",
+    "i64",
+    "i8",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+];
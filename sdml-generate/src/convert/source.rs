@@ -365,10 +365,9 @@ impl SourceGenerator {
                         writer,
                         DEFINITION_ANNOTATION_INDENT,
                     )?;
-                    if body.has_members() {
-                        writer.write_all(EOL)?;
-                    }
+                    writer.write_all(EOL)?;
                 }
+                self.write_entity_identity(body.identity(), writer)?;
                 for member in body.members() {
                     self.write_member(member, writer)?;
                 }
@@ -490,6 +489,27 @@ impl SourceGenerator {
         Ok(())
     }
 
+    fn write_entity_identity(
+        &mut self,
+        defn: &Member,
+        writer: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let indentation = self.options.indentation_str(DEFINITION_MEMBER_INDENT);
+        let initial_indentation = format!("{indentation}{} ", keyword("identity"));
+        match defn.kind() {
+            MemberKind::Reference(v) => {
+                self.write_member_reference(v, &initial_indentation, writer)
+            }
+            MemberKind::Definition(v) => self.write_member_definition_inner(
+                v,
+                &initial_indentation,
+                MEMBER_ANNOTATION_INDENT,
+                &indentation,
+                writer,
+            ),
+        }
+    }
+
     fn write_member(&mut self, defn: &Member, writer: &mut dyn Write) -> Result<(), Error> {
         let indentation = self.options.indentation_str(DEFINITION_MEMBER_INDENT);
         match defn.kind() {
@@ -564,13 +584,13 @@ impl SourceGenerator {
             format!(
                 "{}{}{}{}{}{} ",
                 braces_start(),
-                if let Some(uniqueness) = defn.uniqueness() {
-                    format!("{} ", keyword(uniqueness.to_string()))
+                if let Some(ordering) = defn.ordering() {
+                    format!("{} ", keyword(ordering.to_string()))
                 } else {
                     String::new()
                 },
-                if let Some(ordering) = defn.ordering() {
-                    format!("{} ", keyword(ordering.to_string()))
+                if let Some(uniqueness) = defn.uniqueness() {
+                    format!("{} ", keyword(uniqueness.to_string()))
                 } else {
                     String::new()
                 },
@@ -0,0 +1,404 @@
+/*!
+This module provides the generation of a PostgreSQL migration script, the DDL statements that
+change the tables for one version of a module into the tables for another.
+
+Both versions of the module are mapped to tables exactly as the [`SqlGenerator`](super::SqlGenerator)
+does, and the migration is the difference between the two sets of tables and enumerated types. It
+covers added and dropped tables and columns, changes to the type, nullability, and check
+constraints of a column, changes to primary and foreign keys, and added and removed enum
+variants. As the DDL export names each check and foreign key constraint, these can be dropped
+by name.
+
+Any step that may delete data, or fail against existing rows, is preceded by a `-- WARNING:`
+comment describing the risk; these should be reviewed before the script is run.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::convert::sql::SqlGeneratorOptions;
+use sdml_generate::convert::sql::migration::write_migration;
+
+fn print_migration(old: &Module, new: &Module, cache: &InMemoryModuleCache) {
+    let mut stdout = std::io::stdout();
+    write_migration(old, new, cache, SqlGeneratorOptions::default(), &mut stdout).unwrap();
+}
+```
+
+ */
+
+use super::{quoted, quoted_list, string_literal, Column, EnumType, Schema, SqlGeneratorOptions};
+use sdml_core::error::Error;
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
+use sdml_core::store::ModuleStore;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Write the PostgreSQL statements that migrate the tables for the module `old` to those for
+/// the module `new`, both mapped using `options`.
+///
+pub fn write_migration<W: Write>(
+    old: &Module,
+    new: &Module,
+    cache: &impl ModuleStore,
+    options: SqlGeneratorOptions,
+    writer: &mut W,
+) -> Result<(), Error> {
+    let old_schema = Schema::new(old, cache, options);
+    let new_schema = Schema::new(new, cache, options);
+    let steps = migration_steps(&old_schema, &new_schema);
+
+    if old.name() == new.name() {
+        writeln!(writer, "-- Migration: {}", new.name())?;
+    } else {
+        writeln!(writer, "-- Migration: {} to {}", old.name(), new.name())?;
+    }
+    if steps.is_empty() {
+        writeln!(writer, "-- No changes.")?;
+    }
+    for step in steps {
+        writeln!(writer)?;
+        if let Some(warning) = step.warning {
+            writeln!(writer, "-- WARNING: {warning}")?;
+        }
+        writeln!(writer, "{}", step.statement)?;
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Step {
+    warning: Option<String>,
+    statement: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the migration steps, ordered so that types and tables exist before they are used and
+/// constraints are dropped before the columns and tables they depend on.
+///
+fn migration_steps(old: &Schema, new: &Schema) -> Vec<Step> {
+    let mut steps = Vec::default();
+
+    // enumerated types that have lost a variant are recreated, with any column using them
+    // converted after the tables are changed.
+    let mut recreated: Vec<&EnumType> = Vec::default();
+    for new_enum in &new.enums {
+        match old.enum_type(&new_enum.name) {
+            None => steps.push(Step::new(new_enum.create_statement())),
+            Some(old_enum) => {
+                let removed: Vec<&String> = old_enum
+                    .variants
+                    .iter()
+                    .filter(|variant| !new_enum.variants.contains(variant))
+                    .collect();
+                if !removed.is_empty() {
+                    steps.push(Step::with_warning(
+                        format!(
+                            "removing {} from {} fails if any row still uses them.",
+                            removed
+                                .iter()
+                                .map(|variant| string_literal(variant))
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                            new_enum.name
+                        ),
+                        format!(
+                            "ALTER TYPE {} RENAME TO {};",
+                            quoted(&new_enum.name),
+                            quoted(&old_type_name(&new_enum.name))
+                        ),
+                    ));
+                    steps.push(Step::new(new_enum.create_statement()));
+                    recreated.push(new_enum);
+                } else {
+                    for (i, variant) in new_enum.variants.iter().enumerate() {
+                        if !old_enum.variants.contains(variant) {
+                            let position = match i {
+                                0 => new_enum
+                                    .variants
+                                    .get(1)
+                                    .map(|next| format!(" BEFORE {}", string_literal(next))),
+                                _ => Some(format!(
+                                    " AFTER {}",
+                                    string_literal(&new_enum.variants[i - 1])
+                                )),
+                            };
+                            steps.push(Step::new(format!(
+                                "ALTER TYPE {} ADD VALUE {}{};",
+                                quoted(&new_enum.name),
+                                string_literal(variant),
+                                position.unwrap_or_default()
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // foreign keys that are removed or changed, including all of those on dropped tables so
+    // that the tables may be dropped in any order.
+    for old_table in &old.tables {
+        let new_table = new.table(&old_table.name);
+        for foreign_key in &old_table.foreign_keys {
+            let name = foreign_key.constraint_name(&old_table.name);
+            if new_table.and_then(|new_table| new_table.foreign_key(&name)) != Some(foreign_key) {
+                steps.push(Step::new(format!(
+                    "ALTER TABLE {} DROP CONSTRAINT {};",
+                    quoted(&old_table.name),
+                    quoted(&name)
+                )));
+            }
+        }
+    }
+
+    for new_table in &new.tables {
+        match old.table(&new_table.name) {
+            None => steps.push(Step::new(new_table.create_statements())),
+            Some(old_table) => {
+                let table = &new_table.name;
+                let primary_key_changed = old_table.primary_key != new_table.primary_key;
+                if primary_key_changed && !old_table.primary_key.is_empty() {
+                    steps.push(Step::with_warning(
+                        format!(
+                            "changing the primary key of {table} fails if the new key is not unique."
+                        ),
+                        format!(
+                            "ALTER TABLE {} DROP CONSTRAINT {};",
+                            quoted(table),
+                            quoted(&old_table.primary_key_name())
+                        ),
+                    ));
+                }
+
+                for old_column in &old_table.columns {
+                    if new_table.column(&old_column.name).is_none() {
+                        steps.push(Step::with_warning(
+                            format!(
+                                "dropping the column {table}.{} permanently deletes its data.",
+                                old_column.name
+                            ),
+                            format!(
+                                "ALTER TABLE {} DROP COLUMN {};",
+                                quoted(table),
+                                quoted(&old_column.name)
+                            ),
+                        ));
+                    }
+                }
+
+                for new_column in &new_table.columns {
+                    match old_table.column(&new_column.name) {
+                        None => {
+                            let statement = format!(
+                                "ALTER TABLE {} ADD COLUMN {};",
+                                quoted(table),
+                                new_column.definition(table)
+                            );
+                            if new_column.not_null {
+                                steps.push(Step::with_warning(
+                                    format!(
+                                        "adding the required column {table}.{} fails if the table has any rows; add a default or populate it first.",
+                                        new_column.name
+                                    ),
+                                    statement,
+                                ));
+                            } else {
+                                steps.push(Step::new(statement));
+                            }
+                        }
+                        Some(old_column) => {
+                            column_steps(table, old_column, new_column, &recreated, &mut steps)
+                        }
+                    }
+                }
+
+                if primary_key_changed && !new_table.primary_key.is_empty() {
+                    steps.push(Step::new(format!(
+                        "ALTER TABLE {} ADD PRIMARY KEY ({});",
+                        quoted(table),
+                        quoted_list(&new_table.primary_key)
+                    )));
+                }
+
+                if old_table.description != new_table.description {
+                    steps.push(Step::new(
+                        new_table.comment_statement(new_table.description.as_ref()),
+                    ));
+                }
+            }
+        }
+    }
+
+    for old_table in &old.tables {
+        if new.table(&old_table.name).is_none() {
+            steps.push(Step::with_warning(
+                format!(
+                    "dropping the table {} permanently deletes its data.",
+                    old_table.name
+                ),
+                format!("DROP TABLE {};", quoted(&old_table.name)),
+            ));
+        }
+    }
+
+    for enum_type in &recreated {
+        steps.push(Step::new(format!(
+            "DROP TYPE {};",
+            quoted(&old_type_name(&enum_type.name))
+        )));
+    }
+    for old_enum in &old.enums {
+        if new.enum_type(&old_enum.name).is_none() {
+            steps.push(Step::new(format!("DROP TYPE {};", quoted(&old_enum.name))));
+        }
+    }
+
+    for new_table in &new.tables {
+        let old_table = old.table(&new_table.name);
+        for foreign_key in &new_table.foreign_keys {
+            let name = foreign_key.constraint_name(&new_table.name);
+            let statement = foreign_key.add_statement(&new_table.name);
+            match old_table {
+                None => steps.push(Step::new(statement)),
+                Some(old_table) if old_table.foreign_key(&name) != Some(foreign_key) => {
+                    steps.push(Step::with_warning(
+                        format!(
+                            "adding the foreign key {name} fails if any existing row of {} has no matching row in {}.",
+                            new_table.name, foreign_key.table
+                        ),
+                        statement,
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    steps
+}
+
+///
+/// Add the steps to change `old_column` of `table` into `new_column`. The check constraint is
+/// dropped before, and added after, any change to the column's type.
+///
+fn column_steps(
+    table: &str,
+    old_column: &Column,
+    new_column: &Column,
+    recreated: &[&EnumType],
+    steps: &mut Vec<Step>,
+) {
+    let column = &new_column.name;
+    let check_changed = old_column.check() != new_column.check();
+    if check_changed && old_column.check().is_some() {
+        steps.push(Step::new(format!(
+            "ALTER TABLE {} DROP CONSTRAINT {};",
+            quoted(table),
+            quoted(&old_column.check_name(table))
+        )));
+    }
+
+    if old_column.sql_type != new_column.sql_type {
+        steps.push(Step::with_warning(
+            format!(
+                "changing the type of {table}.{column} from {} to {} fails, or loses precision, if existing values cannot be converted.",
+                old_column.sql_type, new_column.sql_type
+            ),
+            format!(
+                "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{};",
+                quoted(table),
+                quoted(column),
+                new_column.sql_type,
+                quoted(column),
+                new_column.sql_type
+            ),
+        ));
+    } else if recreated
+        .iter()
+        .any(|enum_type| quoted(&enum_type.name) == new_column.sql_type)
+    {
+        steps.push(Step::new(format!(
+            "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::text::{};",
+            quoted(table),
+            quoted(column),
+            new_column.sql_type,
+            quoted(column),
+            new_column.sql_type
+        )));
+    }
+
+    if !old_column.not_null && new_column.not_null {
+        steps.push(Step::with_warning(
+            format!("making {table}.{column} required fails if it contains any null values."),
+            format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET NOT NULL;",
+                quoted(table),
+                quoted(column)
+            ),
+        ));
+    } else if old_column.not_null && !new_column.not_null {
+        steps.push(Step::new(format!(
+            "ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL;",
+            quoted(table),
+            quoted(column)
+        )));
+    }
+
+    if check_changed {
+        if let Some(check) = new_column.check() {
+            steps.push(Step::with_warning(
+                format!("adding the constraint on {table}.{column} fails if any existing value does not satisfy it."),
+                format!(
+                    "ALTER TABLE {} ADD CONSTRAINT {} CHECK ({check});",
+                    quoted(table),
+                    quoted(&new_column.check_name(table))
+                ),
+            ));
+        }
+    }
+
+    if old_column.description != new_column.description {
+        steps.push(Step::new(
+            new_column.comment_statement(table, new_column.description.as_ref()),
+        ));
+    }
+}
+
+fn old_type_name(name: &str) -> String {
+    format!("{name}_old")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Step {
+    fn new(statement: String) -> Self {
+        Self {
+            warning: None,
+            statement,
+        }
+    }
+
+    fn with_warning(warning: String, statement: String) -> Self {
+        Self {
+            warning: Some(warning),
+            statement,
+        }
+    }
+}
@@ -0,0 +1,940 @@
+/*!
+This module provides a generator that creates PostgreSQL data definition (DDL) statements for
+the tables that store the instance data of a module's definitions.
+
+The definitions of a module are mapped as follows.
+
+* **entities** are tables, with the columns for the `identity` member as the primary key.
+* **structures** are embedded in the table of their parent, each structure member is one or
+  more columns named with the parent member's name as a prefix.
+* **enums** are either enumerated types, created with `CREATE TYPE`, or text columns with a
+  check constraint on the variant names, see [`EnumStyle`].
+* **datatypes** are mapped to the SQL type of their simple base type, using the length and
+  digit facets where possible and adding a check constraint for any other facet.
+
+A member whose type is an entity is a foreign key, named with the member's name and the
+referenced identity column; as the referenced table may be defined later in the module, or have
+a reference back, all foreign keys are added with `ALTER TABLE` after the tables are created. A
+member with a minimum cardinality of zero is nullable, and a member with a maximum cardinality
+greater than one is stored in a join table named for the entity and member. Union and mapping
+types, and any structure nested within itself, are stored as `JSONB`.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::sql::{EnumStyle, SqlGenerator, SqlGeneratorOptions};
+
+fn print_ddl(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = SqlGenerator::default();
+    let options = SqlGeneratorOptions::default().with_enum_style(EnumStyle::CheckConstraint);
+    let ddl = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{ddl}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, facet_name, member_def, module_named, resolve_datatype, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::Generator;
+use heck::ToSnakeCase;
+use sdml_core::error::Error;
+use sdml_core::model::annotations::AnnotationProperty;
+use sdml_core::model::definitions::{Definition, EntityDef, EnumDef, HasMembers, HasVariants};
+use sdml_core::model::members::Member;
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SequenceMember, SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for the PostgreSQL DDL of a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct SqlGenerator {}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SqlGeneratorOptions {
+    enum_style: EnumStyle,
+}
+
+///
+/// The representation of enum definitions, and the columns that use them.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EnumStyle {
+    /// Each enum is an enumerated type, created with `CREATE TYPE ... AS ENUM`.
+    #[default]
+    EnumType,
+    /// Each column of an enum type is a text column with a check constraint on the variant names.
+    CheckConstraint,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SqlGeneratorOptions {
+    pub fn with_enum_style(self, enum_style: EnumStyle) -> Self {
+        Self { enum_style }
+    }
+
+    pub fn enum_style(&self) -> EnumStyle {
+        self.enum_style
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for SqlGenerator {
+    type Options = SqlGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let schema = Schema::new(module, cache, options);
+
+        writeln!(writer, "-- Module: {}", module.name())?;
+        for enum_type in &schema.enums {
+            writeln!(writer, "\n{}", enum_type.create_statement())?;
+        }
+        for table in &schema.tables {
+            writeln!(writer, "\n{}", table.create_statements())?;
+        }
+        for table in &schema.tables {
+            for foreign_key in &table.foreign_keys {
+                writeln!(writer, "\n{}", foreign_key.add_statement(&table.name))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The enumerated types and tables for a module, in the order they are created; the tables for
+/// entities are followed by the join tables for their many-valued members.
+///
+#[derive(Debug, Default)]
+struct Schema {
+    enums: Vec<EnumType>,
+    tables: Vec<Table>,
+}
+
+#[derive(Debug)]
+struct EnumType {
+    name: String,
+    variants: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    cache: &'a S,
+    options: SqlGeneratorOptions,
+    tables: Vec<Table>,
+    join_tables: Vec<Table>,
+    // the entities and structures currently being mapped, to detect recursive types.
+    stack: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct Table {
+    name: String,
+    description: Option<String>,
+    columns: Vec<Column>,
+    primary_key: Vec<String>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Column {
+    name: String,
+    sql_type: String,
+    not_null: bool,
+    checks: Vec<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ForeignKey {
+    columns: Vec<String>,
+    table: String,
+    referenced_columns: Vec<String>,
+}
+
+///
+/// The columns, and any foreign keys, for a value of a single type.
+///
+#[derive(Debug, Default)]
+struct Columns {
+    columns: Vec<Column>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Schema {
+    fn new(module: &Module, cache: &impl ModuleStore, options: SqlGeneratorOptions) -> Self {
+        let mut context = Context {
+            cache,
+            options,
+            tables: Default::default(),
+            join_tables: Default::default(),
+            stack: Default::default(),
+        };
+        let mut enums = Vec::default();
+        for definition in module.body().definitions() {
+            match definition {
+                Definition::Enum(enum_def) if options.enum_style == EnumStyle::EnumType => enums
+                    .push(EnumType {
+                        name: sql_name(enum_def.name().as_ref()),
+                        variants: enum_def
+                            .body()
+                            .map(|body| {
+                                body.variants()
+                                    .map(|variant| variant.name().to_string())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    }),
+                Definition::Entity(entity) => context.add_entity(entity, module),
+                _ => {}
+            }
+        }
+        let mut tables = context.tables;
+        tables.extend(context.join_tables);
+        Self { enums, tables }
+    }
+
+    fn enum_type(&self, name: &str) -> Option<&EnumType> {
+        self.enums.iter().find(|enum_type| enum_type.name == name)
+    }
+
+    fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|table| table.name == name)
+    }
+}
+
+impl EnumType {
+    fn create_statement(&self) -> String {
+        format!(
+            "CREATE TYPE {} AS ENUM ({});",
+            quoted(&self.name),
+            self.variants
+                .iter()
+                .map(|variant| string_literal(variant))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl Table {
+    ///
+    /// Return the `CREATE TABLE` statement for this table, followed by any comments on the
+    /// table and its columns.
+    ///
+    fn create_statements(&self) -> String {
+        let mut lines: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| column.definition(&self.name))
+            .collect();
+        if !self.primary_key.is_empty() {
+            lines.push(format!("PRIMARY KEY ({})", quoted_list(&self.primary_key)));
+        }
+
+        let mut statements = format!(
+            "CREATE TABLE {} (\n    {}\n);",
+            quoted(&self.name),
+            lines.join(",\n    ")
+        );
+        if let Some(description) = &self.description {
+            statements.push('\n');
+            statements.push_str(&self.comment_statement(Some(description)));
+        }
+        for column in &self.columns {
+            if let Some(description) = &column.description {
+                statements.push('\n');
+                statements.push_str(&column.comment_statement(&self.name, Some(description)));
+            }
+        }
+        statements
+    }
+
+    fn comment_statement(&self, description: Option<&String>) -> String {
+        format!(
+            "COMMENT ON TABLE {} IS {};",
+            quoted(&self.name),
+            description
+                .map(|description| string_literal(description))
+                .unwrap_or_else(|| "NULL".to_string())
+        )
+    }
+
+    fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    fn foreign_key(&self, name: &str) -> Option<&ForeignKey> {
+        self.foreign_keys
+            .iter()
+            .find(|foreign_key| foreign_key.constraint_name(&self.name) == name)
+    }
+
+    fn primary_key_name(&self) -> String {
+        format!("{}_pkey", self.name)
+    }
+}
+
+impl Column {
+    fn new(name: String, sql_type: &str, not_null: bool) -> Self {
+        Self {
+            name,
+            sql_type: sql_type.to_string(),
+            not_null,
+            checks: Default::default(),
+            description: None,
+        }
+    }
+
+    ///
+    /// Return the definition of this column, as used in both `CREATE TABLE` and `ADD COLUMN`.
+    ///
+    fn definition(&self, table: &str) -> String {
+        let mut definition = format!("{} {}", quoted(&self.name), self.sql_type);
+        if self.not_null {
+            definition.push_str(" NOT NULL");
+        }
+        if let Some(check) = self.check() {
+            definition.push_str(&format!(
+                " CONSTRAINT {} CHECK ({check})",
+                quoted(&self.check_name(table))
+            ));
+        }
+        definition
+    }
+
+    ///
+    /// Return the single check constraint for this column, combining each of its checks.
+    ///
+    fn check(&self) -> Option<String> {
+        if self.checks.is_empty() {
+            None
+        } else {
+            Some(self.checks.join(" AND "))
+        }
+    }
+
+    fn check_name(&self, table: &str) -> String {
+        format!("{table}_{}_check", self.name)
+    }
+
+    fn comment_statement(&self, table: &str, description: Option<&String>) -> String {
+        format!(
+            "COMMENT ON COLUMN {}.{} IS {};",
+            quoted(table),
+            quoted(&self.name),
+            description
+                .map(|description| string_literal(description))
+                .unwrap_or_else(|| "NULL".to_string())
+        )
+    }
+}
+
+impl ForeignKey {
+    fn constraint_name(&self, table: &str) -> String {
+        format!("{table}_{}_fkey", self.columns.join("_"))
+    }
+
+    fn add_statement(&self, table: &str) -> String {
+        format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({});",
+            quoted(table),
+            quoted(&self.constraint_name(table)),
+            quoted_list(&self.columns),
+            quoted(&self.table),
+            quoted_list(&self.referenced_columns)
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    fn add_entity(&mut self, entity: &EntityDef, module: &Module) {
+        let mut table = Table {
+            name: sql_name(entity.name().as_ref()),
+            description: entity.body().and_then(description),
+            ..Default::default()
+        };
+        let Some(body) = entity.body() else {
+            self.tables.push(table);
+            return;
+        };
+
+        let identity = self.member_columns(body.identity(), module, "", true);
+        table.primary_key = identity
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect();
+        table.columns.extend(identity.columns);
+        table.foreign_keys.extend(identity.foreign_keys);
+
+        for member in body.members() {
+            let is_many = member_def(member, module, self.cache)
+                .map(|member_def| member_def.target_cardinality().max_occurs() != Some(1))
+                .unwrap_or_default();
+            if is_many {
+                self.add_join_table(&table, member, module);
+            } else {
+                let columns = self.member_columns(member, module, "", false);
+                table.columns.extend(columns.columns);
+                table.foreign_keys.extend(columns.foreign_keys);
+            }
+        }
+
+        self.tables.push(table);
+    }
+
+    ///
+    /// Add a join table for the many-valued `member` of the entity stored in `owner`, with the
+    /// owner's primary key columns and the columns for a single value of the member.
+    ///
+    fn add_join_table(&mut self, owner: &Table, member: &Member, module: &Module) {
+        let member_name = sql_name(member.name().as_ref());
+        let mut table = Table {
+            name: format!("{}_{member_name}", owner.name),
+            ..Default::default()
+        };
+
+        let owner_columns: Vec<String> = owner
+            .primary_key
+            .iter()
+            .map(|column| format!("{}_{column}", owner.name))
+            .collect();
+        for (name, key) in owner_columns.iter().zip(owner.primary_key.iter()) {
+            if let Some(key) = owner.columns.iter().find(|column| &column.name == key) {
+                table.columns.push(Column {
+                    name: name.clone(),
+                    sql_type: key.sql_type.clone(),
+                    not_null: true,
+                    checks: Default::default(),
+                    description: None,
+                });
+            }
+        }
+        table.foreign_keys.push(ForeignKey {
+            columns: owner_columns.clone(),
+            table: owner.name.clone(),
+            referenced_columns: owner.primary_key.clone(),
+        });
+
+        let Some(member_def) = member_def(member, module, self.cache) else {
+            return;
+        };
+        table.description = member_def.body().and_then(description);
+        let values = self.type_columns(
+            &member_name,
+            resolve_type(member_def.target_type(), module, self.cache),
+            module,
+            true,
+        );
+        if member_def.target_cardinality().is_unique() == Some(true) {
+            table.primary_key = owner_columns;
+            table
+                .primary_key
+                .extend(values.columns.iter().map(|column| column.name.clone()));
+        }
+        table.columns.extend(values.columns);
+        table.foreign_keys.extend(values.foreign_keys);
+
+        self.join_tables.push(table);
+    }
+
+    ///
+    /// Return the columns for a single-valued `member`, prefixing each column name with
+    /// `prefix`; the columns are not null if `required`, or the member requires a value.
+    ///
+    fn member_columns(
+        &mut self,
+        member: &Member,
+        module: &Module,
+        prefix: &str,
+        required: bool,
+    ) -> Columns {
+        let name = format!("{prefix}{}", sql_name(member.name().as_ref()));
+        let Some(member_def) = member_def(member, module, self.cache) else {
+            return Columns {
+                columns: vec![Column::new(name, "TEXT", required)],
+                foreign_keys: Default::default(),
+            };
+        };
+        let cardinality = member_def.target_cardinality();
+        let mut columns = if cardinality.max_occurs() == Some(1) {
+            self.type_columns(
+                &name,
+                resolve_type(member_def.target_type(), module, self.cache),
+                module,
+                required || cardinality.min_occurs() > 0,
+            )
+        } else {
+            // a many-valued member of an embedded structure.
+            Columns {
+                columns: vec![Column::new(
+                    name,
+                    "JSONB",
+                    required || cardinality.min_occurs() > 0,
+                )],
+                foreign_keys: Default::default(),
+            }
+        };
+        if let [column] = columns.columns.as_mut_slice() {
+            column.description = member_def.body().and_then(description);
+        }
+        columns
+    }
+
+    ///
+    /// Return the columns for a value of the type `resolved`, using `name` for a single column or
+    /// as the prefix of the columns for an entity's key or an embedded structure.
+    ///
+    fn type_columns(
+        &mut self,
+        name: &str,
+        resolved: ResolvedType<'_>,
+        module: &Module,
+        not_null: bool,
+    ) -> Columns {
+        let mut columns = Columns::default();
+        match resolved {
+            ResolvedType::Simple(simple_type) => {
+                let mut column = Column::new(name.to_string(), sql_type(simple_type), not_null);
+                if simple_type.is_unsigned() {
+                    column.checks.push(format!("{} >= 0", quoted(name)));
+                }
+                columns.columns.push(column);
+            }
+            ResolvedType::Definition(type_name, Definition::Datatype(datatype)) => {
+                let datatype_module =
+                    module_named(type_name.module(), module, self.cache).unwrap_or(module);
+                let resolved = resolve_datatype(datatype, datatype_module, self.cache);
+                let mut column =
+                    Column::new(name.to_string(), sql_type(resolved.simple_type), not_null);
+                let has_minimum = resolved.facets.iter().any(|facet| {
+                    matches!(
+                        facet_name(facet),
+                        Some(stdlib::xsd::MIN_INCLUSIVE | stdlib::xsd::MIN_EXCLUSIVE)
+                    )
+                });
+                if resolved.simple_type.is_unsigned() && !has_minimum {
+                    column.checks.push(format!("{} >= 0", quoted(name)));
+                }
+                add_facets(&mut column, resolved.simple_type, &resolved.facets);
+                columns.columns.push(column);
+            }
+            ResolvedType::Definition(_, Definition::Enum(enum_def)) => {
+                let column = match self.options.enum_style {
+                    EnumStyle::EnumType => Column::new(
+                        name.to_string(),
+                        &quoted(&sql_name(enum_def.name().as_ref())),
+                        not_null,
+                    ),
+                    EnumStyle::CheckConstraint => {
+                        let mut column = Column::new(name.to_string(), "TEXT", not_null);
+                        column.checks.push(format!(
+                            "{} IN ({})",
+                            quoted(name),
+                            variant_literals(enum_def)
+                        ));
+                        column
+                    }
+                };
+                columns.columns.push(column);
+            }
+            ResolvedType::Definition(type_name, Definition::Entity(entity))
+                if !self.stack.contains(&type_name.to_string()) =>
+            {
+                let Some(body) = entity.body() else {
+                    columns
+                        .columns
+                        .push(Column::new(name.to_string(), "TEXT", not_null));
+                    return columns;
+                };
+                let entity_module =
+                    module_named(type_name.module(), module, self.cache).unwrap_or(module);
+                self.stack.push(type_name.to_string());
+                let referencing =
+                    self.member_columns(body.identity(), entity_module, &format!("{name}_"), true);
+                let referenced = self.member_columns(body.identity(), entity_module, "", true);
+                self.stack.pop();
+                columns.foreign_keys.push(ForeignKey {
+                    columns: referencing
+                        .columns
+                        .iter()
+                        .map(|column| column.name.clone())
+                        .collect(),
+                    table: sql_name(entity.name().as_ref()),
+                    referenced_columns: referenced
+                        .columns
+                        .into_iter()
+                        .map(|column| column.name)
+                        .collect(),
+                });
+                columns
+                    .columns
+                    .extend(referencing.columns.into_iter().map(|mut column| {
+                        column.not_null = not_null;
+                        column.description = None;
+                        column
+                    }));
+            }
+            ResolvedType::Definition(type_name, Definition::Structure(structure))
+                if !self.stack.contains(&type_name.to_string()) =>
+            {
+                let structure_module =
+                    module_named(type_name.module(), module, self.cache).unwrap_or(module);
+                self.stack.push(type_name.to_string());
+                if let Some(body) = structure.body() {
+                    for member in body.members() {
+                        let mut member_columns = self.member_columns(
+                            member,
+                            structure_module,
+                            &format!("{name}_"),
+                            false,
+                        );
+                        if !not_null {
+                            member_columns
+                                .columns
+                                .iter_mut()
+                                .for_each(|column| column.not_null = false);
+                        }
+                        columns.columns.extend(member_columns.columns);
+                        columns.foreign_keys.extend(member_columns.foreign_keys);
+                    }
+                }
+                self.stack.pop();
+            }
+            ResolvedType::Unknown | ResolvedType::Unresolved(_) => {
+                columns
+                    .columns
+                    .push(Column::new(name.to_string(), "TEXT", not_null));
+            }
+            _ => {
+                columns
+                    .columns
+                    .push(Column::new(name.to_string(), "JSONB", not_null));
+            }
+        }
+        columns
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn sql_type(simple_type: SimpleType) -> &'static str {
+    match simple_type {
+        SimpleType::Boolean => "BOOLEAN",
+        SimpleType::String | SimpleType::Iri => "TEXT",
+        SimpleType::Integer | SimpleType::Long | SimpleType::Unsigned | SimpleType::UnsignedInt => {
+            "BIGINT"
+        }
+        SimpleType::Int | SimpleType::UnsignedShort => "INTEGER",
+        SimpleType::Short | SimpleType::Byte | SimpleType::UnsignedByte => "SMALLINT",
+        SimpleType::UnsignedLong => "NUMERIC(20)",
+        SimpleType::Decimal => "NUMERIC",
+        SimpleType::Double => "DOUBLE PRECISION",
+        SimpleType::Float => "REAL",
+        SimpleType::Date => "DATE",
+        SimpleType::DateTime => "TIMESTAMP WITH TIME ZONE",
+        SimpleType::Time => "TIME",
+        SimpleType::Duration => "INTERVAL",
+        SimpleType::Binary => "BYTEA",
+    }
+}
+
+///
+/// Apply the datatype `facets` to `column`, either by refining its type or by adding a check
+/// constraint.
+///
+fn add_facets(column: &mut Column, simple_type: SimpleType, facets: &[&AnnotationProperty]) {
+    let name = quoted(&column.name);
+    let facet_value = |facet_name_wanted: &str| {
+        facets
+            .iter()
+            .find(|facet| facet_name(facet) == Some(facet_name_wanted))
+            .map(|facet| facet.value())
+    };
+
+    if simple_type == SimpleType::String {
+        if let Some(length) = facet_value(stdlib::xsd::LENGTH).and_then(value_as_u64) {
+            column.sql_type = format!("VARCHAR({length})");
+            column
+                .checks
+                .push(format!("char_length({name}) = {length}"));
+        } else if let Some(length) = facet_value(stdlib::xsd::MAX_LENGTH).and_then(value_as_u64) {
+            column.sql_type = format!("VARCHAR({length})");
+        }
+    }
+    if simple_type == SimpleType::Decimal {
+        if let Some(precision) = facet_value(stdlib::xsd::TOTAL_DIGITS).and_then(value_as_u64) {
+            match facet_value(stdlib::xsd::FRACTION_DIGITS).and_then(value_as_u64) {
+                Some(scale) => column.sql_type = format!("NUMERIC({precision}, {scale})"),
+                None => column.sql_type = format!("NUMERIC({precision})"),
+            }
+        }
+    }
+
+    for facet in facets {
+        let check = match facet_name(facet) {
+            Some(stdlib::xsd::MIN_LENGTH) => {
+                format!("char_length({name}) >= {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::MAX_LENGTH) if simple_type != SimpleType::String => {
+                format!("char_length({name}) <= {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::PATTERN) => match facet.value().as_string() {
+                // XML Schema patterns match the entire value.
+                Some(pattern) => format!(
+                    "{name} ~ {}",
+                    string_literal(&format!("^({})$", pattern.value()))
+                ),
+                None => continue,
+            },
+            Some(stdlib::xsd::MIN_INCLUSIVE) => {
+                format!("{name} >= {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::MAX_INCLUSIVE) => {
+                format!("{name} <= {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::MIN_EXCLUSIVE) => {
+                format!("{name} > {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::MAX_EXCLUSIVE) => {
+                format!("{name} < {}", value_literal(facet.value()))
+            }
+            Some(stdlib::xsd::ENUMERATION) => {
+                format!("{name} IN ({})", value_literal(facet.value()))
+            }
+            _ => continue,
+        };
+        column.checks.push(check);
+    }
+}
+
+fn value_as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Simple(SimpleValue::Unsigned(v)) => Some(*v),
+        Value::Simple(SimpleValue::Integer(v)) => u64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+///
+/// Return `value` as a SQL literal, the members of a list are returned separated by commas for
+/// use in an `IN` expression.
+///
+fn value_literal(value: &Value) -> String {
+    match value {
+        Value::Simple(v) => simple_value_literal(v),
+        Value::ValueConstructor(v) => simple_value_literal(v.value()),
+        Value::Reference(v) => string_literal(&v.to_string()),
+        Value::Mapping(v) => string_literal(&v.to_string()),
+        Value::List(v) => v
+            .iter()
+            .map(|member| match member {
+                SequenceMember::Simple(v) => simple_value_literal(v),
+                SequenceMember::ValueConstructor(v) => simple_value_literal(v.value()),
+                SequenceMember::Reference(v) => string_literal(&v.to_string()),
+                SequenceMember::Mapping(v) => string_literal(&v.to_string()),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn simple_value_literal(value: &SimpleValue) -> String {
+    match value {
+        SimpleValue::Boolean(v) => if *v { "TRUE" } else { "FALSE" }.to_string(),
+        SimpleValue::Double(v) => v.to_string(),
+        SimpleValue::Decimal(v) => v.to_string(),
+        SimpleValue::Integer(v) => v.to_string(),
+        SimpleValue::Unsigned(v) => v.to_string(),
+        SimpleValue::String(v) => string_literal(v.value()),
+        SimpleValue::IriReference(v) => string_literal(v.as_ref()),
+        SimpleValue::Binary(v) => format!(
+            "'\\x{}'",
+            v.as_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        ),
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn variant_literals(enum_def: &EnumDef) -> String {
+    enum_def
+        .body()
+        .map(|body| {
+            body.variants()
+                .map(|variant| string_literal(variant.name().as_ref()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default()
+}
+
+///
+/// Return the SQL name for an SDML identifier, in snake case.
+///
+fn sql_name(name: &str) -> String {
+    name.to_snake_case()
+}
+
+///
+/// Return `name` for use in a statement, quoted if it is a reserved word.
+///
+fn quoted(name: &str) -> String {
+    if RESERVED_WORDS.contains(&name.to_uppercase().as_str()) {
+        format!("\"{name}\"")
+    } else {
+        name.to_string()
+    }
+}
+
+fn quoted_list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| quoted(name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The PostgreSQL reserved key words, which cannot be used as unquoted table or column names.
+///
+const RESERVED_WORDS: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INITIALLY",
+    "INTERSECT",
+    "INTO",
+    "LATERAL",
+    "LEADING",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "SELECT",
+    "SESSION_USER",
+    "SOME",
+    "SYMMETRIC",
+    "SYSTEM_USER",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod migration;
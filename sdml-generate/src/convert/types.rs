@@ -0,0 +1,310 @@
+/*!
+Resolution of the types used by members and datatypes, shared by the generators that map a
+module into the type system of another schema or programming language.
+
+A type reference is resolved either to a [`SimpleType`], for the builtin `sdml` types and the
+XML Schema datatypes, or to the user definition it names, which may be in the current module or
+in an imported one. Datatype definitions may be resolved, through any chain of user datatypes,
+to their simple base type and the facet annotations that restrict it.
+
+*/
+
+use sdml_core::model::annotations::{AnnotationProperty, HasAnnotations};
+use sdml_core::model::definitions::{DatatypeDef, Definition};
+use sdml_core::model::identifiers::{Identifier, IdentifierReference, QualifiedIdentifier};
+use sdml_core::model::members::{MappingType, Member, MemberDef, MemberKind, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::{HasBody, HasName, HasNameReference, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The simple types of the standard library that generators map into their own scalar types.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum SimpleType {
+    Boolean,
+    String,
+    Integer,
+    Long,
+    Int,
+    Short,
+    Byte,
+    Unsigned,
+    UnsignedLong,
+    UnsignedInt,
+    UnsignedShort,
+    UnsignedByte,
+    Decimal,
+    Double,
+    Float,
+    Date,
+    DateTime,
+    Time,
+    Duration,
+    Iri,
+    Binary,
+}
+
+///
+/// The result of resolving a type reference.
+///
+#[derive(Clone, Debug)]
+pub(crate) enum ResolvedType<'a> {
+    Unknown,
+    Simple(SimpleType),
+    Definition(QualifiedIdentifier, &'a Definition),
+    Mapping(&'a MappingType),
+    Unresolved(QualifiedIdentifier),
+}
+
+///
+/// A datatype definition resolved to its simple base type, with the facets that restrict it
+/// from the datatype and each of its user-defined base datatypes; where a facet is repeated the
+/// one nearest to the datatype is kept.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct ResolvedDatatype<'a> {
+    pub(crate) simple_type: SimpleType,
+    /// The name of the library type the datatype is based on, if it could be resolved.
+    pub(crate) base_type: Option<QualifiedIdentifier>,
+    pub(crate) facets: Vec<&'a AnnotationProperty>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the qualified form of `reference`; an unqualified name refers either to a definition
+/// in `module` or to a member imported by it.
+///
+pub(crate) fn qualify(reference: &IdentifierReference, module: &Module) -> QualifiedIdentifier {
+    match reference {
+        IdentifierReference::QualifiedIdentifier(name) => name.clone(),
+        IdentifierReference::Identifier(name) => {
+            if module.resolve_local(name).is_none() {
+                if let Some(imported) = module
+                    .body()
+                    .imported_types()
+                    .into_iter()
+                    .find(|imported| imported.member() == name)
+                {
+                    return imported.clone();
+                }
+            }
+            name.with_module(module.name().clone())
+        }
+    }
+}
+
+///
+/// Return the module named `name`, which may be `module` itself.
+///
+pub(crate) fn module_named<'a>(
+    name: &Identifier,
+    module: &'a Module,
+    cache: &'a impl ModuleStore,
+) -> Option<&'a Module> {
+    if name == module.name() {
+        Some(module)
+    } else {
+        cache.get(name)
+    }
+}
+
+pub(crate) fn resolve_type<'a>(
+    type_ref: &'a TypeReference,
+    module: &'a Module,
+    cache: &'a impl ModuleStore,
+) -> ResolvedType<'a> {
+    match type_ref {
+        TypeReference::Unknown => ResolvedType::Unknown,
+        TypeReference::Type(reference) => resolve_reference(reference, module, cache),
+        TypeReference::MappingType(mapping) => ResolvedType::Mapping(mapping),
+    }
+}
+
+pub(crate) fn resolve_reference<'a>(
+    reference: &IdentifierReference,
+    module: &'a Module,
+    cache: &'a impl ModuleStore,
+) -> ResolvedType<'a> {
+    let name = qualify(reference, module);
+    if cache.is_library_module(name.module()) {
+        // library types that are not simple types, such as `rdf:langString`, are treated as
+        // strings.
+        ResolvedType::Simple(SimpleType::from_name(&name).unwrap_or(SimpleType::String))
+    } else if let Some(definition) = module_named(name.module(), module, cache)
+        .and_then(|module| module.resolve_local(name.member()))
+    {
+        ResolvedType::Definition(name, definition)
+    } else {
+        ResolvedType::Unresolved(name)
+    }
+}
+
+///
+/// Resolve `datatype`, defined in `module`, to its simple base type and restricting facets.
+///
+pub(crate) fn resolve_datatype<'a>(
+    datatype: &'a DatatypeDef,
+    module: &'a Module,
+    cache: &'a impl ModuleStore,
+) -> ResolvedDatatype<'a> {
+    let mut facets: Vec<&'a AnnotationProperty> = Default::default();
+    let mut current = (datatype, module);
+    // a chain of datatypes longer than this is assumed to be a cycle.
+    for _ in 0..32 {
+        let (datatype, module) = current;
+        if let Some(body) = datatype.body() {
+            for facet in body
+                .annotation_properties()
+                .filter(|property| property.is_datatype_facet())
+            {
+                if !facets
+                    .iter()
+                    .any(|existing| existing.name_reference() == facet.name_reference())
+                {
+                    facets.push(facet);
+                }
+            }
+        }
+        match resolve_reference(datatype.base_type(), module, cache) {
+            ResolvedType::Simple(simple_type) => {
+                return ResolvedDatatype {
+                    simple_type,
+                    base_type: Some(qualify(datatype.base_type(), module)),
+                    facets,
+                }
+            }
+            ResolvedType::Definition(name, Definition::Datatype(base)) => {
+                match module_named(name.module(), module, cache) {
+                    Some(base_module) => current = (base, base_module),
+                    None => break,
+                }
+            }
+            _ => break,
+        }
+    }
+    ResolvedDatatype {
+        simple_type: SimpleType::String,
+        base_type: None,
+        facets,
+    }
+}
+
+///
+/// Return the member definition for `member`, following a property reference to the member
+/// definition of the property.
+///
+pub(crate) fn member_def<'a>(
+    member: &'a Member,
+    module: &'a Module,
+    cache: &'a impl ModuleStore,
+) -> Option<&'a MemberDef> {
+    match member.kind() {
+        MemberKind::Definition(member_def) => Some(member_def),
+        MemberKind::Reference(reference) => match resolve_reference(reference, module, cache) {
+            ResolvedType::Definition(_, Definition::Property(property)) => {
+                Some(property.member_def())
+            }
+            _ => None,
+        },
+    }
+}
+
+///
+/// Return the name of the XML Schema facet, such as `pattern` or `minInclusive`, that `facet`
+/// sets, or `None` if it is not a datatype facet.
+///
+pub(crate) fn facet_name(facet: &AnnotationProperty) -> Option<&str> {
+    match facet.name_reference() {
+        IdentifierReference::QualifiedIdentifier(name) if facet.is_datatype_facet() => {
+            Some(name.member().as_ref())
+        }
+        _ => None,
+    }
+}
+
+///
+/// Return the first non-empty description of an annotated element, taken from `skos:definition`
+/// or else `dc:description`.
+///
+pub(crate) fn description<T: HasAnnotations>(annotated: &T) -> Option<String> {
+    annotated
+        .definitions()
+        .chain(annotated.descriptions())
+        .map(|description| description.value().trim().to_string())
+        .find(|description| !description.is_empty())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SimpleType {
+    ///
+    /// Return the simple type named `name`, from either the `sdml` or `xsd` modules. Any XML
+    /// Schema datatype without a corresponding simple type is treated as a string.
+    ///
+    pub(crate) fn from_name(name: &QualifiedIdentifier) -> Option<Self> {
+        let member = name.member().as_ref();
+        if name.module().as_ref() == stdlib::sdml::MODULE_NAME {
+            match member {
+                stdlib::sdml::BINARY => Some(Self::Binary),
+                stdlib::sdml::BOOLEAN => Some(Self::Boolean),
+                stdlib::sdml::DECIMAL => Some(Self::Decimal),
+                stdlib::sdml::DOUBLE => Some(Self::Double),
+                stdlib::sdml::INTEGER => Some(Self::Integer),
+                stdlib::sdml::IRI => Some(Self::Iri),
+                stdlib::sdml::LANGUAGE | stdlib::sdml::STRING => Some(Self::String),
+                stdlib::sdml::UNSIGNED => Some(Self::Unsigned),
+                _ => None,
+            }
+        } else if name.module().as_ref() == stdlib::xsd::MODULE_NAME {
+            Some(match member {
+                stdlib::xsd::ANY_URI => Self::Iri,
+                stdlib::xsd::BASE64_BINARY | stdlib::xsd::HEX_BINARY => Self::Binary,
+                stdlib::xsd::BOOLEAN => Self::Boolean,
+                stdlib::xsd::DATE => Self::Date,
+                stdlib::xsd::DATETIME => Self::DateTime,
+                stdlib::xsd::DECIMAL => Self::Decimal,
+                stdlib::xsd::DOUBLE => Self::Double,
+                stdlib::xsd::DURATION => Self::Duration,
+                stdlib::xsd::FLOAT => Self::Float,
+                stdlib::xsd::TIME => Self::Time,
+                stdlib::xsd::INTEGER
+                | stdlib::xsd::NONPOSITIVE_INTEGER
+                | stdlib::xsd::NEGATIVE_INTEGER => Self::Integer,
+                stdlib::xsd::LONG => Self::Long,
+                stdlib::xsd::INT => Self::Int,
+                stdlib::xsd::SHORT => Self::Short,
+                stdlib::xsd::BYTE => Self::Byte,
+                stdlib::xsd::NONNEGATIVE_INTEGER | stdlib::xsd::POSITIVE_INTEGER => Self::Unsigned,
+                stdlib::xsd::UNSIGNED_LONG => Self::UnsignedLong,
+                stdlib::xsd::UNSIGNED_INT => Self::UnsignedInt,
+                stdlib::xsd::UNSIGNED_SHORT => Self::UnsignedShort,
+                stdlib::xsd::UNSIGNED_BYTE => Self::UnsignedByte,
+                _ => Self::String,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn is_unsigned(&self) -> bool {
+        matches!(
+            self,
+            Self::Unsigned
+                | Self::UnsignedLong
+                | Self::UnsignedInt
+                | Self::UnsignedShort
+                | Self::UnsignedByte
+        )
+    }
+}
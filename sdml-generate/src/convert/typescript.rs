@@ -0,0 +1,735 @@
+/*!
+This module provides a generator that creates a TypeScript module of types for the definitions of
+a module and, optionally, runtime type guards for them.
+
+The definitions of a module are mapped as follows.
+
+* **entities**, **structures**, and **events** are interfaces with a property for each member;
+  a member with the cardinality `{0..1}` is an optional property and a member with a maximum
+  cardinality greater than one is an array.
+* **enums** are unions of string literal types, one for each variant.
+* **unions** are discriminated unions of objects with the name of the variant in the property
+  `type` and its value in the property `value`; this is the same representation as the Rust and
+  Python generators.
+* **datatypes** are branded type aliases of their simple base type, such as
+  `string & { readonly __brand: "Vin" }`, so that a value is only of the datatype once it has
+  been checked or explicitly cast.
+* **properties**, **rdf** definitions, and **type classes** are not included.
+
+A member that refers to an entity has the type of that entity's identity, rather than the entity
+itself, and a mapping type is a `Record`. Types defined in other modules are imported from the
+module file `./{module}`.
+
+With type guards enabled each type also has a function `is{Type}(value: unknown)` that checks a
+value is of the type; the guard for a datatype checks the `xsd:pattern`, `xsd:length`,
+`xsd:minLength`, `xsd:maxLength`, and bounds facets of the datatype, and the guard for an
+interface checks the cardinality of each property, including that the values of a unique member
+are distinct.
+
+# Example
+
+```rust
+use sdml_core::model::modules::Module;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_generate::Generator;
+use sdml_generate::convert::typescript::{TypeScriptGenerator, TypeScriptGeneratorOptions};
+
+fn print_types(module: &Module, cache: &InMemoryModuleCache) {
+    let mut generator = TypeScriptGenerator::default();
+    let options = TypeScriptGeneratorOptions::default().type_guards(true);
+    let source = generator
+        .generate_to_string(module, cache, options, None)
+        .unwrap();
+    println!("{source}");
+}
+```
+
+ */
+
+use crate::convert::types::{
+    description, facet_name, member_def, module_named, resolve_datatype, resolve_type,
+    ResolvedType, SimpleType,
+};
+use crate::Generator;
+use sdml_core::error::Error;
+use sdml_core::model::annotations::AnnotationProperty;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants, StructureBody};
+use sdml_core::model::identifiers::QualifiedIdentifier;
+use sdml_core::model::members::{Member, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::{SimpleValue, Value};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::stdlib;
+use sdml_core::store::ModuleStore;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generator for a TypeScript module of types for a module's definitions.
+///
+#[derive(Debug, Default)]
+pub struct TypeScriptGenerator {}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TypeScriptGeneratorOptions {
+    type_guards: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TypeScriptGeneratorOptions {
+    ///
+    /// Also generate a runtime type guard for each type.
+    ///
+    pub fn type_guards(self, type_guards: bool) -> Self {
+        Self { type_guards }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Generator for TypeScriptGenerator {
+    type Options = TypeScriptGeneratorOptions;
+
+    fn generate_with_options<W>(
+        &mut self,
+        module: &Module,
+        cache: &impl ModuleStore,
+        options: Self::Options,
+        _: Option<PathBuf>,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        W: Write + Sized,
+    {
+        let context = Context {
+            module,
+            cache,
+            imports: Default::default(),
+        };
+        let mut blocks: Vec<String> = Vec::default();
+        for definition in module.body().definitions() {
+            if let Some(item) = context.item(definition) {
+                blocks.push(item.declaration());
+                if options.type_guards {
+                    blocks.push(item.guard());
+                }
+            }
+        }
+
+        writeln!(writer, "/**")?;
+        writeln!(writer, " * Types for the SDML module `{}`.", module.name())?;
+        if let Some(documentation) = description(module.body()) {
+            writeln!(writer, " *")?;
+            for line in documentation.lines() {
+                writeln!(writer, "{}", format!(" * {line}").trim_end())?;
+            }
+        }
+        writeln!(writer, " */")?;
+
+        let imports = context.imports.into_inner();
+        if !imports.is_empty() {
+            writeln!(writer)?;
+            for (module_name, names) in imports {
+                if options.type_guards {
+                    let names: Vec<String> = names
+                        .iter()
+                        .map(|name| format!("type {name}, {}", guard_name(name)))
+                        .collect();
+                    writeln!(
+                        writer,
+                        "import {{ {} }} from \"./{module_name}\";",
+                        names.join(", ")
+                    )?;
+                } else {
+                    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                    writeln!(
+                        writer,
+                        "import type {{ {} }} from \"./{module_name}\";",
+                        names.join(", ")
+                    )?;
+                }
+            }
+        }
+        for block in blocks {
+            writeln!(writer, "\n{block}")?;
+        }
+        if options.type_guards {
+            writeln!(writer, "\n{GUARD_HELPERS}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Context<'a, S: ModuleStore> {
+    module: &'a Module,
+    cache: &'a S,
+    /// The names of the types imported from each other module.
+    imports: RefCell<BTreeMap<String, BTreeSet<String>>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scalar {
+    Boolean,
+    String,
+    Number,
+    Integer,
+    Unsigned,
+}
+
+#[derive(Clone, Debug)]
+enum TsType {
+    Unknown,
+    Scalar(Scalar),
+    Named(String),
+    Array {
+        element: Box<TsType>,
+        min: u32,
+        max: Option<u32>,
+        unique: bool,
+    },
+    Record(Box<TsType>),
+}
+
+#[derive(Debug)]
+struct Property {
+    name: String,
+    documentation: Option<String>,
+    ts_type: TsType,
+    optional: bool,
+}
+
+#[derive(Debug)]
+enum ItemKind {
+    Interface(Vec<Property>),
+    Enum(Vec<String>),
+    Union(Vec<(String, TsType)>),
+    Branded(Scalar, Vec<String>),
+}
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    documentation: Option<String>,
+    kind: ItemKind,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: ModuleStore> Context<'_, S> {
+    fn item(&self, definition: &Definition) -> Option<Item> {
+        let name = definition.name().to_string();
+        let (documentation, kind) = match definition {
+            Definition::Entity(entity) => {
+                let mut properties = Vec::default();
+                if let Some(body) = entity.body() {
+                    properties.push(self.property(body.identity()));
+                    properties.extend(body.members().map(|member| self.property(member)));
+                }
+                (
+                    entity.body().and_then(description),
+                    ItemKind::Interface(properties),
+                )
+            }
+            Definition::Structure(structure) => self.interface(structure.body()),
+            Definition::Event(event) => self.interface(event.body()),
+            Definition::Enum(enum_def) => (
+                enum_def.body().and_then(description),
+                ItemKind::Enum(
+                    enum_def
+                        .body()
+                        .map(|body| {
+                            body.variants()
+                                .map(|variant| variant.name().to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ),
+            ),
+            Definition::Union(union_def) => (
+                union_def.body().and_then(description),
+                ItemKind::Union(
+                    union_def
+                        .body()
+                        .map(|body| {
+                            body.variants()
+                                .map(|variant| {
+                                    (
+                                        variant.name().to_string(),
+                                        self.ts_type(
+                                            &TypeReference::Type(variant.name_reference().clone()),
+                                            self.module,
+                                        ),
+                                    )
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                ),
+            ),
+            Definition::Datatype(datatype) => {
+                let resolved = resolve_datatype(datatype, self.module, self.cache);
+                let scalar = scalar(resolved.simple_type);
+                let conditions = facet_conditions(scalar, &resolved.facets);
+                (
+                    datatype.body().and_then(description),
+                    ItemKind::Branded(scalar, conditions),
+                )
+            }
+            Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => return None,
+        };
+        Some(Item {
+            name,
+            documentation,
+            kind,
+        })
+    }
+
+    fn interface(&self, body: Option<&StructureBody>) -> (Option<String>, ItemKind) {
+        (
+            body.and_then(description),
+            ItemKind::Interface(
+                body.map(|body| body.members().map(|member| self.property(member)).collect())
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+
+    fn property(&self, member: &Member) -> Property {
+        let mut property = Property {
+            name: member.name().to_string(),
+            documentation: None,
+            ts_type: TsType::Unknown,
+            optional: false,
+        };
+        if let Some(member_def) = member_def(member, self.module, self.cache) {
+            let cardinality = member_def.target_cardinality();
+            let ts_type = self.ts_type(member_def.target_type(), self.module);
+            if cardinality.max_occurs() == Some(1) {
+                property.ts_type = ts_type;
+                property.optional = cardinality.min_occurs() == 0;
+            } else {
+                property.ts_type = TsType::Array {
+                    element: Box::new(ts_type),
+                    min: cardinality.min_occurs(),
+                    max: cardinality.max_occurs(),
+                    unique: cardinality.is_unique() == Some(true),
+                };
+            }
+            property.documentation = member_def.body().and_then(description);
+        }
+        property
+    }
+
+    ///
+    /// Return the TypeScript type for `type_ref`, which is used in `module`.
+    ///
+    fn ts_type(&self, type_ref: &TypeReference, module: &Module) -> TsType {
+        match resolve_type(type_ref, module, self.cache) {
+            ResolvedType::Unknown => TsType::Unknown,
+            ResolvedType::Simple(simple_type) => TsType::Scalar(scalar(simple_type)),
+            ResolvedType::Mapping(mapping) => {
+                TsType::Record(Box::new(self.ts_type(mapping.range(), module)))
+            }
+            ResolvedType::Definition(name, definition) => match definition {
+                Definition::Entity(entity) => {
+                    let module = module_named(name.module(), module, self.cache).unwrap_or(module);
+                    entity
+                        .body()
+                        .and_then(|body| member_def(body.identity(), module, self.cache))
+                        .map(|identity| self.ts_type(identity.target_type(), module))
+                        .unwrap_or(TsType::Unknown)
+                }
+                Definition::Property(_) | Definition::Rdf(_) | Definition::TypeClass(_) => {
+                    TsType::Unknown
+                }
+                _ => self.named_type(&name),
+            },
+            ResolvedType::Unresolved(name) => self.named_type(&name),
+        }
+    }
+
+    fn named_type(&self, name: &QualifiedIdentifier) -> TsType {
+        if name.module() != self.module.name() {
+            self.imports
+                .borrow_mut()
+                .entry(name.module().to_string())
+                .or_default()
+                .insert(name.member().to_string());
+        }
+        TsType::Named(name.member().to_string())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Item {
+    fn declaration(&self) -> String {
+        let name = &self.name;
+        let mut lines = Vec::default();
+        push_documentation(&mut lines, self.documentation.as_deref(), "");
+        match &self.kind {
+            ItemKind::Interface(properties) if properties.is_empty() => {
+                lines.push(format!("export interface {name} {{}}"));
+            }
+            ItemKind::Interface(properties) => {
+                lines.push(format!("export interface {name} {{"));
+                for property in properties {
+                    push_documentation(&mut lines, property.documentation.as_deref(), "  ");
+                    lines.push(format!(
+                        "  {}{}: {};",
+                        property.name,
+                        if property.optional { "?" } else { "" },
+                        property.ts_type.declaration()
+                    ));
+                }
+                lines.push("}".into());
+            }
+            ItemKind::Enum(variants) if variants.is_empty() => {
+                lines.push(format!("export type {name} = never;"));
+            }
+            ItemKind::Enum(variants) => {
+                lines.push(format!(
+                    "export type {name} = {};",
+                    variants
+                        .iter()
+                        .map(|variant| string_literal(variant))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                ));
+            }
+            ItemKind::Union(variants) if variants.is_empty() => {
+                lines.push(format!("export type {name} = never;"));
+            }
+            ItemKind::Union(variants) => {
+                lines.push(format!("export type {name} ="));
+                let last = variants.len() - 1;
+                for (i, (variant, ts_type)) in variants.iter().enumerate() {
+                    lines.push(format!(
+                        "  | {{ type: {}; value: {} }}{}",
+                        string_literal(variant),
+                        ts_type.declaration(),
+                        if i == last { ";" } else { "" }
+                    ));
+                }
+            }
+            ItemKind::Branded(scalar, _) => {
+                lines.push(format!(
+                    "export type {name} = {} & {{ readonly __brand: {} }};",
+                    scalar.declaration(),
+                    string_literal(name)
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn guard(&self) -> String {
+        let name = &self.name;
+        let mut lines = vec![format!(
+            "export function {}(value: unknown): value is {name} {{",
+            guard_name(name)
+        )];
+        let conditions: Vec<String> = match &self.kind {
+            ItemKind::Interface(properties) => {
+                lines.extend(object_prelude());
+                properties
+                    .iter()
+                    .map(|property| {
+                        let accessor = format!("v.{}", property.name);
+                        let condition = property.ts_type.guard(&accessor, 0);
+                        if property.optional {
+                            format!("({accessor} === undefined || {condition})")
+                        } else {
+                            condition
+                        }
+                    })
+                    .collect()
+            }
+            ItemKind::Enum(variants) => variants
+                .iter()
+                .map(|variant| format!("value === {}", string_literal(variant)))
+                .collect(),
+            ItemKind::Union(variants) => {
+                if !variants.is_empty() {
+                    lines.extend(object_prelude());
+                }
+                variants
+                    .iter()
+                    .map(|(variant, ts_type)| {
+                        format!(
+                            "(v.type === {} && {})",
+                            string_literal(variant),
+                            ts_type.guard("v.value", 0)
+                        )
+                    })
+                    .collect()
+            }
+            ItemKind::Branded(scalar, conditions) => {
+                let mut all = vec![scalar.guard("value")];
+                all.extend(conditions.iter().cloned());
+                all
+            }
+        };
+        let separator = if matches!(self.kind, ItemKind::Enum(_) | ItemKind::Union(_)) {
+            " ||"
+        } else {
+            " &&"
+        };
+        match conditions.len() {
+            0 if matches!(self.kind, ItemKind::Interface(_)) => lines.push("  return true;".into()),
+            0 => lines.push("  return false;".into()),
+            1 => lines.push(format!("  return {};", conditions[0])),
+            _ => {
+                lines.push("  return (".into());
+                let last = conditions.len() - 1;
+                for (i, condition) in conditions.iter().enumerate() {
+                    lines.push(format!(
+                        "    {condition}{}",
+                        if i == last { "" } else { separator }
+                    ));
+                }
+                lines.push("  );".into());
+            }
+        }
+        lines.push("}".into());
+        lines.join("\n")
+    }
+}
+
+impl TsType {
+    fn declaration(&self) -> String {
+        match self {
+            Self::Unknown => "unknown".into(),
+            Self::Scalar(scalar) => scalar.declaration().into(),
+            Self::Named(name) => name.clone(),
+            Self::Array { element, .. } => format!("{}[]", element.declaration()),
+            Self::Record(value) => format!("Record<string, {}>", value.declaration()),
+        }
+    }
+
+    ///
+    /// Return the condition that `accessor` is of this type, `depth` is the nesting of the
+    /// condition within element guards and is used to name their parameters.
+    ///
+    fn guard(&self, accessor: &str, depth: usize) -> String {
+        let element_name = if depth == 0 {
+            "e".to_string()
+        } else {
+            format!("e{depth}")
+        };
+        match self {
+            Self::Unknown => format!("{accessor} !== undefined"),
+            Self::Scalar(scalar) => scalar.guard(accessor),
+            Self::Named(name) => format!("{}({accessor})", guard_name(name)),
+            Self::Array {
+                element,
+                min,
+                max,
+                unique,
+            } => format!(
+                "isArrayOf({accessor}, ({element_name}) => {}, {min}, {}, {unique})",
+                element.guard(&element_name, depth + 1),
+                max.map(|max| max.to_string())
+                    .unwrap_or_else(|| "undefined".into())
+            ),
+            Self::Record(value) => format!(
+                "isRecordOf({accessor}, ({element_name}) => {})",
+                value.guard(&element_name, depth + 1)
+            ),
+        }
+    }
+}
+
+impl Scalar {
+    fn declaration(&self) -> &'static str {
+        match self {
+            Self::Boolean => "boolean",
+            Self::String => "string",
+            Self::Number | Self::Integer | Self::Unsigned => "number",
+        }
+    }
+
+    fn guard(&self, accessor: &str) -> String {
+        match self {
+            Self::Boolean => format!("typeof {accessor} === \"boolean\""),
+            Self::String => format!("typeof {accessor} === \"string\""),
+            Self::Number => format!("typeof {accessor} === \"number\""),
+            Self::Integer => format!("Number.isInteger({accessor})"),
+            Self::Unsigned => {
+                format!("Number.isInteger({accessor}) && ({accessor} as number) >= 0")
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn scalar(simple_type: SimpleType) -> Scalar {
+    match simple_type {
+        SimpleType::Boolean => Scalar::Boolean,
+        SimpleType::Decimal | SimpleType::Double | SimpleType::Float => Scalar::Number,
+        SimpleType::Integer
+        | SimpleType::Long
+        | SimpleType::Int
+        | SimpleType::Short
+        | SimpleType::Byte => Scalar::Integer,
+        SimpleType::Unsigned
+        | SimpleType::UnsignedLong
+        | SimpleType::UnsignedInt
+        | SimpleType::UnsignedShort
+        | SimpleType::UnsignedByte => Scalar::Unsigned,
+        SimpleType::String
+        | SimpleType::Date
+        | SimpleType::DateTime
+        | SimpleType::Time
+        | SimpleType::Duration
+        | SimpleType::Iri
+        | SimpleType::Binary => Scalar::String,
+    }
+}
+
+///
+/// Return the conditions, on the guarded `value`, for the datatype facets that apply to values
+/// of the `scalar` type.
+///
+fn facet_conditions(scalar: Scalar, facets: &[&AnnotationProperty]) -> Vec<String> {
+    let number = if scalar == Scalar::Number {
+        "value"
+    } else {
+        "(value as number)"
+    };
+    facets
+        .iter()
+        .filter_map(|facet| {
+            let value = facet.value();
+            match (facet_name(facet)?, scalar) {
+                (stdlib::xsd::LENGTH, Scalar::String) => {
+                    Some(format!("value.length === {}", number_literal(value)?))
+                }
+                (stdlib::xsd::MIN_LENGTH, Scalar::String) => {
+                    Some(format!("value.length >= {}", number_literal(value)?))
+                }
+                (stdlib::xsd::MAX_LENGTH, Scalar::String) => {
+                    Some(format!("value.length <= {}", number_literal(value)?))
+                }
+                (stdlib::xsd::PATTERN, Scalar::String) => {
+                    // XML Schema patterns match the entire value.
+                    let pattern = value.as_string()?.value().replace('/', "\\/");
+                    Some(format!("/^(?:{pattern})$/.test(value)"))
+                }
+                (
+                    stdlib::xsd::MIN_INCLUSIVE,
+                    Scalar::Number | Scalar::Integer | Scalar::Unsigned,
+                ) => Some(format!("{number} >= {}", number_literal(value)?)),
+                (
+                    stdlib::xsd::MIN_EXCLUSIVE,
+                    Scalar::Number | Scalar::Integer | Scalar::Unsigned,
+                ) => Some(format!("{number} > {}", number_literal(value)?)),
+                (
+                    stdlib::xsd::MAX_INCLUSIVE,
+                    Scalar::Number | Scalar::Integer | Scalar::Unsigned,
+                ) => Some(format!("{number} <= {}", number_literal(value)?)),
+                (
+                    stdlib::xsd::MAX_EXCLUSIVE,
+                    Scalar::Number | Scalar::Integer | Scalar::Unsigned,
+                ) => Some(format!("{number} < {}", number_literal(value)?)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn number_literal(value: &Value) -> Option<String> {
+    match value {
+        Value::Simple(
+            v @ (SimpleValue::Integer(_)
+            | SimpleValue::Unsigned(_)
+            | SimpleValue::Decimal(_)
+            | SimpleValue::Double(_)),
+        ) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn object_prelude() -> [String; 5] {
+    [
+        "  if (typeof value !== \"object\" || value === null) {".into(),
+        "    return false;".into(),
+        "  }".into(),
+        "  const v = value as Record<string, unknown>;".into(),
+        String::default(),
+    ]
+}
+
+fn guard_name(name: &str) -> String {
+    format!("is{name}")
+}
+
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn push_documentation(lines: &mut Vec<String>, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        let documentation = documentation.replace("*/", "*\\/");
+        if documentation.contains('\n') {
+            lines.push(format!("{indent}/**"));
+            lines.extend(
+                documentation
+                    .lines()
+                    .map(|line| format!("{indent} * {line}").trim_end().to_string()),
+            );
+            lines.push(format!("{indent} */"));
+        } else {
+            lines.push(format!("{indent}/** {documentation} */"));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const GUARD_HELPERS: &str = r#"function isArrayOf(
+  value: unknown,
+  guard: (element: unknown) => boolean,
+  min: number,
+  max: number | undefined,
+  unique: boolean,
+): boolean {
+  return (
+    Array.isArray(value) &&
+    value.length >= min &&
+    (max === undefined || value.length <= max) &&
+    (!unique || new Set(value).size === value.length) &&
+    value.every(guard)
+  );
+}
+
+function isRecordOf(value: unknown, guard: (element: unknown) => boolean): boolean {
+  return (
+    typeof value === "object" &&
+    value !== null &&
+    !Array.isArray(value) &&
+    Object.values(value).every(guard)
+  );
+}"#;
@@ -1,5 +1,5 @@
 use std::{
-    io::{self, ErrorKind, Write},
+    io::{self, Write},
     path::Path,
     process::{Command, Output},
 };
@@ -47,7 +47,7 @@ where
         if o.status.code().map(|c| c != 0).unwrap_or(true) {
             error!("command execution failed; error: {:?}", o.status);
             let mes = String::from_utf8_lossy(&o.stderr).to_string();
-            Err(std::io::Error::new(ErrorKind::Other, mes))
+            Err(std::io::Error::other(mes))
         } else {
             Ok(String::from_utf8_lossy(&o.stdout).to_string())
         }
@@ -50,6 +50,23 @@ macro_rules! generator {
     };
 }
 
+pub fn load_example(
+    test_name: &str,
+    cache: &mut sdml_core::store::InMemoryModuleCache,
+) -> sdml_core::model::identifiers::Identifier {
+    let input = std::path::PathBuf::from(format!(
+        "{}/{}/{}.sdm",
+        MANIFEST_PATH, TEST_INPUT_PATH, test_name
+    ));
+
+    println!("Reading test example from {:?}", input);
+    let mut loader = sdml_parse::load::FsModuleLoader::default();
+    match loader.load_from_file(input, cache, false) {
+        Ok(module) => module,
+        Err(e) => panic!("Load/Parse error: {}", e),
+    }
+}
+
 const UPDATE_EXAMPLES_OUTPUT_ENV: &str = "UPDATE_EXAMPLES_OUTPUT";
 
 pub fn verify_example_output(result_string: &str, expected_path: &std::path::PathBuf) {
@@ -81,13 +98,6 @@ macro_rules! test_example {
             #[cfg_attr(windows, ignore)]
             fn [< test_ $test_name:lower _  $result_ext>]() {
                 let test_name = stringify!($test_name);
-                let input = ::std::path::PathBuf::from(
-                    format!(
-                        "{}/{}/{}.sdm",
-                        $crate::common::MANIFEST_PATH,
-                        $crate::common::TEST_INPUT_PATH,
-                        test_name
-                    ));
                 let expected = std::path::PathBuf::from(
                     format!(
                         "{}/{}/{}/{}.{}",
@@ -98,14 +108,9 @@ macro_rules! test_example {
                         $result_ext
                     ));
 
-                println!("Reading test example from {:?}", input);
                 let mut cache = ::sdml_core::store::InMemoryModuleCache::default();
-                let mut loader = ::sdml_parse::load::FsModuleLoader::default();
-                let module = loader.load_from_file(input, &mut cache, false);
-                if let Err(e) = module {
-                    panic!("Load/Parse error: {}", e);
-                }
-                let module = cache.get(&module.unwrap()).unwrap();
+                let module = $crate::common::load_example(test_name, &mut cache);
+                let module = cache.get(&module).unwrap();
                 println!("Module {} loaded.", module.name());
 
                 let result_string = $transform(module, &cache);
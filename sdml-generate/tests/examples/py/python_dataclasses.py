@@ -0,0 +1,62 @@
+"""Types for the SDML module `rentals`."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from datetime import date, datetime
+from decimal import Decimal
+from enum import Enum
+from typing import Literal, NewType, Optional, Union
+
+
+Vin = NewType("Vin", str)
+
+
+Seats = NewType("Seats", int)
+
+
+Price = NewType("Price", Decimal)
+
+
+@dataclass(kw_only=True)
+class Vehicle:
+    vin: Vin
+    kind: VehicleKind
+    seats: Optional[Seats] = None
+    tags: set[str]
+    daily_rate: Price
+    registered_on: date
+    mileage: Optional[dict[str, int]] = None
+
+
+class VehicleKind(str, Enum):
+    CAR = "Car"
+    VAN = "Van"
+
+
+@dataclass(kw_only=True)
+class Address:
+    lines: list[str]
+    parent: Optional[Address] = None
+
+
+@dataclass(kw_only=True)
+class PayeeVehicle:
+    type: Literal["Vehicle"] = "Vehicle"
+    value: Vin
+
+
+@dataclass(kw_only=True)
+class PayeeAddress:
+    type: Literal["Address"] = "Address"
+    value: Address
+
+
+Payee = Union[PayeeVehicle, PayeeAddress]
+
+
+@dataclass(kw_only=True)
+class Rented:
+    vehicle: Vin
+    at: datetime
+    payees: list[Payee] = field(default_factory=list)
@@ -0,0 +1,64 @@
+"""Types for the SDML module `rentals`."""
+
+from __future__ import annotations
+
+from datetime import date, datetime
+from decimal import Decimal
+from enum import Enum
+from typing import Annotated, Literal, Optional, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+Vin = Annotated[str, Field(pattern=r"^(?:[A-HJ-NPR-Z0-9]{17})$", max_length=17)]
+"""A vehicle identification number"""
+
+
+Seats = Annotated[int, Field(ge=1, le=9)]
+
+
+Price = Annotated[Decimal, Field(max_digits=8, decimal_places=2)]
+
+
+class Vehicle(BaseModel):
+    """A rentable vehicle"""
+
+    model_config = ConfigDict(populate_by_name=True)
+
+    vin: Vin
+    kind: VehicleKind
+    seats: Optional[Seats] = None
+    tags: set[str] = Field(min_length=1, max_length=5)
+    daily_rate: Price = Field(alias="dailyRate")
+    registered_on: date = Field(alias="registeredOn")
+    mileage: Optional[dict[str, int]] = None
+
+
+class VehicleKind(str, Enum):
+    #: A passenger car
+    CAR = "Car"
+    VAN = "Van"
+
+
+class Address(BaseModel):
+    lines: list[str] = Field(min_length=1)
+    parent: Optional[Address] = None
+
+
+class PayeeVehicle(BaseModel):
+    type: Literal["Vehicle"] = "Vehicle"
+    value: Vin
+
+
+class PayeeAddress(BaseModel):
+    type: Literal["Address"] = "Address"
+    value: Address
+
+
+Payee = Annotated[Union[PayeeVehicle, PayeeAddress], Field(discriminator="type")]
+
+
+class Rented(BaseModel):
+    vehicle: Vin
+    at: datetime
+    payees: list[Payee] = Field(default_factory=list)
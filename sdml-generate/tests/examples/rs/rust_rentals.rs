@@ -0,0 +1,60 @@
+//! Types for the SDML module `rentals`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// A vehicle identification number
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Vin(pub String);
+
+/// A rentable vehicle
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vehicle {
+    pub vin: Vin,
+    pub kind: VehicleKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seats: Option<u8>,
+    pub tags: HashSet<String>,
+    #[serde(default)]
+    pub ratings: BTreeSet<i64>,
+    #[serde(rename = "registeredOn")]
+    pub registered_on: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mileage: Option<BTreeMap<String, i64>>,
+    #[serde(default)]
+    pub r#type: Vec<Address>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum VehicleKind {
+    /// A passenger car
+    Car,
+    Van,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Address {
+    pub lines: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Address>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum Payee {
+    Vehicle(Vin),
+    Address(Address),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rented {
+    pub vehicle: Vin,
+    pub at: String,
+    #[serde(default)]
+    pub points: Vec<Point>,
+}
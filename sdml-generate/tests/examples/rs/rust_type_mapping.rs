@@ -0,0 +1,20 @@
+//! Types for the SDML module `rentals`.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Vehicle {
+    pub vin: vin::Vin,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum Payee {
+    Vehicle(vin::Vin),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Rented {
+    pub vehicle: vin::Vin,
+    pub at: chrono::NaiveDateTime,
+}
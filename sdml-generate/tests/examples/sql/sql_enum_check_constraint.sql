@@ -0,0 +1,7 @@
+-- Module: rentals
+
+CREATE TABLE vehicle (
+    vin TEXT NOT NULL,
+    kind TEXT NOT NULL CONSTRAINT vehicle_kind_check CHECK (kind IN ('Car', 'Van')),
+    PRIMARY KEY (vin)
+);
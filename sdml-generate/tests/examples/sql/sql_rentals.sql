@@ -0,0 +1,40 @@
+-- Module: rentals
+
+CREATE TYPE vehicle_kind AS ENUM ('Car', 'Van');
+
+CREATE TABLE customer (
+    id BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    address_street TEXT,
+    address_city TEXT,
+    address_post_code TEXT,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE vehicle (
+    vin VARCHAR(17) NOT NULL CONSTRAINT vehicle_vin_check CHECK (char_length(vin) = 17 AND vin ~ '^([A-HJ-NPR-Z0-9]{17})$'),
+    kind vehicle_kind NOT NULL,
+    seats SMALLINT CONSTRAINT vehicle_seats_check CHECK (seats >= 1 AND seats < 10),
+    owner_id BIGINT,
+    PRIMARY KEY (vin)
+);
+COMMENT ON TABLE vehicle IS 'A rentable vehicle';
+
+CREATE TABLE vehicle_drivers (
+    vehicle_vin VARCHAR(17) NOT NULL,
+    drivers_id BIGINT NOT NULL,
+    PRIMARY KEY (vehicle_vin, drivers_id)
+);
+
+CREATE TABLE vehicle_tags (
+    vehicle_vin VARCHAR(17) NOT NULL,
+    tags TEXT NOT NULL
+);
+
+ALTER TABLE vehicle ADD CONSTRAINT vehicle_owner_id_fkey FOREIGN KEY (owner_id) REFERENCES customer (id);
+
+ALTER TABLE vehicle_drivers ADD CONSTRAINT vehicle_drivers_vehicle_vin_fkey FOREIGN KEY (vehicle_vin) REFERENCES vehicle (vin);
+
+ALTER TABLE vehicle_drivers ADD CONSTRAINT vehicle_drivers_drivers_id_fkey FOREIGN KEY (drivers_id) REFERENCES customer (id);
+
+ALTER TABLE vehicle_tags ADD CONSTRAINT vehicle_tags_vehicle_vin_fkey FOREIGN KEY (vehicle_vin) REFERENCES vehicle (vin);
@@ -0,0 +1,23 @@
+-- Module: accounts
+
+CREATE TABLE "user" (
+    id TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE "order" (
+    id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE user_order (
+    user_id TEXT NOT NULL,
+    order_id TEXT NOT NULL
+);
+
+ALTER TABLE "order" ADD CONSTRAINT order_user_id_fkey FOREIGN KEY (user_id) REFERENCES "user" (id);
+
+ALTER TABLE user_order ADD CONSTRAINT user_order_user_id_fkey FOREIGN KEY (user_id) REFERENCES "user" (id);
+
+ALTER TABLE user_order ADD CONSTRAINT user_order_order_id_fkey FOREIGN KEY (order_id) REFERENCES "order" (id);
@@ -0,0 +1,32 @@
+/**
+ * Types for the SDML module `rentals`.
+ */
+
+/** A vehicle identification number */
+export type Vin = string & { readonly __brand: "Vin" };
+
+export type Seats = number & { readonly __brand: "Seats" };
+
+/** A rentable vehicle */
+export interface Vehicle {
+  vin: Vin;
+  kind: VehicleKind;
+  seats?: Seats;
+  tags: string[];
+  mileage?: Record<string, number>;
+}
+
+export type VehicleKind = "Car" | "Van";
+
+export interface Address {
+  lines: string[];
+}
+
+export type Payee =
+  | { type: "Vehicle"; value: Vin }
+  | { type: "Address"; value: Address };
+
+export interface Rented {
+  vehicle: Vin;
+  at: string;
+}
@@ -0,0 +1,126 @@
+/**
+ * Types for the SDML module `rentals`.
+ */
+
+export type Vin = string & { readonly __brand: "Vin" };
+
+export function isVin(value: unknown): value is Vin {
+  return (
+    typeof value === "string" &&
+    /^(?:[A-HJ-NPR-Z0-9]{17})$/.test(value) &&
+    value.length <= 17
+  );
+}
+
+export type Seats = number & { readonly __brand: "Seats" };
+
+export function isSeats(value: unknown): value is Seats {
+  return (
+    Number.isInteger(value) && (value as number) >= 0 &&
+    (value as number) >= 1 &&
+    (value as number) <= 9
+  );
+}
+
+export interface Vehicle {
+  vin: Vin;
+  kind: VehicleKind;
+  seats?: Seats;
+  tags: string[];
+  mileage?: Record<string, number>;
+}
+
+export function isVehicle(value: unknown): value is Vehicle {
+  if (typeof value !== "object" || value === null) {
+    return false;
+  }
+  const v = value as Record<string, unknown>;
+
+  return (
+    isVin(v.vin) &&
+    isVehicleKind(v.kind) &&
+    (v.seats === undefined || isSeats(v.seats)) &&
+    isArrayOf(v.tags, (e) => typeof e === "string", 1, 5, true) &&
+    (v.mileage === undefined || isRecordOf(v.mileage, (e) => Number.isInteger(e)))
+  );
+}
+
+export type VehicleKind = "Car" | "Van";
+
+export function isVehicleKind(value: unknown): value is VehicleKind {
+  return (
+    value === "Car" ||
+    value === "Van"
+  );
+}
+
+export interface Address {
+  lines: string[];
+}
+
+export function isAddress(value: unknown): value is Address {
+  if (typeof value !== "object" || value === null) {
+    return false;
+  }
+  const v = value as Record<string, unknown>;
+
+  return isArrayOf(v.lines, (e) => typeof e === "string", 1, undefined, false);
+}
+
+export type Payee =
+  | { type: "Vehicle"; value: Vin }
+  | { type: "Address"; value: Address };
+
+export function isPayee(value: unknown): value is Payee {
+  if (typeof value !== "object" || value === null) {
+    return false;
+  }
+  const v = value as Record<string, unknown>;
+
+  return (
+    (v.type === "Vehicle" && isVin(v.value)) ||
+    (v.type === "Address" && isAddress(v.value))
+  );
+}
+
+export interface Rented {
+  vehicle: Vin;
+  at: string;
+}
+
+export function isRented(value: unknown): value is Rented {
+  if (typeof value !== "object" || value === null) {
+    return false;
+  }
+  const v = value as Record<string, unknown>;
+
+  return (
+    isVin(v.vehicle) &&
+    typeof v.at === "string"
+  );
+}
+
+function isArrayOf(
+  value: unknown,
+  guard: (element: unknown) => boolean,
+  min: number,
+  max: number | undefined,
+  unique: boolean,
+): boolean {
+  return (
+    Array.isArray(value) &&
+    value.length >= min &&
+    (max === undefined || value.length <= max) &&
+    (!unique || new Set(value).size === value.length) &&
+    value.every(guard)
+  );
+}
+
+function isRecordOf(value: unknown, guard: (element: unknown) => boolean): boolean {
+  return (
+    typeof value === "object" &&
+    value !== null &&
+    !Array.isArray(value) &&
+    Object.values(value).every(guard)
+  );
+}
@@ -0,0 +1,50 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::avro::{AvroGenerator, AvroGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_avro,
+    AvroGenerator::default(),
+    AvroGeneratorOptions::default()
+}
+
+generator! {
+    generate_avro_record,
+    AvroGenerator::default(),
+    AvroGeneratorOptions::default().with_record("VehicleRented")
+}
+
+test_examples! {
+    avro, "avsc", generate_avro => (
+        avro_rentals
+    )
+}
+
+test_examples! {
+    avro_record, "avsc", generate_avro_record => (
+        avro_single_record
+    )
+}
+
+#[test]
+fn test_unknown_record() {
+    let mut cache = InMemoryModuleCache::default();
+    let name = common::load_example("avro_single_record", &mut cache);
+    let module = cache.get(&name).unwrap();
+    let mut generator = AvroGenerator::default();
+    assert!(generator
+        .generate_to_string(
+            module,
+            &cache,
+            AvroGeneratorOptions::default().with_record("Vehicle"),
+            None
+        )
+        .is_err());
+}
@@ -0,0 +1,217 @@
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_generate::actions::diff::{
+    diff_modules, ChangeKind, Compatibility, ElementKind, VersionBump,
+};
+use sdml_parse::load::FsModuleLoader;
+use semver::Version;
+
+const OLD: &str = r#"module diff_example is
+  structure Customer is
+    name -> string
+    email -> string
+    rentals -> {0..} Rental
+  end
+  enum Status of
+    Open
+    Closed
+  end
+  structure Rental
+end
+"#;
+
+const NEW: &str = r#"module diff_example is
+  structure Rental
+  structure Customer is
+    name -> string
+    rentals -> {1..} Rental
+    nickname -> {0..1} string
+  end
+  enum Status of
+    Open
+    Pending
+  end
+  structure Vehicle is
+    @skos:prefLabel = "Vehicle"
+  end
+end
+"#;
+
+fn load(source: &str) -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut source.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+fn find(
+    changes: &[(ChangeKind, ElementKind, String, Compatibility)],
+    path: &str,
+) -> (ChangeKind, ElementKind, Compatibility) {
+    changes
+        .iter()
+        .find(|(_, _, p, _)| p == path)
+        .map(|(c, e, _, b)| (*c, *e, *b))
+        .unwrap_or_else(|| panic!("no change for {path} in {changes:?}"))
+}
+
+#[test]
+fn test_diff_identical_modules() {
+    let diff = diff_modules(&load(OLD), &load(OLD));
+    assert!(diff.is_empty());
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn test_diff_ignores_order_and_layout() {
+    let reordered = OLD.replace("  structure Rental\n", "").replace(
+        "module diff_example is\n",
+        "module diff_example is\n\n  structure Rental\n\n",
+    );
+    let diff = diff_modules(&load(OLD), &load(&reordered));
+    assert!(diff.is_empty(), "{diff:?}");
+}
+
+#[test]
+fn test_diff_classifies_changes() {
+    let diff = diff_modules(&load(OLD), &load(NEW));
+    let changes = diff
+        .changes()
+        .map(|c| {
+            (
+                c.change(),
+                c.element(),
+                c.path().to_string(),
+                c.compatibility(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(changes.len(), 6, "{changes:?}");
+    assert!(diff.is_breaking());
+
+    assert_eq!(
+        find(&changes, "Customer.email"),
+        (
+            ChangeKind::Removed,
+            ElementKind::Member,
+            Compatibility::Breaking
+        )
+    );
+    assert_eq!(
+        find(&changes, "Customer.rentals"),
+        (
+            ChangeKind::Changed,
+            ElementKind::Cardinality,
+            Compatibility::Breaking
+        )
+    );
+    assert_eq!(
+        find(&changes, "Customer.nickname"),
+        (
+            ChangeKind::Added,
+            ElementKind::Member,
+            Compatibility::Compatible
+        )
+    );
+    assert_eq!(
+        find(&changes, "Status.Closed"),
+        (
+            ChangeKind::Removed,
+            ElementKind::Variant,
+            Compatibility::Breaking
+        )
+    );
+    assert_eq!(
+        find(&changes, "Status.Pending"),
+        (
+            ChangeKind::Added,
+            ElementKind::Variant,
+            Compatibility::Compatible
+        )
+    );
+    assert_eq!(
+        find(&changes, "Vehicle"),
+        (
+            ChangeKind::Added,
+            ElementKind::Definition,
+            Compatibility::Compatible
+        )
+    );
+}
+
+#[test]
+fn test_diff_widening_cardinality_is_compatible() {
+    let new = OLD.replace("name -> string", "name -> {0..1} string");
+    let diff = diff_modules(&load(OLD), &load(&new));
+    assert_eq!(diff.len(), 1);
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn test_diff_json() {
+    let diff = diff_modules(&load(OLD), &load(NEW));
+    let mut buffer = Vec::new();
+    sdml_generate::actions::diff::write_diff_json(&diff, false, &mut buffer).unwrap();
+    let json = String::from_utf8(buffer).unwrap();
+    assert!(
+        json.starts_with(r#"{"old_module":"diff_example","new_module":"diff_example","changes":["#)
+    );
+    assert!(json.contains(r#""path":"Status.Closed","compatibility":"breaking""#));
+}
+
+#[test]
+fn test_diff_required_bump() {
+    assert_eq!(
+        diff_modules(&load(OLD), &load(OLD)).required_bump(),
+        VersionBump::None
+    );
+    assert_eq!(
+        diff_modules(&load(OLD), &load(NEW)).required_bump(),
+        VersionBump::Major
+    );
+    let added = OLD.replace(
+        "  structure Rental\n",
+        "  structure Rental\n  structure Vehicle\n",
+    );
+    assert_eq!(
+        diff_modules(&load(OLD), &load(&added)).required_bump(),
+        VersionBump::Minor
+    );
+}
+
+#[test]
+fn test_version_bump_between_and_apply() {
+    let v = |s: &str| Version::parse(s).unwrap();
+    assert_eq!(
+        VersionBump::between(&v("1.2.3"), &v("2.0.0")),
+        VersionBump::Major
+    );
+    assert_eq!(
+        VersionBump::between(&v("1.2.3"), &v("1.3.0")),
+        VersionBump::Minor
+    );
+    assert_eq!(
+        VersionBump::between(&v("1.2.3"), &v("1.2.4")),
+        VersionBump::Patch
+    );
+    assert_eq!(
+        VersionBump::between(&v("1.2.3"), &v("1.2.3")),
+        VersionBump::None
+    );
+    assert_eq!(
+        VersionBump::between(&v("0.2.3"), &v("0.3.0")),
+        VersionBump::Major
+    );
+    assert_eq!(
+        VersionBump::between(&v("0.2.3"), &v("0.2.4")),
+        VersionBump::Minor
+    );
+
+    assert_eq!(VersionBump::Major.apply(&v("1.2.3")), v("2.0.0"));
+    assert_eq!(VersionBump::Minor.apply(&v("1.2.3")), v("1.3.0"));
+    assert_eq!(VersionBump::Patch.apply(&v("1.2.3")), v("1.2.4"));
+    assert_eq!(VersionBump::Major.apply(&v("0.2.3")), v("0.3.0"));
+    assert_eq!(VersionBump::Minor.apply(&v("0.2.3")), v("0.2.4"));
+}
@@ -0,0 +1,201 @@
+use sdml_core::error::Error;
+use sdml_core::model::definitions::{Definition, HasMembers};
+use sdml_core::model::identifiers::{Identifier, IdentifierReference};
+use sdml_core::model::members::{MemberKind, TypeReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::walk::visit::{walk_module, Visitor};
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_generate::actions::flatten::flatten_module;
+use sdml_parse::load::FsModuleLoader;
+use std::str::FromStr;
+
+const FLEET: &str = r#"module fleet <https://example.com/fleet#> is
+  import [ xsd skos ]
+  datatype vin <- xsd:string
+  structure Vehicle is
+    @skos:definition = "a vehicle"
+    vin -> vin
+    kind -> VehicleKind
+  end
+  enum VehicleKind of
+    Car
+    Truck
+  end
+  structure Unused
+end
+"#;
+
+const RENTALS: &str = r#"module rentals <https://example.com/rentals#> is
+  import [ fleet fleet:VehicleKind ]
+  structure Vehicle is
+    inner -> fleet:Vehicle
+  end
+  entity Rental is
+    identity id -> string
+    car -> fleet:Vehicle
+    kind -> VehicleKind
+  end
+end
+"#;
+
+fn load() -> (Module, InMemoryModuleCache) {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    loader
+        .load_from_reader(&mut FLEET.as_bytes(), &mut cache, false)
+        .unwrap();
+    let name = loader
+        .load_from_reader(&mut RENTALS.as_bytes(), &mut cache, false)
+        .unwrap();
+    (cache.get(&name).unwrap().clone(), cache)
+}
+
+fn member_types(definition: &Definition) -> Vec<String> {
+    let members: Vec<_> = match definition {
+        Definition::Entity(defn) => defn.body().unwrap().members().collect(),
+        Definition::Structure(defn) => defn.body().unwrap().members().collect(),
+        _ => panic!("unexpected definition {definition:?}"),
+    };
+    members
+        .into_iter()
+        .map(|member| match member.kind() {
+            MemberKind::Definition(def) => match def.target_type() {
+                TypeReference::Type(reference) => reference.to_string(),
+                other => panic!("unexpected type {other:?}"),
+            },
+            MemberKind::Reference(reference) => reference.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_flatten_inlines_and_renames() {
+    let (module, cache) = load();
+    let flattened = flatten_module(&module, &cache).unwrap();
+
+    assert_eq!(flattened.name(), module.name());
+    assert_eq!(flattened.base_uri(), module.base_uri());
+
+    let names: Vec<String> = flattened
+        .body()
+        .definitions()
+        .map(|definition| definition.name().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["Vehicle", "Rental", "FleetVehicle", "VehicleKind", "vin"]
+    );
+
+    let imports: Vec<String> = flattened
+        .body()
+        .imported_modules()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect();
+    assert_eq!(imports.len(), 2);
+    assert!(imports.contains(&"skos".to_string()));
+    assert!(imports.contains(&"xsd".to_string()));
+
+    let find = |name: &str| {
+        flattened
+            .resolve_local(&Identifier::from_str(name).unwrap())
+            .unwrap()
+    };
+    assert_eq!(member_types(find("Vehicle")), vec!["FleetVehicle"]);
+    assert_eq!(
+        member_types(find("Rental")),
+        vec!["FleetVehicle", "VehicleKind"]
+    );
+    assert_eq!(
+        member_types(find("FleetVehicle")),
+        vec!["vin", "VehicleKind"]
+    );
+}
+
+#[test]
+fn test_flatten_without_imports_is_unchanged() {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut FLEET.as_bytes(), &mut cache, false)
+        .unwrap();
+    let module = cache.get(&name).unwrap();
+    let flattened = flatten_module(module, &cache).unwrap();
+
+    assert_eq!(
+        flattened.body().definitions().count(),
+        module.body().definitions().count()
+    );
+}
+
+const AUDITS: &str = r#"module audits <https://example.com/audits#> is
+  import [ fleet ]
+  structure Vehicle
+  structure Audit is
+    @fleet:vin = fleet:vin("1HGCM82633A004352")
+    vehicle -> Vehicle is
+      assert all_audited is
+        ∀ v ∈ fleet:Vehicle, is_audited(v)
+      end
+    end
+  end
+end
+"#;
+
+#[test]
+fn test_flatten_rewrites_constraints_and_constructors() {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    loader
+        .load_from_reader(&mut FLEET.as_bytes(), &mut cache, false)
+        .unwrap();
+    let name = loader
+        .load_from_reader(&mut AUDITS.as_bytes(), &mut cache, false)
+        .unwrap();
+    let module = cache.get(&name).unwrap();
+    let flattened = flatten_module(module, &cache).unwrap();
+
+    let mut references = References::default();
+    walk_module(&mut references, &flattened).unwrap();
+    assert!(references.0.contains(&"FleetVehicle".to_string()));
+    assert!(references.0.contains(&"vin".to_string()));
+    assert!(!references.0.iter().any(|name| name.starts_with("fleet:")));
+}
+
+#[test]
+fn test_flatten_keeps_registered_library_modules_imported() {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let fleet = loader
+        .load_from_reader(&mut FLEET.as_bytes(), &mut cache, false)
+        .unwrap();
+    let fleet = cache.get(&fleet).unwrap().clone();
+    cache.insert_library_module(fleet);
+    let name = loader
+        .load_from_reader(&mut RENTALS.as_bytes(), &mut cache, false)
+        .unwrap();
+    let module = cache.get(&name).unwrap();
+    let flattened = flatten_module(module, &cache).unwrap();
+
+    let names: Vec<String> = flattened
+        .body()
+        .definitions()
+        .map(|definition| definition.name().to_string())
+        .collect();
+    assert_eq!(names, vec!["Vehicle", "Rental"]);
+    assert!(flattened
+        .body()
+        .imported_modules()
+        .contains(&Identifier::from_str("fleet").unwrap()));
+}
+
+#[derive(Debug, Default)]
+struct References(Vec<String>);
+
+impl Visitor<'_> for References {
+    fn visit_identifier_reference(&mut self, node: &IdentifierReference) -> Result<(), Error> {
+        self.0.push(node.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use pretty_assertions::assert_eq;
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::Error;
+use sdml_generate::actions::format::{format_source, FormatOptions};
+use sdml_parse::load::FsModuleLoader;
+use std::path::PathBuf;
+
+pub mod common;
+
+const SOURCE: &str = r#";;; Header comment
+
+;; About the module
+module rentals <https://example.com/rentals#> is
+
+import xsd
+    import [ skos dc ] ; trailing
+  ; leading the rentals import
+  import rentals_base
+
+@dc:version =  2
+   @skos:prefLabel = "Rentals"@en
+
+
+  entity   Vehicle is ; the vehicle
+    @skos:prefLabel = [ "vehicle"@en "véhicule"@fr ]
+    identity vin->xsd:string
+
+    ; the kind of vehicle
+    kind -> { 0 .. 1 } VehicleKind
+    tags -> {ordered unique 0..} string is
+      assert no_spaces is
+            ∀ self, not
+              self.contains(" ")
+      end
+    end
+    ref rentedBy
+  end
+
+  enum VehicleKind of Car Van end
+
+  structure Empty is
+  end
+end ; the end
+"#;
+
+const FORMATTED: &str = r#";;; Header comment
+
+;; About the module
+module rentals <https://example.com/rentals#> is
+
+  import [ dc skos ] ; trailing
+  ; leading the rentals import
+  import rentals_base
+  import xsd
+
+  @dc:version     = 2
+  @skos:prefLabel = "Rentals"@en
+
+  entity Vehicle is ; the vehicle
+    @skos:prefLabel = [ "vehicle"@en "véhicule"@fr ]
+    identity vin -> xsd:string
+
+    ; the kind of vehicle
+    kind -> {0..1} VehicleKind
+    tags -> {ordered unique 0..} string is
+      assert no_spaces is
+        ∀ self, not
+          self.contains(" ")
+      end
+    end
+    ref rentedBy
+  end
+
+  enum VehicleKind of
+    Car
+    Van
+  end
+
+  structure Empty is end
+end ; the end
+"#;
+
+fn load(source: &str) -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut source.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+#[test]
+fn test_format_source() {
+    let formatted = format_source(0, SOURCE, &FormatOptions::default()).unwrap();
+    assert_eq!(formatted, FORMATTED);
+}
+
+#[test]
+fn test_format_is_idempotent() {
+    let formatted = format_source(0, FORMATTED, &FormatOptions::default()).unwrap();
+    assert_eq!(formatted, FORMATTED);
+}
+
+#[test]
+fn test_format_options() {
+    let options = FormatOptions::default()
+        .with_indentation(4)
+        .with_sort_imports(false)
+        .with_align_annotations(false);
+    let formatted = format_source(0, SOURCE, &options).unwrap();
+    assert!(formatted.contains("\n    import [ skos dc ] ; trailing\n"));
+    assert!(formatted.contains("\n    @dc:version = 2\n"));
+    assert!(formatted.contains("\n        identity vin -> xsd:string\n"));
+}
+
+#[test]
+fn test_format_syntax_error() {
+    let result = format_source(0, "module broken is entity end", &FormatOptions::default());
+    assert!(matches!(result, Err(Error::LanguageValidationError { .. })));
+}
+
+#[test]
+fn test_format_examples_preserves_model() {
+    let examples = PathBuf::from(common::MANIFEST_PATH).join(common::TEST_INPUT_PATH);
+    let mut count = 0;
+    for entry in std::fs::read_dir(examples).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map(|ext| ext == "sdm").unwrap_or_default() {
+            let source = std::fs::read_to_string(&path).unwrap();
+            let formatted = format_source(0, &source, &FormatOptions::default()).unwrap();
+            assert_eq!(
+                format_source(0, &formatted, &FormatOptions::default()).unwrap(),
+                formatted,
+                "formatting {path:?} is not idempotent"
+            );
+            // sorting imports changes their order in the model.
+            let unsorted = FormatOptions::default().with_sort_imports(false);
+            let formatted = format_source(0, &source, &unsorted).unwrap();
+            assert_eq!(load(&formatted), load(&source), "formatting {path:?}");
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
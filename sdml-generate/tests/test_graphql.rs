@@ -0,0 +1,37 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::graphql::{GraphQlGenerator, GraphQlGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_graphql,
+    GraphQlGenerator::default(),
+    GraphQlGeneratorOptions::default()
+}
+
+generator! {
+    generate_graphql_mapped,
+    GraphQlGenerator::default(),
+    GraphQlGeneratorOptions::default()
+        .with_scalar("xsd:long", "BigInt")
+        .with_scalar("rentals:Vin", "VIN")
+        .with_scalar("xsd:date", "String")
+}
+
+test_examples! {
+    graphql, "graphql", generate_graphql => (
+        graphql_rentals
+    )
+}
+
+test_examples! {
+    graphql_scalar_mapping, "graphql", generate_graphql_mapped => (
+        graphql_scalar_mapping
+    )
+}
@@ -0,0 +1,22 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::json_schema::{JsonSchemaGenerator, JsonSchemaGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_json_schema,
+    JsonSchemaGenerator::default(),
+    JsonSchemaGeneratorOptions::default()
+}
+
+test_examples! {
+    json_schema, "json", generate_json_schema => (
+        json_schema_rentals
+    )
+}
@@ -0,0 +1,77 @@
+use paste::paste;
+use pretty_assertions::assert_eq;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::protobuf::{FieldNumbers, ProtobufGenerator, ProtobufGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+// The field numbers saved from generating `protobuf_rentals`, before `seats` and the `Van`
+// variant were removed and `colour` and `Truck` were added.
+const SAVED_FIELD_NUMBERS: &str = r#"rentals.Payee.Vehicle = 1
+rentals.Payee.Location = 2
+rentals.Rented.at = 1
+rentals.Vehicle.vin = 1
+rentals.Vehicle.kind = 2
+rentals.Vehicle.seats = 3
+rentals.Vehicle.tags = 4
+rentals.Vehicle.location = 5
+rentals.Vehicle.mileage = 6
+rentals.Vehicle.odometer = 10
+rentals.VehicleKind.Car = 1
+rentals.VehicleKind.Van = 2
+"#;
+
+generator! {
+    generate_protobuf,
+    ProtobufGenerator::default(),
+    ProtobufGeneratorOptions::default()
+}
+
+generator! {
+    generate_protobuf_saved,
+    ProtobufGenerator::default(),
+    ProtobufGeneratorOptions::default().with_field_numbers(SAVED_FIELD_NUMBERS.parse().unwrap())
+}
+
+test_examples! {
+    protobuf, "proto", generate_protobuf => (
+        protobuf_rentals,
+        protobuf_unspecified_variant
+    )
+}
+
+test_examples! {
+    protobuf_saved_field_numbers, "proto", generate_protobuf_saved => (
+        protobuf_reserved_fields
+    )
+}
+
+#[test]
+fn test_field_numbers_text() {
+    let field_numbers: FieldNumbers =
+        "# rentals\n\nrentals.Vehicle.vin = 1\nrentals.Vehicle.kind = 2\n"
+            .parse()
+            .unwrap();
+    assert_eq!(field_numbers.get("rentals.Vehicle", "kind"), Some(2));
+    assert_eq!(
+        field_numbers.to_string(),
+        "rentals.Vehicle.vin = 1\nrentals.Vehicle.kind = 2\n"
+    );
+    assert!("rentals.Vehicle.vin = one".parse::<FieldNumbers>().is_err());
+}
+
+#[test]
+fn test_numbered_unspecified_variant() {
+    let mut cache = InMemoryModuleCache::default();
+    let name = common::load_example("protobuf_unspecified_numbered", &mut cache);
+    let module = cache.get(&name).unwrap();
+    let mut generator = ProtobufGenerator::default();
+    assert!(generator
+        .generate_to_string(module, &cache, ProtobufGeneratorOptions::default(), None)
+        .is_err());
+}
@@ -0,0 +1,34 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::python::{PythonGenerator, PythonGeneratorOptions, PythonStyle};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_pydantic,
+    PythonGenerator::default(),
+    PythonGeneratorOptions::default()
+}
+
+generator! {
+    generate_dataclasses,
+    PythonGenerator::default(),
+    PythonGeneratorOptions::default().with_style(PythonStyle::Dataclass)
+}
+
+test_examples! {
+    python_pydantic, "py", generate_pydantic => (
+        python_rentals
+    )
+}
+
+test_examples! {
+    python_dataclass, "py", generate_dataclasses => (
+        python_dataclasses
+    )
+}
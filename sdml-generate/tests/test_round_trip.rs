@@ -0,0 +1,113 @@
+use pretty_assertions::{assert_eq, assert_ne};
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::diagnostics::UseColor;
+use sdml_generate::color::set_colorize;
+use sdml_generate::convert::source::{SourceGenerator, SourceGeneratorOptions};
+use sdml_generate::Generator;
+use sdml_parse::load::FsModuleLoader;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const SOURCE: &str = r#"module round_trip <https://example.com/round_trip#> is
+  import [ skos xsd ]
+
+  @skos:prefLabel = "Round Trip"@en
+
+  datatype code <- xsd:string
+
+  entity Vehicle is
+    @skos:prefLabel = [ "vehicle"@en "véhicule"@fr ]
+    identity vin -> code
+    kind -> VehicleKind
+    tags -> {ordered unique 0..} string
+    owner -> {0..1} Party
+  end
+
+  structure Party is
+    name -> string
+    scores -> {1..3} integer
+  end
+
+  enum VehicleKind of
+    Car
+    Van is
+      @skos:prefLabel = "van"
+    end
+  end
+
+  union Asset of
+    Vehicle
+    Party as Owner
+  end
+
+  event Registered source Vehicle is
+    at -> xsd:dateTime
+  end
+end
+"#;
+
+fn load(source: &str) -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut source.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+fn emit(module: &Module) -> String {
+    // turn this off to avoid control characters in the output.
+    set_colorize(UseColor::Never);
+    let mut generator: SourceGenerator = Default::default();
+    generator
+        .generate_to_string(
+            module,
+            &InMemoryModuleCache::default(),
+            SourceGeneratorOptions::default(),
+            None,
+        )
+        .unwrap()
+}
+
+fn hash_of(module: &Module) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    module.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_round_trip_is_equal() {
+    let original = load(SOURCE);
+    let round_tripped = load(&emit(&original));
+
+    assert_eq!(original, round_tripped);
+    assert_eq!(hash_of(&original), hash_of(&round_tripped));
+}
+
+#[test]
+fn test_equality_ignores_layout() {
+    let original = load(SOURCE);
+    let reformatted = load(&SOURCE.replace("\n\n", "\n").replace("  ", "    "));
+
+    assert_eq!(original, reformatted);
+    assert_eq!(hash_of(&original), hash_of(&reformatted));
+}
+
+#[test]
+fn test_equality_ignores_import_grouping() {
+    let original = load(SOURCE);
+    let regrouped = load(&SOURCE.replace("import [ skos xsd ]", "import skos\n  import xsd"));
+
+    assert_eq!(original, regrouped);
+    assert_eq!(hash_of(&original), hash_of(&regrouped));
+}
+
+#[test]
+fn test_equality_detects_changes() {
+    let original = load(SOURCE);
+    let changed = load(&SOURCE.replace("{1..3} integer", "{1..4} integer"));
+
+    assert_ne!(original, changed);
+    assert_ne!(hash_of(&original), hash_of(&changed));
+}
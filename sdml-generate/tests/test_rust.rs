@@ -0,0 +1,37 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::rust::{RustGenerator, RustGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_rust,
+    RustGenerator::default(),
+    RustGeneratorOptions::default()
+}
+
+generator! {
+    generate_rust_mapped,
+    RustGenerator::default(),
+    RustGeneratorOptions::default()
+        .with_type("xsd:dateTime", "chrono::NaiveDateTime")
+        .with_type("rentals:Vin", "vin::Vin")
+}
+
+test_examples! {
+    rust, "rs", generate_rust => (
+        rust_rentals,
+        rust_reserved_names
+    )
+}
+
+test_examples! {
+    rust_type_mapping, "rs", generate_rust_mapped => (
+        rust_type_mapping
+    )
+}
@@ -1,14 +1,17 @@
+use paste::paste;
 use pretty_assertions::assert_eq;
 use sdml_core::model::identifiers::Identifier;
 use sdml_core::model::modules::Module;
-use sdml_core::store::InMemoryModuleCache;
+use sdml_core::model::HasName;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
 use sdml_errors::diagnostics::UseColor;
 use sdml_generate::color::set_colorize;
 use sdml_generate::convert::source::{SourceGenerator, SourceGeneratorOptions};
 use sdml_generate::Generator;
 use url::Url;
 
-pub mod common;
+#[macro_use]
+mod common;
 
 #[test]
 fn test_generate_module_empty() {
@@ -49,3 +52,25 @@ fn test_generate_module_empty_with_base() {
         "module example <http://example.com/> is end\n"
     );
 }
+
+// ------------------------------------------------------------------------------------------------
+// Examples
+// ------------------------------------------------------------------------------------------------
+
+generator! {
+    generate_source,
+    SourceGenerator::default(),
+    SourceGeneratorOptions::default(),
+    || {
+        // turn this off to avoid control characters in the output.
+        set_colorize(UseColor::Never);
+    }
+}
+
+test_examples! {
+    source, "sdm", generate_source => (
+        entity_with_diff_members,
+        entity_with_members,
+        member_sequence_ordering
+    )
+}
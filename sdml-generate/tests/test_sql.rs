@@ -0,0 +1,35 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::sql::{EnumStyle, SqlGenerator, SqlGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_sql,
+    SqlGenerator::default(),
+    SqlGeneratorOptions::default()
+}
+
+generator! {
+    generate_sql_check_constraint,
+    SqlGenerator::default(),
+    SqlGeneratorOptions::default().with_enum_style(EnumStyle::CheckConstraint)
+}
+
+test_examples! {
+    sql_ddl, "sql", generate_sql => (
+        sql_rentals,
+        sql_reserved_words
+    )
+}
+
+test_examples! {
+    sql_enum_check_constraint, "sql", generate_sql_check_constraint => (
+        sql_enum_check_constraint
+    )
+}
@@ -0,0 +1,154 @@
+use pretty_assertions::assert_eq;
+use sdml_core::model::modules::Module;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_generate::convert::sql::migration::write_migration;
+use sdml_generate::convert::sql::SqlGeneratorOptions;
+use sdml_parse::load::FsModuleLoader;
+
+const OLD: &str = r#"module rentals is
+
+  import xsd
+
+  datatype Vin <- xsd:string is
+    @xsd:maxLength = 17
+  end
+
+  entity Customer is
+    identity id -> xsd:long
+    email -> string
+    phone -> {0..1} string
+  end
+
+  entity Vehicle is
+    identity vin -> Vin
+    kind -> VehicleKind
+    owner -> {0..1} Customer
+  end
+
+  entity Depot is
+    identity code -> string
+  end
+
+  enum VehicleKind of Car Van Truck end
+end
+"#;
+
+const NEW: &str = r#"module rentals is
+
+  import xsd
+
+  datatype Vin <- xsd:string is
+    @xsd:length = 17
+  end
+
+  entity Customer is
+    identity id -> xsd:long
+    email -> {0..1} string
+    phone -> string
+    tags -> {0..} string
+  end
+
+  entity Vehicle is
+    identity vin -> Vin
+    kind -> VehicleKind
+    seats -> {0..1} unsigned
+  end
+
+  enum VehicleKind of Car Van end
+
+  enum Status of Active Retired end
+end
+"#;
+
+fn load(source: &str) -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut source.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+fn migration(old: &str, new: &str) -> String {
+    let cache = InMemoryModuleCache::default();
+    let mut buffer = Vec::default();
+    write_migration(
+        &load(old),
+        &load(new),
+        &cache,
+        SqlGeneratorOptions::default(),
+        &mut buffer,
+    )
+    .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_sql_migration() {
+    assert_eq!(
+        migration(OLD, NEW),
+        r#"-- Migration: rentals
+
+-- WARNING: removing 'Truck' from vehicle_kind fails if any row still uses them.
+ALTER TYPE vehicle_kind RENAME TO vehicle_kind_old;
+
+CREATE TYPE vehicle_kind AS ENUM ('Car', 'Van');
+
+CREATE TYPE status AS ENUM ('Active', 'Retired');
+
+ALTER TABLE vehicle DROP CONSTRAINT vehicle_owner_id_fkey;
+
+ALTER TABLE customer ALTER COLUMN email DROP NOT NULL;
+
+-- WARNING: making customer.phone required fails if it contains any null values.
+ALTER TABLE customer ALTER COLUMN phone SET NOT NULL;
+
+-- WARNING: dropping the column vehicle.owner_id permanently deletes its data.
+ALTER TABLE vehicle DROP COLUMN owner_id;
+
+-- WARNING: adding the constraint on vehicle.vin fails if any existing value does not satisfy it.
+ALTER TABLE vehicle ADD CONSTRAINT vehicle_vin_check CHECK (char_length(vin) = 17);
+
+ALTER TABLE vehicle ALTER COLUMN kind TYPE vehicle_kind USING kind::text::vehicle_kind;
+
+ALTER TABLE vehicle ADD COLUMN seats BIGINT CONSTRAINT vehicle_seats_check CHECK (seats >= 0);
+
+CREATE TABLE customer_tags (
+    customer_id BIGINT NOT NULL,
+    tags TEXT NOT NULL
+);
+
+-- WARNING: dropping the table depot permanently deletes its data.
+DROP TABLE depot;
+
+DROP TYPE vehicle_kind_old;
+
+ALTER TABLE customer_tags ADD CONSTRAINT customer_tags_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id);
+"#
+    );
+}
+
+#[test]
+fn test_sql_migration_no_changes() {
+    assert_eq!(
+        migration(OLD, OLD),
+        "-- Migration: rentals\n-- No changes.\n"
+    );
+}
+
+#[test]
+fn test_sql_migration_added_enum_variants() {
+    let sql = migration(
+        "module m is enum Kind of B C end end",
+        "module m is enum Kind of A B C D end end",
+    );
+    assert_eq!(
+        sql,
+        r#"-- Migration: m
+
+ALTER TYPE kind ADD VALUE 'A' BEFORE 'B';
+
+ALTER TYPE kind ADD VALUE 'D' AFTER 'C';
+"#
+    );
+}
@@ -0,0 +1,34 @@
+use paste::paste;
+use sdml_core::{
+    model::{modules::Module, HasName},
+    store::{InMemoryModuleCache, ModuleStore},
+};
+use sdml_generate::convert::typescript::{TypeScriptGenerator, TypeScriptGeneratorOptions};
+use sdml_generate::Generator;
+
+#[macro_use]
+mod common;
+
+generator! {
+    generate_typescript,
+    TypeScriptGenerator::default(),
+    TypeScriptGeneratorOptions::default()
+}
+
+generator! {
+    generate_typescript_guards,
+    TypeScriptGenerator::default(),
+    TypeScriptGeneratorOptions::default().type_guards(true)
+}
+
+test_examples! {
+    typescript, "ts", generate_typescript => (
+        typescript_rentals
+    )
+}
+
+test_examples! {
+    typescript_type_guards, "ts", generate_typescript_guards => (
+        typescript_type_guards
+    )
+}
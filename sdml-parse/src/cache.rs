@@ -0,0 +1,237 @@
+/*!
+This module provides a persistent, on-disk, cache of parsed modules.
+
+Parsing a large set of modules can take a significant amount of time, and for tools such as the
+command-line that are run repeatedly over the same sources the result is the same each time. The
+[`ParsedModuleCache`] stores each parsed [`Module`] as serialized JSON in a cache directory, the
+name of each entry is a hash of the module's source text and the version of the SDML grammar and
+of this crate. Any change to the source, or an upgrade of either the grammar or parser, results in
+a different key and so stale entries are never used.
+
+Modules are only added to the cache if their parsing produced no diagnostics, this ensures that a
+module retrieved from the cache behaves exactly as it would if it had been parsed.
+
+# Example
+
+```rust,no_run
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_core::load::ModuleLoader;
+use sdml_parse::cache::ParsedModuleCache;
+use sdml_parse::load::FsModuleLoader;
+use std::str::FromStr;
+
+let mut cache = InMemoryModuleCache::default().with_stdlib();
+let mut loader = FsModuleLoader::default()
+    .with_parsed_cache(ParsedModuleCache::user_default().unwrap());
+
+let name = Identifier::from_str("rentals").unwrap();
+let module_name = loader.load(&name, None, &mut cache, true);
+assert!(module_name.is_ok());
+```
+
+*/
+
+use sdml_core::model::modules::Module;
+use sdml_errors::Error;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, ErrorKind};
+use std::path::{Path, PathBuf};
+use tracing::{trace, warn};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A directory of serialized modules, keyed by a hash of their source, see the
+/// [module documentation](self) for details.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedModuleCache {
+    directory: PathBuf,
+}
+
+///
+/// The key used to identify a cache entry, computed from a module's source text.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+/// The environment variable used to override the default cache directory location.
+pub const SDML_CACHE_DIR_VARIABLE: &str = "SDML_CACHE_DIR";
+
+/// The file extension used for cache entries.
+pub const SDML_CACHE_FILE_EXTENSION: &str = "json";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ParsedModuleCache {
+    ///
+    /// Create a new cache using `directory` to store entries, the directory is created on first
+    /// use if it does not exist.
+    ///
+    pub fn new<P>(directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    ///
+    /// Create a new cache in the user's default location. This is the value of the environment
+    /// variable `SDML_CACHE_DIR` if set, else the directory `sdml` within the platform cache
+    /// directory (`$XDG_CACHE_HOME`, `$HOME/.cache`, or `%LOCALAPPDATA%`). If none of these
+    /// can be determined `None` is returned.
+    ///
+    pub fn user_default() -> Option<Self> {
+        Self::default_directory().map(Self::new)
+    }
+
+    ///
+    /// Return the default cache directory, see [`Self::user_default`].
+    ///
+    pub fn default_directory() -> Option<PathBuf> {
+        if let Some(directory) = env::var_os(SDML_CACHE_DIR_VARIABLE) {
+            Some(PathBuf::from(directory))
+        } else if let Some(directory) = env::var_os("XDG_CACHE_HOME") {
+            Some(PathBuf::from(directory).join("sdml"))
+        } else if let Some(directory) = env::var_os("HOME") {
+            Some(PathBuf::from(directory).join(".cache").join("sdml"))
+        } else {
+            env::var_os("LOCALAPPDATA").map(|directory| PathBuf::from(directory).join("sdml"))
+        }
+    }
+
+    ///
+    /// Return the directory used to store cache entries.
+    ///
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    ///
+    /// Compute the cache key for the module source text `source`.
+    ///
+    pub fn key_for(&self, source: &str) -> CacheKey {
+        let mut hasher = Sha256::new();
+        hasher.update(tree_sitter_sdml::GRAMMAR_VERSION.as_bytes());
+        hasher.update([0]);
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update([0]);
+        hasher.update(source.as_bytes());
+        CacheKey(
+            hasher
+                .finalize()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+        )
+    }
+
+    ///
+    /// Returns `true` if the cache contains an entry for `key`.
+    ///
+    pub fn contains(&self, key: &CacheKey) -> bool {
+        self.entry_path(key).is_file()
+    }
+
+    ///
+    /// Return the module stored under `key`, if present. Any entry that cannot be read is
+    /// treated as a cache miss.
+    ///
+    pub fn get(&self, key: &CacheKey) -> Option<Module> {
+        trace!("ParsedModuleCache::get({key})");
+        let path = self.entry_path(key);
+        match File::open(&path) {
+            Ok(file) => match serde_json::from_reader(BufReader::new(file)) {
+                Ok(module) => Some(module),
+                Err(e) => {
+                    warn!("Could not read module cache entry {path:?}, error: {e}");
+                    None
+                }
+            },
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => {
+                warn!("Could not open module cache entry {path:?}, error: {e}");
+                None
+            }
+        }
+    }
+
+    ///
+    /// Store `module` under `key`, replacing any existing entry.
+    ///
+    pub fn put(&self, key: &CacheKey, module: &Module) -> Result<(), Error> {
+        trace!("ParsedModuleCache::put({key}, ...)");
+        fs::create_dir_all(&self.directory)?;
+        // Write to a temporary file first so that a concurrent reader never sees a partial entry.
+        let path = self.entry_path(key);
+        let temp_path = path.with_extension(format!("{}.{}", std::process::id(), "tmp"));
+        {
+            let mut writer = BufWriter::new(File::create(&temp_path)?);
+            serde_json::to_writer(&mut writer, module).map_err(std::io::Error::from)?;
+        }
+        fs::rename(temp_path, path)?;
+        Ok(())
+    }
+
+    ///
+    /// Remove the entry stored under `key`, returning `true` if an entry existed.
+    ///
+    pub fn remove(&self, key: &CacheKey) -> Result<bool, Error> {
+        match fs::remove_file(self.entry_path(key)) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    ///
+    /// Remove all entries from the cache, returning the number of entries removed.
+    ///
+    pub fn clear(&self) -> Result<usize, Error> {
+        trace!("ParsedModuleCache::clear()");
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file()
+                && path.extension().and_then(|ext| ext.to_str()) == Some(SDML_CACHE_FILE_EXTENSION)
+            {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn entry_path(&self, key: &CacheKey) -> PathBuf {
+        self.directory
+            .join(format!("{}.{}", key.0, SDML_CACHE_FILE_EXTENSION))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for CacheKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
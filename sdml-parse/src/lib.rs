@@ -77,4 +77,11 @@ mod parse;
 
 pub use sdml_core::error;
 
+pub mod cache;
+
 pub mod load;
+
+pub mod package;
+
+pub mod workspace;
+pub use workspace::Workspace;
@@ -3,22 +3,28 @@ This module contains implementations of the [`ModuleResolver`] and [`ModuleLoade
 file-system based modules.
 */
 
+use crate::cache::ParsedModuleCache;
+use crate::package::{ModulePackage, PackageFormat, PackagedModule};
 use crate::parse::parse_str;
 use codespan_reporting::files::SimpleFiles;
 use sdml_core::load::{ModuleLoader, ModuleResolver};
+use sdml_core::model::definitions::Definition;
 use sdml_core::model::identifiers::Identifier;
-use sdml_core::model::modules::HeaderValue;
-use sdml_core::model::{HasName, HasSourceSpan};
-use sdml_core::stdlib;
+use sdml_core::model::modules::{HeaderValue, Module};
+use sdml_core::model::{HasBody, HasName, HasSourceSpan};
 use sdml_core::store::ModuleStore;
+use sdml_errors::diagnostics::functions::{
+    imported_module_not_found, library_definition_not_allowed, module_not_found,
+};
 use sdml_errors::diagnostics::reporter::ReportCounters;
 use sdml_errors::diagnostics::SeverityFilter;
-use sdml_errors::diagnostics::{functions::imported_module_not_found, StandardStreamReporter};
+use sdml_errors::diagnostics::StandardStreamReporter;
 use sdml_errors::{Diagnostic, Reporter, Source, SourceFiles};
 use sdml_errors::{Error, FileId};
 use search_path::SearchPath;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs::File;
 use std::io::Read;
@@ -34,10 +40,15 @@ use url::Url;
 /// The resolver implements the logic to map module identifiers to file system paths using the
 /// environment variable `SDML_PATH` to contain a search path.
 ///
+/// Any entry in the search path that is a package file (see [`crate::package`]) is not searched
+/// as a directory but is added to the resolver's list of packages. Modules not found on the file
+/// system are then resolved from these packages, in the order they were added.
+///
 #[derive(Clone, Debug)]
 pub struct FsModuleResolver {
     catalog: Option<ModuleCatalog>,
     search_path: SearchPath,
+    packages: Vec<ModulePackage>,
 }
 
 /// The name of the SDML environment variable that may be used to hold a load path.
@@ -64,12 +75,20 @@ pub const SDML_CATALOG_FILE_VARIABLE: &str = "SDML_CATALOG_FILE";
 /// 2. parsing the source into an in-memory representation,
 /// 3. caching the loaded module, and it's source, for future use.
 ///
+/// Optionally, the loader may also use a [`ParsedModuleCache`] in which case the parsing step is
+/// skipped for any module whose source is unchanged since it was last parsed.
+///
+/// When loading recursively the imports of a module are loaded a level at a time, all the modules
+/// imported at the same depth are parsed concurrently. Diagnostics are reported in the order the
+/// modules are imported regardless of which finishes parsing first.
+///
 #[derive(Debug)]
 pub struct FsModuleLoader {
     resolver: FsModuleResolver,
     module_file_ids: HashMap<Identifier, usize>,
     module_files: SourceFiles,
     reporter: Box<dyn Reporter>,
+    parsed_cache: Option<ParsedModuleCache>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -119,6 +138,25 @@ pub struct Item {
     relative_path: PathBuf,
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Where the source of a module, that is not from a reader, was found.
+#[derive(Debug)]
+enum ModuleOrigin {
+    File(PathBuf),
+    Package(PackagedModule),
+}
+
+/// A loader used by a single validation task, diagnostics are held until they can be reported,
+/// in order, by the actual loader.
+#[derive(Debug, Default)]
+struct BufferedLoader<'a> {
+    loader: Option<&'a FsModuleLoader>,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Macros
 // ------------------------------------------------------------------------------------------------
@@ -153,7 +191,27 @@ impl Default for FsModuleResolver {
         // 2. Add the current directory to the search path
         search_path.prepend_cwd();
 
-        // 3. Load catalog file
+        // 3. Move any package files from the search path into the package list
+        let package_files: Vec<PathBuf> = search_path
+            .iter()
+            .filter(|path| PackageFormat::is_package_file(path))
+            .cloned()
+            .collect();
+        let packages = package_files
+            .into_iter()
+            .filter_map(|path| {
+                search_path.remove(&path);
+                match ModulePackage::open(&path) {
+                    Ok(package) => Some(package),
+                    Err(e) => {
+                        error!("Could not load package from search path {path:?}, error: {e}");
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        // 4. Load catalog file
         let catalog = match env::var(SDML_CATALOG_FILE_VARIABLE) {
             // If the environment variable is provided, load it from the location provided
             Ok(catalog_file) => {
@@ -171,6 +229,7 @@ impl Default for FsModuleResolver {
         let _self = Self {
             catalog,
             search_path,
+            packages,
         };
 
         trace!("=> {:?}", _self);
@@ -180,6 +239,11 @@ impl Default for FsModuleResolver {
 
 impl ModuleResolver for FsModuleResolver {
     fn name_to_resource(&self, name: &Identifier, from: Option<FileId>) -> Result<Url, Error> {
+        if self.find_path(name).is_none() {
+            if let Some(packaged) = self.name_to_package_source(name) {
+                return packaged.to_url();
+            }
+        }
         Url::from_file_path(self.name_to_path(name, from)?)
             .map_err(|_| Error::UrlParseError { source: None })
     }
@@ -196,14 +260,54 @@ impl FsModuleResolver {
         self.search_path.append(PathBuf::from(path));
     }
 
+    /// Add the provided package to the end of the package list.
+    pub fn append_package(&mut self, package: ModulePackage) {
+        self.packages.push(package);
+    }
+
+    /// Read the package file at `path` and add it to the end of the package list.
+    pub fn append_package_file(&mut self, path: &Path) -> Result<(), Error> {
+        self.append_package(ModulePackage::open(path)?);
+        Ok(())
+    }
+
+    /// Return an iterator over the packages used by this resolver.
+    pub fn packages(&self) -> impl Iterator<Item = &ModulePackage> {
+        self.packages.iter()
+    }
+
+    /// Return the first package that contains the named module, if any.
+    pub fn name_to_package(&self, name: &Identifier) -> Option<&ModulePackage> {
+        self.packages
+            .iter()
+            .find(|package| package.contains_module(name))
+    }
+
+    /// Return the source of the named module from the first package that contains it, if any.
+    pub fn name_to_package_source(&self, name: &Identifier) -> Option<PackagedModule> {
+        self.name_to_package(name)
+            .and_then(|package| package.module_source(name))
+    }
+
     /// Return a file system path for the resource that /should/ contain the named module.
     pub fn name_to_path(&self, name: &Identifier, from: Option<FileId>) -> Result<PathBuf, Error> {
         trace_entry!("ModuleResolver", "name_to_path" => "{}", name);
+        self.find_path(name).ok_or_else(|| {
+            imported_module_not_found(
+                from.unwrap_or_default(),
+                name.source_span().map(|span| span.into()),
+                name,
+            )
+            .into()
+        })
+    }
+
+    fn find_path(&self, name: &Identifier) -> Option<PathBuf> {
         if let Some(catalog) = &self.catalog {
             let name: String = name.to_string();
             if let Some(path) = catalog.resolve_local_path(&name) {
                 trace!("Found module in catalog, path: {path:?}");
-                return Ok(path);
+                return Some(path);
             }
         }
         self.search_path
@@ -222,14 +326,6 @@ impl FsModuleResolver {
                             })
                     })
             })
-            .ok_or_else(|| {
-                imported_module_not_found(
-                    from.unwrap_or_default(),
-                    name.source_span().map(|span| span.into()),
-                    name,
-                )
-                .into()
-            })
     }
 }
 
@@ -242,6 +338,7 @@ impl Default for FsModuleLoader {
             module_file_ids: Default::default(),
             module_files: SimpleFiles::new(),
             reporter: Box::<StandardStreamReporter>::default(),
+            parsed_cache: None,
         }
     }
 }
@@ -255,18 +352,15 @@ impl ModuleLoader for FsModuleLoader {
         recursive: bool,
     ) -> Result<Identifier, Error> {
         trace_entry!("ModuleLoader", "load" => "{}", name);
-        if stdlib::library_module(name).is_some() {
+        if cache.is_library_module(name) {
             Ok(name.clone())
         } else {
-            let file = match self.resolver.name_to_path(name, from) {
-                Ok(f) => f,
-                Err(Error::LanguageValidationError { source }) => {
-                    self.report(&source)?;
-                    return Err(source.into());
+            match self.resolve_origin(name, from)? {
+                ModuleOrigin::File(file) => self.load_from_file(file, cache, recursive),
+                ModuleOrigin::Package(packaged) => {
+                    self.load_from_package(packaged, cache, recursive)
                 }
-                Err(e) => return Err(e),
-            };
-            self.load_from_file(file, cache, recursive)
+            }
         }
     }
 
@@ -310,6 +404,24 @@ impl FsModuleLoader {
         Self { reporter, ..self }
     }
 
+    /// Use `parsed_cache` to store, and reuse, parsed modules.
+    pub fn with_parsed_cache(self, parsed_cache: ParsedModuleCache) -> Self {
+        Self {
+            parsed_cache: Some(parsed_cache),
+            ..self
+        }
+    }
+
+    /// Return the cache of parsed modules used by this loader, if any.
+    pub fn parsed_cache(&self) -> Option<&ParsedModuleCache> {
+        self.parsed_cache.as_ref()
+    }
+
+    /// Set, or unset, the cache of parsed modules used by this loader.
+    pub fn set_parsed_cache(&mut self, parsed_cache: Option<ParsedModuleCache>) {
+        self.parsed_cache = parsed_cache;
+    }
+
     /// Load a module from the source in `file`.
     pub fn load_from_file(
         &mut self,
@@ -318,26 +430,36 @@ impl FsModuleLoader {
         recursive: bool,
     ) -> Result<Identifier, Error> {
         trace_entry!("ModuleLoader", "load_from_file" => "{:?}", file);
-        let mut reader = File::open(&file)?;
-        let catalog = self.resolver.catalog.clone();
-        let module_name = self.load_inner(&mut reader, Some(file.clone()), cache, recursive)?;
-        let module = cache.get_mut(&module_name).unwrap();
-        module.set_source_file(file.clone());
-        if !module.has_base_uri() {
-            if let Some(catalog) = catalog {
-                let name = module.name().to_string();
-                if let Some(url) = catalog.resolve_uri(&name) {
-                    module.set_base_uri(HeaderValue::from(url));
-                }
-            } else {
-                let file = file.canonicalize()?;
-                match Url::from_file_path(file) {
-                    Ok(base) => module.set_base_uri(HeaderValue::from(base)),
-                    Err(_) => warn!("Could not construct a base URI"),
-                }
-            }
-        }
-        Ok(module_name)
+        let origin = ModuleOrigin::File(file);
+        let source = origin.read_source()?;
+        self.load_inner(source, Some(origin), cache, recursive)
+    }
+
+    /// Load a module from the source found in a package, see [`FsModuleResolver::name_to_package_source`].
+    pub fn load_from_package(
+        &mut self,
+        packaged: PackagedModule,
+        cache: &mut impl ModuleStore,
+        recursive: bool,
+    ) -> Result<Identifier, Error> {
+        trace_entry!("ModuleLoader", "load_from_package" => "{:?}", packaged.source_file());
+        let origin = ModuleOrigin::Package(packaged);
+        let source = origin.read_source()?;
+        self.load_inner(source, Some(origin), cache, recursive)
+    }
+
+    ///
+    /// Return the source text of the named module, without parsing it. The module is resolved as
+    /// it would be by [`ModuleLoader::load`], so the source may be read from a file or from a
+    /// package.
+    ///
+    pub fn read_module_source(
+        &self,
+        name: &Identifier,
+        from: Option<FileId>,
+    ) -> Result<String, Error> {
+        trace_entry!("ModuleLoader", "read_module_source" => "{}", name);
+        self.resolve_origin(name, from)?.read_source()
     }
 
     /// Load a module reading the source from `reader`.
@@ -348,52 +470,249 @@ impl FsModuleLoader {
         recursive: bool,
     ) -> Result<Identifier, Error> {
         trace_entry!("ModuleLoader", "load_from_reader");
-        self.load_inner(reader, None, cache, recursive)
+        let mut source = String::new();
+        reader.read_to_string(&mut source)?;
+        self.load_inner(source, None, cache, recursive)
+    }
+
+    ///
+    /// Load a module from each of the source `files`, the files are parsed concurrently. The
+    /// result for each file is returned in the same order as `files`, a failure to load one file
+    /// does not stop the loading of others.
+    ///
+    pub fn load_from_files(
+        &mut self,
+        files: &[PathBuf],
+        cache: &mut impl ModuleStore,
+        recursive: bool,
+    ) -> Vec<Result<Identifier, Error>> {
+        trace_entry!("ModuleLoader", "load_from_files" => "[{}]", files.len());
+        let mut results: Vec<Option<Result<Identifier, Error>>> = Vec::with_capacity(files.len());
+        let mut pending = Vec::default();
+        for file in files {
+            let origin = ModuleOrigin::File(file.clone());
+            match origin.read_source() {
+                Ok(source) => {
+                    let file_id = self.add_source(source, Some(&origin));
+                    pending.push((file_id, Some(origin)));
+                    results.push(None);
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+
+        let mut parsed = self.parse_and_insert(pending, cache).into_iter();
+        results
+            .into_iter()
+            .map(|result| match result {
+                Some(result) => result,
+                None => {
+                    let result = parsed.next().unwrap();
+                    match result {
+                        Ok(name) if recursive => self.load_imports(&name, cache).map(|_| name),
+                        _ => result,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Return the counts of diagnostics reported so far, without completing the report.
+    pub fn reporter_counters(&self) -> ReportCounters {
+        self.reporter.counters()
+    }
+
+    /// Return a mutable reference to the resolver used by this loader.
+    pub fn resolver_mut(&mut self) -> &mut FsModuleResolver {
+        &mut self.resolver
+    }
+
+    ///
+    /// Validate each of the `modules` with the module validation running on multiple threads.
+    /// Diagnostics are reported once all validation is complete, grouped by module in the same
+    /// order as `modules`, so that the output is the same as validating each in turn.
+    ///
+    pub fn validate_modules(
+        &self,
+        modules: &[&Module],
+        cache: &(impl ModuleStore + Sync),
+        check_constraints: bool,
+    ) -> Result<(), Error> {
+        trace_entry!("ModuleLoader", "validate_modules" => "[{}]", modules.len());
+        let results = parallel_map(modules, |module| {
+            let buffered = BufferedLoader {
+                loader: Some(self),
+                diagnostics: Default::default(),
+            };
+            module.validate(cache, &buffered, check_constraints);
+            buffered.diagnostics.into_inner()
+        });
+        for diagnostic in results.iter().flatten() {
+            self.report(diagnostic)?;
+        }
+        Ok(())
     }
 
     fn load_inner(
         &mut self,
-        reader: &mut dyn Read,
-        file: Option<PathBuf>,
+        source: String,
+        origin: Option<ModuleOrigin>,
         cache: &mut impl ModuleStore,
         recursive: bool,
     ) -> Result<Identifier, Error> {
-        trace!("ModuleLoader::load_inner(..., {file:?}, ..., {recursive})");
-        let mut source = String::new();
-        reader.read_to_string(&mut source)?;
-        let file_name: String = file
-            .map(|p| p.to_string_lossy().into_owned())
-            .unwrap_or_default();
-        let file_id = self.module_files.add(file_name, source.into());
+        trace!("ModuleLoader::load_inner(..., {origin:?}, ..., {recursive})");
+        let file_id = self.add_source(source, origin.as_ref());
+        let name = self
+            .parse_and_insert(vec![(file_id, origin)], cache)
+            .remove(0)?;
+        if recursive {
+            self.load_imports(&name, cache)?;
+        }
+        Ok(name)
+    }
 
-        let module = parse_str(file_id, self)?;
+    // Load the import closure of `name` one level at a time, all the modules at a level are
+    // resolved in order and then parsed together.
+    fn load_imports(
+        &mut self,
+        name: &Identifier,
+        cache: &mut impl ModuleStore,
+    ) -> Result<(), Error> {
+        let mut current = vec![name.clone()];
+        while !current.is_empty() {
+            let mut requested: HashSet<Identifier> = Default::default();
+            let mut pending: Vec<(FileId, Option<ModuleOrigin>)> = Default::default();
+            for parent in &current {
+                let from = self.get_file_id(parent);
+                let mut dependencies = cache
+                    .get(parent)
+                    .map(|module| {
+                        module
+                            .imported_modules()
+                            .into_iter()
+                            .cloned()
+                            .collect::<Vec<Identifier>>()
+                    })
+                    .unwrap_or_default();
+                dependencies.sort();
+                for name in dependencies {
+                    if cache.contains(&name) || cache.is_library_module(&name) {
+                        debug!("found module {name} in cache");
+                    } else if requested.insert(name.clone()) {
+                        debug!("didn't find module {name} in cache, loading");
+                        // TODO: this bails on the first missing import, is that what we want?
+                        let origin = self.resolve_origin(&name, from)?;
+                        let source = origin.read_source()?;
+                        let file_id = self.add_source(source, Some(&origin));
+                        pending.push((file_id, Some(origin)));
+                    }
+                }
+            }
+            current = self
+                .parse_and_insert(pending, cache)
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        Ok(())
+    }
 
-        let name = module.name().clone();
+    fn resolve_origin(
+        &self,
+        name: &Identifier,
+        from: Option<FileId>,
+    ) -> Result<ModuleOrigin, Error> {
+        if self.resolver.find_path(name).is_none() {
+            if let Some(packaged) = self.resolver.name_to_package_source(name) {
+                return Ok(ModuleOrigin::Package(packaged));
+            }
+        }
+        match self.resolver.name_to_path(name, from) {
+            Ok(file) => Ok(ModuleOrigin::File(file)),
+            Err(Error::LanguageValidationError { source }) => {
+                self.report(&source)?;
+                Err(source.into())
+            }
+            Err(e) => Err(e),
+        }
+    }
 
-        let _ = self.module_file_ids.insert(name.clone(), file_id);
+    fn add_source(&mut self, source: String, origin: Option<&ModuleOrigin>) -> FileId {
+        let file_name: String = origin
+            .map(|origin| origin.source_file().to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.module_files.add(file_name, source.into())
+    }
 
-        cache.insert(module);
+    // Parse the pending sources in parallel, then report diagnostics and add the modules to
+    // the cache in the order given.
+    fn parse_and_insert(
+        &mut self,
+        pending: Vec<(FileId, Option<ModuleOrigin>)>,
+        cache: &mut impl ModuleStore,
+    ) -> Vec<Result<Identifier, Error>> {
+        let results = {
+            let files = &self.module_files;
+            let parsed_cache = self.parsed_cache.as_ref();
+            parallel_map(&pending, |(file_id, _)| {
+                parse_or_fetch(
+                    *file_id,
+                    files.get(*file_id).unwrap().source(),
+                    parsed_cache,
+                )
+            })
+        };
 
-        if recursive {
-            let dependencies = {
-                let module = cache.get(&name).unwrap();
-                module
-                    .imported_modules()
-                    .into_iter()
-                    .cloned()
-                    .collect::<Vec<Identifier>>()
-            };
-            for name in &dependencies {
-                if !cache.contains(name) {
-                    debug!("didn't find module {name} in cache, loading");
-                    // TODO: this bails on the first missing import, is that what we want?
-                    self.load(name, Some(file_id), cache, recursive)?;
-                } else {
-                    debug!("found module {name} in cache");
+        pending
+            .into_iter()
+            .zip(results)
+            .map(|((file_id, origin), (result, diagnostics))| {
+                for diagnostic in &diagnostics {
+                    self.report(diagnostic)?;
+                }
+                let mut module = result?;
+                check_library_definitions(&mut module, file_id, cache)?;
+                if let Some(origin) = origin {
+                    self.set_module_origin(&mut module, origin)?;
+                }
+                let name = module.name().clone();
+                let _ = self.module_file_ids.insert(name.clone(), file_id);
+                cache.insert(module);
+                Ok(name)
+            })
+            .collect()
+    }
+
+    fn set_module_origin(&self, module: &mut Module, origin: ModuleOrigin) -> Result<(), Error> {
+        module.set_source_file(origin.source_file());
+        if !module.has_base_uri() {
+            match origin {
+                ModuleOrigin::File(file) => {
+                    if let Some(catalog) = &self.resolver.catalog {
+                        let name = module.name().to_string();
+                        if let Some(url) = catalog.resolve_uri(&name) {
+                            module.set_base_uri(HeaderValue::from(url));
+                        }
+                    } else {
+                        let file = file.canonicalize()?;
+                        match Url::from_file_path(file) {
+                            Ok(base) => module.set_base_uri(HeaderValue::from(base)),
+                            Err(_) => warn!("Could not construct a base URI"),
+                        }
+                    }
+                }
+                ModuleOrigin::Package(packaged) => {
+                    if let Some(url) = packaged.base_uri() {
+                        module.set_base_uri(HeaderValue::from(url.clone()));
+                    } else {
+                        match packaged.to_url() {
+                            Ok(base) => module.set_base_uri(HeaderValue::from(base)),
+                            Err(_) => warn!("Could not construct a base URI"),
+                        }
+                    }
                 }
             }
         }
-        Ok(name)
+        Ok(())
     }
 
     #[inline(always)]
@@ -404,6 +723,165 @@ impl FsModuleLoader {
 
 // ------------------------------------------------------------------------------------------------
 
+impl ModuleOrigin {
+    fn source_file(&self) -> PathBuf {
+        match self {
+            Self::File(file) => file.clone(),
+            Self::Package(packaged) => packaged.source_file(),
+        }
+    }
+
+    fn read_source(&self) -> Result<String, Error> {
+        match self {
+            Self::File(file) => {
+                let mut source = String::new();
+                File::open(file)?.read_to_string(&mut source)?;
+                Ok(source)
+            }
+            Self::Package(packaged) => Ok(packaged.source().to_string()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ModuleLoader for BufferedLoader<'_> {
+    fn load(
+        &mut self,
+        name: &Identifier,
+        _from: Option<FileId>,
+        _cache: &mut impl ModuleStore,
+        _recursive: bool,
+    ) -> Result<Identifier, Error> {
+        // A buffered loader is only used to report, it never loads new modules.
+        Err(module_not_found(name).into())
+    }
+
+    fn resolver(&self) -> &impl ModuleResolver {
+        &self.loader().resolver
+    }
+
+    fn get_file_id(&self, name: &Identifier) -> Option<FileId> {
+        self.loader().get_file_id(name)
+    }
+
+    fn get_source(&self, file_id: FileId) -> Option<Source> {
+        self.loader().get_source(file_id)
+    }
+
+    fn report(&self, diagnostic: &Diagnostic) -> Result<(), Error> {
+        self.diagnostics.borrow_mut().push(diagnostic.clone());
+        Ok(())
+    }
+
+    fn reporter_done(&self, top_module_name: Option<String>) -> Result<ReportCounters, Error> {
+        self.loader().reporter_done(top_module_name)
+    }
+
+    fn set_severity_filter(&mut self, _filter: SeverityFilter) {}
+}
+
+impl BufferedLoader<'_> {
+    fn loader(&self) -> &FsModuleLoader {
+        self.loader
+            .expect("a buffered loader must be created with a loader")
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+// Parse the source, or fetch the already parsed module from `parsed_cache`. Modules are only
+// added to the parsed cache if they produced no diagnostics, otherwise the diagnostics would be
+// lost on the next load.
+fn parse_or_fetch(
+    file_id: FileId,
+    source: &Source,
+    parsed_cache: Option<&ParsedModuleCache>,
+) -> (Result<Module, Error>, Vec<Diagnostic>) {
+    if let Some(parsed_cache) = parsed_cache {
+        let cache_key = parsed_cache.key_for(source.as_ref());
+        if let Some(mut module) = parsed_cache.get(&cache_key) {
+            debug!("using cached module {}", module.name());
+            module.set_file_id(file_id);
+            (Ok(module), Vec::default())
+        } else {
+            let (result, diagnostics) = parse_str(file_id, source);
+            if let Ok(module) = &result {
+                if diagnostics.is_empty() {
+                    if let Err(e) = parsed_cache.put(&cache_key, module) {
+                        warn!(
+                            "Could not add module {} to cache, error: {e}",
+                            module.name()
+                        );
+                    }
+                }
+            }
+            (result, diagnostics)
+        }
+    } else {
+        parse_str(file_id, source)
+    }
+}
+
+// Library definitions, RDF and type class definitions, are only allowed in library modules. This
+// is checked once the module has been parsed so that the store decides which modules are library
+// modules.
+fn check_library_definitions(
+    module: &mut Module,
+    file_id: FileId,
+    cache: &impl ModuleStore,
+) -> Result<(), Error> {
+    if cache.is_library_module(module.name()) {
+        module.set_library_module(true);
+    } else if let Some(definition) = module
+        .body()
+        .definitions()
+        .find(|defn| matches!(defn, Definition::Rdf(_) | Definition::TypeClass(_)))
+    {
+        return Err(library_definition_not_allowed(
+            file_id,
+            definition.source_span().map(|span| span.into()),
+            definition.name(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+// Apply `f` to each item using as many threads as are useful, the results are returned in the
+// same order as `items`.
+fn parallel_map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len());
+    if threads <= 1 {
+        items.iter().map(f).collect()
+    } else {
+        let chunk_size = items.len().div_ceil(threads);
+        let f = &f;
+        std::thread::scope(|scope| {
+            let handles = items
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("parallel task panicked"))
+                .collect()
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
 impl ModuleCatalog {
     ///
     /// Load a resolver catalog file from the current directory.
@@ -453,9 +931,8 @@ impl ModuleCatalog {
     fn load_from_file(file: &Path) -> Option<Self> {
         trace!("ModuleCatalog::load_from_file({file:?})");
         match std::fs::read_to_string(file) {
-            Ok(source) => match serde_json::from_str::<ModuleCatalog>(&source) {
-                Ok(mut catalog) => {
-                    catalog.loaded_from = file.parent().unwrap().to_path_buf();
+            Ok(source) => match Self::load_from_str(&source, file.parent().unwrap()) {
+                Ok(catalog) => {
                     info!("Loaded catalog, file: {file:?}");
                     Some(catalog)
                 }
@@ -471,6 +948,29 @@ impl ModuleCatalog {
         }
     }
 
+    ///
+    /// Parse a resolver catalog from `source`, any relative paths are resolved against the
+    /// directory `loaded_from`.
+    ///
+    pub fn load_from_str(source: &str, loaded_from: &Path) -> Result<Self, Error> {
+        trace!("ModuleCatalog::load_from_str(..., {loaded_from:?})");
+        let mut catalog = serde_json::from_str::<ModuleCatalog>(source)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        catalog.loaded_from = loaded_from.to_path_buf();
+        Ok(catalog)
+    }
+
+    ///
+    /// Create a new, empty, catalog with the provided `base` URL.
+    ///
+    pub fn new(base: Url) -> Self {
+        Self {
+            base,
+            loaded_from: Default::default(),
+            entries: Default::default(),
+        }
+    }
+
     // --------------------------------------------------------------------------------------------
 
     pub fn base(&self) -> &Url {
@@ -513,6 +1013,10 @@ impl ModuleCatalog {
         self.entries.values()
     }
 
+    pub fn add_entry(&mut self, key: String, entry: CatalogEntry) -> Option<CatalogEntry> {
+        self.entries.insert(key, entry)
+    }
+
     // --------------------------------------------------------------------------------------------
 
     pub fn groups(&self) -> impl Iterator<Item = (&String, &Group)> {
@@ -661,6 +1165,18 @@ impl Group {
 }
 
 impl Item {
+    pub fn new<S>(relative_url: S, relative_path: PathBuf) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            relative_url: relative_url.into(),
+            relative_path,
+        }
+    }
+
+    // --------------------------------------------------------------------------------------------
+
     pub fn relative_path(&self) -> &PathBuf {
         &self.relative_path
     }
@@ -0,0 +1,556 @@
+/*!
+This module provides support for *packages*, archive files that contain a set of modules and a
+catalog file describing them.
+
+A package is either a zip file or a tar file (optionally gzip compressed) that contains one or
+more SDML source files and, at its root, an `sdml-catalog.json` file. Packages may be added to
+the search list of an [`FsModuleResolver`](crate::load::FsModuleResolver) so that modules are
+loaded directly from the archive without being unpacked.
+
+# Example
+
+```rust,no_run
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_core::load::ModuleLoader;
+use sdml_parse::load::{FsModuleLoader, FsModuleResolver};
+use sdml_parse::package::ModulePackage;
+use std::path::Path;
+use std::str::FromStr;
+
+let mut resolver = FsModuleResolver::default();
+resolver.append_package(ModulePackage::open(Path::new("rentals.zip")).unwrap());
+
+let mut cache = InMemoryModuleCache::default().with_stdlib();
+let mut loader = FsModuleLoader::default().with_resolver(resolver);
+
+let name = Identifier::from_str("rentals").unwrap();
+let module_name = loader.load(&name, None, &mut cache, true);
+assert!(module_name.is_ok());
+```
+
+*/
+
+use crate::load::{
+    CatalogEntry, Item, ModuleCatalog, SDML_CATALOG_FILE_NAME, SDML_FILE_EXTENSION,
+    SDML_FILE_EXTENSION_LONG,
+};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
+use sdml_core::store::ModuleStore;
+use sdml_errors::diagnostics::functions::module_not_found;
+use sdml_errors::Error;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tracing::{info, trace, warn};
+use url::Url;
+use zip::write::SimpleFileOptions;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The archive formats supported for module packages.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PackageFormat {
+    /// A zip archive, with the extension `.zip`.
+    #[default]
+    Zip,
+    /// An uncompressed tar archive, with the extension `.tar`.
+    Tar,
+    /// A gzip compressed tar archive, with the extension `.tar.gz` or `.tgz`.
+    TarGz,
+}
+
+///
+/// A package that has been read into memory, all SDML source files and the package catalog are
+/// retained so that modules can be loaded without further access to the archive.
+///
+#[derive(Clone, Debug)]
+pub struct ModulePackage {
+    path: PathBuf,
+    format: PackageFormat,
+    catalog: Option<ModuleCatalog>,
+    files: HashMap<PathBuf, String>,
+}
+
+///
+/// A module source as found in a package.
+///
+#[derive(Clone, Debug)]
+pub struct PackagedModule {
+    package_path: PathBuf,
+    inner_path: PathBuf,
+    source: String,
+    base_uri: Option<Url>,
+}
+
+///
+/// A builder used to construct a new package from a set of modules and their sources.
+///
+#[derive(Clone, Debug)]
+pub struct PackageBuilder {
+    catalog: ModuleCatalog,
+    files: BTreeMap<PathBuf, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for PackageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Zip => "zip",
+                Self::Tar => "tar",
+                Self::TarGz => "tar.gz",
+            }
+        )
+    }
+}
+
+impl FromStr for PackageFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zip" => Ok(Self::Zip),
+            "tar" => Ok(Self::Tar),
+            "tar.gz" | "tgz" => Ok(Self::TarGz),
+            _ => Err(unsupported_format(s)),
+        }
+    }
+}
+
+impl PackageFormat {
+    ///
+    /// Determine the package format from the extension of the file at `path`, returning `None` if
+    /// the extension is not one of the supported formats.
+    ///
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_string_lossy().to_lowercase();
+        if file_name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if file_name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `true` if the file at `path` has the extension of a supported package format.
+    ///
+    pub fn is_package_file(path: &Path) -> bool {
+        path.is_file() && Self::from_path(path).is_some()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ModulePackage {
+    ///
+    /// Open and read the package file at `path`, the format is determined by the file's extension.
+    ///
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        trace!("ModulePackage::open({path:?})");
+        let format = PackageFormat::from_path(path)
+            .ok_or_else(|| unsupported_format(path.to_string_lossy()))?;
+        let file = File::open(path)?;
+        Self::from_reader(file, format, path)
+    }
+
+    ///
+    /// Read a package of the given `format` from `reader`; `path` is used to identify the package
+    /// in diagnostics and module source file names.
+    ///
+    pub fn from_reader<R>(reader: R, format: PackageFormat, path: &Path) -> Result<Self, Error>
+    where
+        R: Read + Seek,
+    {
+        let mut files: HashMap<PathBuf, String> = Default::default();
+        match format {
+            PackageFormat::Zip => {
+                let mut archive = zip::ZipArchive::new(reader).map_err(std::io::Error::from)?;
+                for i in 0..archive.len() {
+                    let mut entry = archive.by_index(i).map_err(std::io::Error::from)?;
+                    if entry.is_file() {
+                        if let Some(entry_path) = entry.enclosed_name() {
+                            if is_package_member(&entry_path) {
+                                let mut source = String::new();
+                                entry.read_to_string(&mut source)?;
+                                files.insert(entry_path, source);
+                            }
+                        }
+                    }
+                }
+            }
+            PackageFormat::Tar => read_tar(tar::Archive::new(reader), &mut files)?,
+            PackageFormat::TarGz => {
+                read_tar(tar::Archive::new(GzDecoder::new(reader)), &mut files)?
+            }
+        }
+
+        let catalog = match files.get(&PathBuf::from(SDML_CATALOG_FILE_NAME)) {
+            Some(source) => Some(ModuleCatalog::load_from_str(source, Path::new(""))?),
+            None => {
+                warn!("Package {path:?} does not contain a catalog file");
+                None
+            }
+        };
+
+        info!("Loaded package, file: {path:?}, members: {}", files.len());
+        Ok(Self {
+            path: path.to_path_buf(),
+            format,
+            catalog,
+            files,
+        })
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn format(&self) -> PackageFormat {
+        self.format
+    }
+
+    pub fn catalog(&self) -> Option<&ModuleCatalog> {
+        self.catalog.as_ref()
+    }
+
+    pub fn file_names(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.keys()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Returns `true` if this package contains the source for the module named `name`.
+    ///
+    pub fn contains_module(&self, name: &Identifier) -> bool {
+        self.module_path(name).is_some()
+    }
+
+    ///
+    /// Return the path, within the package, of the source for the module named `name`. The
+    /// package catalog is consulted first and then the same naming conventions used by the
+    /// file-system resolver.
+    ///
+    pub fn module_path(&self, name: &Identifier) -> Option<PathBuf> {
+        trace!("ModulePackage::module_path({name})");
+        let name = name.to_string();
+        if let Some(path) = self
+            .catalog
+            .as_ref()
+            .and_then(|catalog| catalog.resolve_local_path(&name))
+        {
+            let path = normalize_path(&path);
+            if self.files.contains_key(&path) {
+                return Some(path);
+            } else {
+                warn!("Package catalog entry for {name} refers to missing file {path:?}");
+            }
+        }
+        [
+            format!("{name}.{SDML_FILE_EXTENSION}"),
+            format!("{name}/{name}.{SDML_FILE_EXTENSION}"),
+            format!("{name}.{SDML_FILE_EXTENSION_LONG}"),
+            format!("{name}/{name}.{SDML_FILE_EXTENSION_LONG}"),
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .find(|path| self.files.contains_key(path))
+    }
+
+    ///
+    /// Return the source, and associated location information, for the module named `name`.
+    ///
+    pub fn module_source(&self, name: &Identifier) -> Option<PackagedModule> {
+        self.module_path(name).map(|inner_path| PackagedModule {
+            package_path: self.path.clone(),
+            source: self.files.get(&inner_path).cloned().unwrap_or_default(),
+            inner_path,
+            base_uri: self
+                .catalog
+                .as_ref()
+                .and_then(|catalog| catalog.resolve_uri(&name.to_string())),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl PackagedModule {
+    pub fn package_path(&self) -> &PathBuf {
+        &self.package_path
+    }
+
+    pub fn inner_path(&self) -> &PathBuf {
+        &self.inner_path
+    }
+
+    pub fn source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    pub fn base_uri(&self) -> Option<&Url> {
+        self.base_uri.as_ref()
+    }
+
+    ///
+    /// Returns a path used to identify the packaged module as a file; this is the path of the
+    /// package joined with the path of the source within it.
+    ///
+    pub fn source_file(&self) -> PathBuf {
+        self.package_path.join(&self.inner_path)
+    }
+
+    ///
+    /// Returns a URL for the packaged module, this is the URL of the package file with the path
+    /// of the source within it as fragment.
+    ///
+    pub fn to_url(&self) -> Result<Url, Error> {
+        let package_path = self.package_path.canonicalize()?;
+        let mut url =
+            Url::from_file_path(package_path).map_err(|_| Error::UrlParseError { source: None })?;
+        url.set_fragment(Some(&self.inner_path.to_string_lossy()));
+        Ok(url)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl PackageBuilder {
+    ///
+    /// Create a new, empty, package builder; `base` is used as the base URL of the package's
+    /// catalog.
+    ///
+    pub fn new(base: Url) -> Self {
+        Self {
+            catalog: ModuleCatalog::new(base),
+            files: Default::default(),
+        }
+    }
+
+    ///
+    /// Add a single module, and its `source`, to the package. The module source is stored as
+    /// `{name}.sdm` and an entry is added to the catalog using the module's base URI if present.
+    ///
+    pub fn add_module(&mut self, module: &Module, source: &str) {
+        trace!("PackageBuilder::add_module({})", module.name());
+        let name = module.name().to_string();
+        let relative_path = PathBuf::from(format!("{name}.{SDML_FILE_EXTENSION}"));
+        let relative_url = module
+            .base_uri()
+            .map(|uri| uri.value().to_string())
+            .unwrap_or_else(|| format!("{name}#"));
+        self.catalog.add_entry(
+            name,
+            CatalogEntry::from(Item::new(relative_url, relative_path.clone())),
+        );
+        self.files.insert(relative_path, source.to_string());
+    }
+
+    ///
+    /// Add the module named `name` and all of its transitive, non-library, imports to the
+    /// package. All modules must be present in `store` and their sources available from `loader`.
+    ///
+    pub fn add_module_closure(
+        &mut self,
+        name: &Identifier,
+        store: &impl ModuleStore,
+        loader: &impl ModuleLoader,
+    ) -> Result<(), Error> {
+        let mut done: HashSet<Identifier> = Default::default();
+        let mut todo: Vec<Identifier> = vec![name.clone()];
+        while let Some(name) = todo.pop() {
+            if done.contains(&name) || store.is_library_module(&name) {
+                continue;
+            }
+            let module = store
+                .get(&name)
+                .ok_or_else(|| -> Error { module_not_found(&name).into() })?;
+            let source = loader
+                .get_source_by_name(&name)
+                .ok_or_else(|| -> Error { module_not_found(&name).into() })?;
+            self.add_module(module, source.as_ref());
+            todo.extend(module.imported_modules().into_iter().cloned());
+            done.insert(name);
+        }
+        Ok(())
+    }
+
+    pub fn catalog(&self) -> &ModuleCatalog {
+        &self.catalog
+    }
+
+    pub fn file_names(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.keys()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Write the package to the file at `path`, the format is determined by the file's extension.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> Result<(), Error> {
+        let format = PackageFormat::from_path(path)
+            .ok_or_else(|| unsupported_format(path.to_string_lossy()))?;
+        let mut file = File::create(path)?;
+        self.write(&mut file, format)
+    }
+
+    ///
+    /// Write the package, in the given `format`, to `writer`.
+    ///
+    pub fn write<W>(&self, writer: &mut W, format: PackageFormat) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        let catalog = serde_json::to_string_pretty(&self.catalog)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let members: Vec<(PathBuf, &String)> =
+            std::iter::once((PathBuf::from(SDML_CATALOG_FILE_NAME), &catalog))
+                .chain(self.files.iter().map(|(k, v)| (k.clone(), v)))
+                .collect();
+        match format {
+            PackageFormat::Zip => {
+                let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+                let options = SimpleFileOptions::default();
+                for (path, content) in &members {
+                    archive
+                        .start_file_from_path(path, options)
+                        .map_err(std::io::Error::from)?;
+                    archive.write_all(content.as_bytes())?;
+                }
+                let buffer = archive.finish().map_err(std::io::Error::from)?;
+                writer.write_all(buffer.get_ref())?;
+            }
+            PackageFormat::Tar => {
+                let mut archive = tar::Builder::new(writer);
+                write_tar(&mut archive, &members)?;
+                archive.finish()?;
+            }
+            PackageFormat::TarGz => {
+                let mut archive = tar::Builder::new(GzEncoder::new(writer, Compression::default()));
+                write_tar(&mut archive, &members)?;
+                archive.into_inner()?.finish()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn unsupported_format<S>(s: S) -> Error
+where
+    S: Display,
+{
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("not a supported package format: {s}"),
+    )
+    .into()
+}
+
+fn is_package_member(path: &Path) -> bool {
+    path == Path::new(SDML_CATALOG_FILE_NAME)
+        || path
+            .extension()
+            .map(|ext| ext == SDML_FILE_EXTENSION || ext == SDML_FILE_EXTENSION_LONG)
+            .unwrap_or_default()
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|c| !matches!(c, std::path::Component::CurDir))
+        .collect()
+}
+
+fn read_tar<R>(
+    mut archive: tar::Archive<R>,
+    files: &mut HashMap<PathBuf, String>,
+) -> Result<(), Error>
+where
+    R: Read,
+{
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.header().entry_type().is_file() {
+            let entry_path = normalize_path(&entry.path()?);
+            if is_package_member(&entry_path) {
+                let mut source = String::new();
+                entry.read_to_string(&mut source)?;
+                files.insert(entry_path, source);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_tar<W>(archive: &mut tar::Builder<W>, members: &[(PathBuf, &String)]) -> Result<(), Error>
+where
+    W: Write,
+{
+    for (path, content) in members {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive.append_data(&mut header, path, content.as_bytes())?;
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            PackageFormat::from_path(Path::new("a/b.zip")),
+            Some(PackageFormat::Zip)
+        );
+        assert_eq!(
+            PackageFormat::from_path(Path::new("b.tar")),
+            Some(PackageFormat::Tar)
+        );
+        assert_eq!(
+            PackageFormat::from_path(Path::new("b.tar.gz")),
+            Some(PackageFormat::TarGz)
+        );
+        assert_eq!(
+            PackageFormat::from_path(Path::new("b.TGZ")),
+            Some(PackageFormat::TarGz)
+        );
+        assert_eq!(PackageFormat::from_path(Path::new("b.sdm")), None);
+    }
+}
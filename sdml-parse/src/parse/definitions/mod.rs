@@ -1,16 +1,14 @@
 use super::ParseContext;
 use crate::parse::annotations::parse_annotation;
 use sdml_core::error::Error;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::annotations::{AnnotationOnlyBody, HasAnnotations};
 use sdml_core::model::definitions::Definition;
-use sdml_core::model::{HasName, HasSourceSpan};
+use sdml_core::model::HasSourceSpan;
 use sdml_core::syntax::{
     NODE_KIND_ANNOTATION, NODE_KIND_DATA_TYPE_DEF, NODE_KIND_ENTITY_DEF, NODE_KIND_ENUM_DEF,
     NODE_KIND_EVENT_DEF, NODE_KIND_LINE_COMMENT, NODE_KIND_PROPERTY_DEF, NODE_KIND_RDF_DEF,
     NODE_KIND_STRUCTURE_DEF, NODE_KIND_TYPE_CLASS_DEF, NODE_KIND_UNION_DEF,
 };
-use sdml_errors::diagnostics::functions::library_definition_not_allowed;
 use tree_sitter::TreeCursor;
 
 // ------------------------------------------------------------------------------------------------
@@ -49,30 +47,10 @@ pub(crate) fn parse_definition<'a>(
                     return Ok(parse_union_def(context, &mut node.walk())?.into());
                 }
                 NODE_KIND_RDF_DEF => {
-                    let rdf_def = parse_rdf_def(context, &mut node.walk())?;
-                    return if context.is_library {
-                        Ok(rdf_def.into())
-                    } else {
-                        Err(library_definition_not_allowed(
-                            context.file_id,
-                            rdf_def.source_span().map(|s| s.into()),
-                            rdf_def.name(),
-                        )
-                        .into())
-                    };
+                    return Ok(parse_rdf_def(context, &mut node.walk())?.into());
                 }
                 NODE_KIND_TYPE_CLASS_DEF => {
-                    let type_class = parse_type_class_def(context, &mut node.walk())?;
-                    return if context.is_library {
-                        Ok(type_class.into())
-                    } else {
-                        Err(library_definition_not_allowed(
-                            context.file_id,
-                            type_class.source_span().map(|s| s.into()),
-                            type_class.name(),
-                        )
-                        .into())
-                    };
+                    return Ok(parse_type_class_def(context, &mut node.walk())?.into());
                 }
                 NODE_KIND_LINE_COMMENT => {}
                 _ => {
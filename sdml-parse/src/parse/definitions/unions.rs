@@ -3,7 +3,6 @@ use crate::parse::definitions::parse_annotation_only_body;
 use crate::parse::identifiers::{parse_identifier, parse_identifier_reference};
 use crate::parse::ParseContext;
 use sdml_core::error::Error;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::annotations::HasAnnotations;
 use sdml_core::model::definitions::{HasVariants, TypeVariant, UnionBody, UnionDef};
 use sdml_core::model::{HasOptionalBody, HasSourceSpan};
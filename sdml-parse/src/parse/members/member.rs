@@ -3,7 +3,6 @@ use crate::parse::identifiers::{parse_identifier, parse_identifier_reference};
 use crate::parse::members::{parse_cardinality_expression, parse_type_reference};
 use crate::parse::ParseContext;
 use sdml_core::error::Error;
-use sdml_core::load::ModuleLoader;
 use sdml_core::model::identifiers::IdentifierReference;
 use sdml_core::model::members::{Member, MemberDef};
 use sdml_core::model::{HasOptionalBody, HasSourceSpan};
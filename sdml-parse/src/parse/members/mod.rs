@@ -1,6 +1,5 @@
 use crate::parse::identifiers::{parse_identifier, parse_identifier_reference};
 use crate::parse::ParseContext;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
 use sdml_core::model::members::{Cardinality, MappingType, Ordering, TypeReference, Uniqueness};
 use sdml_core::model::HasSourceSpan;
@@ -9,9 +9,7 @@ YYYYY
 
 */
 
-use crate::load::FsModuleLoader;
 use crate::parse::modules::parse_module;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::identifiers::Identifier;
 use sdml_core::model::modules::{Import, Module};
 use sdml_core::model::HasSourceSpan;
@@ -21,11 +19,13 @@ use sdml_errors::diagnostics::functions::{
     duplicate_variant, found_error_node,
 };
 use sdml_errors::Error;
-use sdml_errors::{FileId, Source};
+use sdml_errors::{Diagnostic, FileId, Source};
+use std::cell::RefCell;
 use std::collections::HashSet;
 use tracing::trace;
 use tree_sitter::Node;
 use tree_sitter::Parser;
+use tree_sitter::Tree;
 use tree_sitter_sdml::language;
 
 // ------------------------------------------------------------------------------------------------
@@ -43,26 +43,33 @@ mod macros;
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// This should only be called by `ModuleLoader`
-pub(crate) fn parse_str(file_id: FileId, loader: &FsModuleLoader) -> Result<Module, Error> {
+// This should only be called by `ModuleLoader`, the diagnostics produced while parsing are
+// returned, rather than reported, so that parsing may happen on any thread.
+pub(crate) fn parse_str(
+    file_id: FileId,
+    source: &Source,
+) -> (Result<Module, Error>, Vec<Diagnostic>) {
     trace!("parse_str({file_id}, ...)");
-    let file_cache = loader.files();
-    let source = file_cache.get(file_id).unwrap().source();
     let mut parser = Parser::new();
     parser
         .set_language(&language())
         .expect("Error loading SDML grammar");
 
     let tree = parser.parse(source, None).unwrap();
-    let node = tree.root_node();
 
-    let mut context = ParseContext::new(file_id, loader);
+    let mut context = ParseContext::new(file_id, source);
+    let result = parse_tree(&mut context, &tree);
+    (result, context.diagnostics.into_inner())
+}
+
+fn parse_tree<'a>(context: &mut ParseContext<'a>, tree: &'a Tree) -> Result<Module, Error> {
+    let node = tree.root_node();
     context.check_if_error(&node, "module")?;
 
     if node.kind() == NODE_KIND_MODULE {
         let mut cursor = tree.walk();
-        let mut module = parse_module(&mut context, &mut cursor)?;
-        module.set_file_id(file_id);
+        let mut module = parse_module(context, &mut cursor)?;
+        module.set_file_id(context.file_id);
         Ok(module)
     } else {
         unexpected_node!(context, "parse_str", node, NODE_KIND_MODULE);
@@ -79,10 +86,9 @@ pub(crate) fn parse_str(file_id: FileId, loader: &FsModuleLoader) -> Result<Modu
 
 #[derive(Debug)]
 pub(crate) struct ParseContext<'a> {
-    loader: &'a FsModuleLoader,
     file_id: FileId,
-    source: Source,
-    is_library: bool,
+    source: &'a Source,
+    diagnostics: RefCell<Vec<Diagnostic>>,
     module: Option<Identifier>,
     imports: HashSet<Import>,
     type_names: HashSet<Identifier>,
@@ -94,15 +100,12 @@ pub(crate) struct ParseContext<'a> {
 // ------------------------------------------------------------------------------------------------
 
 impl<'a> ParseContext<'a> {
-    fn new(file_id: FileId, loader: &'a FsModuleLoader) -> Self {
-        let file_cache = loader.files();
-        let file = file_cache.get(file_id).unwrap();
+    fn new(file_id: FileId, source: &'a Source) -> Self {
         Self {
             file_id,
-            loader,
-            source: file.source().clone(),
+            source,
+            diagnostics: Default::default(),
             module: Default::default(),
-            is_library: false,
             imports: Default::default(),
             type_names: Default::default(),
             member_names: Default::default(),
@@ -113,10 +116,14 @@ impl<'a> ParseContext<'a> {
         Ok(node.utf8_text(self.source.as_ref())?)
     }
 
+    fn report(&self, diagnostic: &Diagnostic) {
+        self.diagnostics.borrow_mut().push(diagnostic.clone());
+    }
+
     fn check_if_error(&self, node: &Node<'a>, rule: &str) -> Result<(), Error> {
         if node.is_error() {
             let diagnostic = found_error_node(self.file_id, node.byte_range(), rule);
-            self.loader.report(&diagnostic).unwrap();
+            self.report(&diagnostic);
             Err(diagnostic.into())
         } else {
             Ok(())
@@ -138,7 +145,7 @@ impl<'a> ParseContext<'a> {
                     import.source_span().unwrap().byte_range(),
                 )
             };
-            self.loader.report(&diagnostic).unwrap();
+            self.report(&diagnostic);
         } else {
             self.imports.insert(import.clone());
         }
@@ -152,7 +159,7 @@ impl<'a> ParseContext<'a> {
                 name.source_span().unwrap().byte_range(),
                 type_defn.source_span().unwrap().byte_range(),
             );
-            self.loader.report(&diagnostic).unwrap();
+            self.report(&diagnostic);
         } else {
             self.type_names.insert(name.clone());
         }
@@ -166,7 +173,7 @@ impl<'a> ParseContext<'a> {
                 member.source_span().unwrap().byte_range(),
                 name.source_span().unwrap().byte_range(),
             );
-            self.loader.report(&diagnostic).unwrap();
+            self.report(&diagnostic);
         } else {
             self.member_names.insert(name.clone());
         }
@@ -180,7 +187,7 @@ impl<'a> ParseContext<'a> {
                 member.source_span().unwrap().byte_range(),
                 name.source_span().unwrap().byte_range(),
             );
-            self.loader.report(&diagnostic).unwrap();
+            self.report(&diagnostic);
         } else {
             self.member_names.insert(name.clone());
         }
@@ -5,9 +5,7 @@ use crate::parse::annotations::parse_annotation;
 use crate::parse::definitions::parse_definition;
 use crate::parse::identifiers::{parse_identifier, parse_qualified_identifier};
 use sdml_core::error::Error;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::annotations::HasAnnotations;
-use sdml_core::model::identifiers::Identifier;
 use sdml_core::model::modules::{HeaderValue, Import, ImportStatement, ModuleImport};
 use sdml_core::model::modules::{Module, ModuleBody};
 use sdml_core::model::HasSourceSpan;
@@ -41,7 +39,6 @@ pub(crate) fn parse_module<'a>(
     );
     let name = parse_identifier(context, &child)?;
     context.module = Some(name.clone());
-    context.is_library = Identifier::is_library_module_name(&name);
 
     let child = node_field_named!(
         context,
@@ -98,7 +95,9 @@ fn parse_module_body<'a>(
     rule_fn!("module_body", cursor.node());
 
     let mut body = ModuleBody::default().with_source_span(cursor.node().into());
-    body.set_library_status(&context.module.clone().unwrap());
+    // Library definitions are accepted by the parser, the loader checks them once the module
+    // is parsed as only the module store knows which modules are library modules.
+    body.set_library(true);
 
     for node in cursor.node().named_children(cursor) {
         context.check_if_error(&node, RULE_NAME)?;
@@ -1,6 +1,5 @@
 use crate::parse::identifiers::parse_identifier_reference;
 use rust_decimal::Decimal;
-use sdml_core::load::ModuleLoader as ModuleLoaderTrait;
 use sdml_core::model::values::{
     Binary, LanguageString, LanguageTag, MappingValue, SequenceOfValues, SimpleValue, Value,
     ValueConstructor,
@@ -0,0 +1,369 @@
+/*!
+This module provides a [`Workspace`], a set of modules discovered from one or more root
+directories and/or catalog files, loaded into a single store.
+
+While the [`FsModuleLoader`] is built around loading a single module and its imports, a workspace
+represents an entire model repository. All SDML source files found under each root directory,
+and all the modules listed in each catalog, become *members* of the workspace. Members are
+loaded, with their imports, into one [`InMemoryModuleCache`] and the workspace then provides
+queries across all members.
+
+# Example
+
+```rust,no_run
+use sdml_core::model::HasName;
+use sdml_parse::Workspace;
+use std::path::Path;
+
+let mut workspace = Workspace::default().with_root(Path::new("models"));
+workspace.load().unwrap();
+
+for module in workspace.modules() {
+    println!("{}", module.name());
+}
+
+workspace.validate(false).unwrap();
+```
+
+*/
+
+use crate::load::{FsModuleLoader, ModuleCatalog, SDML_FILE_EXTENSION, SDML_FILE_EXTENSION_LONG};
+use sdml_core::model::definitions::Definition;
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::model::modules::Module;
+use sdml_core::model::{HasBody, HasName};
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::Error;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{trace, warn};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A set of modules discovered under a set of roots or catalogs and loaded into one store, see
+/// the [module documentation](self) for details.
+///
+#[derive(Debug)]
+pub struct Workspace {
+    roots: Vec<PathBuf>,
+    catalogs: Vec<ModuleCatalog>,
+    loader: FsModuleLoader,
+    store: InMemoryModuleCache,
+    members: Vec<Identifier>,
+    failed: Vec<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new(FsModuleLoader::default())
+    }
+}
+
+impl Workspace {
+    // --------------------------------------------------------------------------------------------
+    // Workspace :: Constructors
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Create a new, empty, workspace that will use `loader` to load modules.
+    ///
+    pub fn new(loader: FsModuleLoader) -> Self {
+        Self {
+            roots: Default::default(),
+            catalogs: Default::default(),
+            loader,
+            store: InMemoryModuleCache::default().with_stdlib(),
+            members: Default::default(),
+            failed: Default::default(),
+        }
+    }
+
+    ///
+    /// Builder-like function to add a root directory to this workspace.
+    ///
+    pub fn with_root(self, root: &Path) -> Self {
+        let mut self_mut = self;
+        self_mut.add_root(root);
+        self_mut
+    }
+
+    ///
+    /// Builder-like function to add a catalog to this workspace.
+    ///
+    pub fn with_catalog(self, catalog: ModuleCatalog) -> Self {
+        let mut self_mut = self;
+        self_mut.add_catalog(catalog);
+        self_mut
+    }
+
+    ///
+    /// Builder-like function to use `store` as the store for this workspace, this allows
+    /// library modules to be registered before loading.
+    ///
+    pub fn with_store(self, store: InMemoryModuleCache) -> Self {
+        Self { store, ..self }
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Workspace :: Sources
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Add a root directory, all SDML source files in this directory, and its sub-directories,
+    /// are members of the workspace.
+    ///
+    pub fn add_root(&mut self, root: &Path) {
+        self.loader.resolver_mut().append_to_search_path(root);
+        self.roots.push(root.to_path_buf());
+    }
+
+    ///
+    /// Add a catalog, all modules listed in the catalog are members of the workspace.
+    ///
+    pub fn add_catalog(&mut self, catalog: ModuleCatalog) {
+        self.catalogs.push(catalog);
+    }
+
+    ///
+    /// Add the catalog file at `path`, see [`Self::add_catalog`].
+    ///
+    pub fn add_catalog_file(&mut self, path: &Path) -> Result<(), Error> {
+        match ModuleCatalog::load_from(path, false) {
+            Some(catalog) => {
+                self.add_catalog(catalog);
+                Ok(())
+            }
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("could not load a module catalog from {path:?}"),
+            )
+            .into()),
+        }
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = &PathBuf> {
+        self.roots.iter()
+    }
+
+    pub fn catalogs(&self) -> impl Iterator<Item = &ModuleCatalog> {
+        self.catalogs.iter()
+    }
+
+    ///
+    /// Return the source files for all members of this workspace, this does not load any of
+    /// the files. The result is sorted and contains no duplicates.
+    ///
+    pub fn discover(&self) -> Result<Vec<PathBuf>, Error> {
+        let mut files: BTreeSet<PathBuf> = Default::default();
+        for root in &self.roots {
+            find_source_files(root, &mut files)?;
+        }
+        for catalog in &self.catalogs {
+            let names = catalog
+                .items()
+                .map(|(name, _)| name.clone())
+                .chain(
+                    catalog
+                        .groups()
+                        .flat_map(|(_, group)| group.entries().map(|(name, _)| name.clone())),
+                )
+                .collect::<Vec<_>>();
+            for name in names {
+                match catalog.resolve_local_path(&name) {
+                    Some(path) if path.is_file() => {
+                        files.insert(path);
+                    }
+                    _ => warn!("Catalog module {name} has no local source file"),
+                }
+            }
+        }
+        Ok(files.into_iter().collect())
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Workspace :: Loading
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Discover and load all members of this workspace, and their imports. Files that fail to
+    /// load have their diagnostics reported by the loader and are recorded in
+    /// [`Self::failed_files`], they do not stop the loading of other files. Returns the number
+    /// of members loaded.
+    ///
+    pub fn load(&mut self) -> Result<usize, Error> {
+        trace!("Workspace::load()");
+        let already_loaded = self
+            .store
+            .modules()
+            .filter_map(|module| {
+                module
+                    .source_file()
+                    .and_then(|file| file.canonicalize().ok())
+                    .map(|file| (file, module.name().clone()))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut files = Vec::default();
+        for file in self.discover()? {
+            match file
+                .canonicalize()
+                .ok()
+                .and_then(|file| already_loaded.get(&file))
+            {
+                Some(name) if !self.members.contains(name) => self.members.push(name.clone()),
+                Some(_) => {}
+                None => files.push(file),
+            }
+        }
+
+        let results = self.loader.load_from_files(&files, &mut self.store, true);
+        for (file, result) in files.into_iter().zip(results) {
+            match result {
+                Ok(name) => {
+                    if !self.members.contains(&name) {
+                        self.members.push(name);
+                    }
+                }
+                Err(e) => {
+                    warn!("Could not load workspace file {file:?}, error: {e}");
+                    self.failed.push(file);
+                }
+            }
+        }
+        self.members.sort();
+        Ok(self.members.len())
+    }
+
+    ///
+    /// Return the files that could not be loaded by [`Self::load`].
+    ///
+    pub fn failed_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.failed.iter()
+    }
+
+    pub fn loader(&self) -> &FsModuleLoader {
+        &self.loader
+    }
+
+    pub fn loader_mut(&mut self) -> &mut FsModuleLoader {
+        &mut self.loader
+    }
+
+    ///
+    /// Return the store containing all workspace members and their imports.
+    ///
+    pub fn store(&self) -> &InMemoryModuleCache {
+        &self.store
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Workspace :: Queries
+    // --------------------------------------------------------------------------------------------
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    ///
+    /// Returns `true` if the module named `name` is a member of this workspace.
+    ///
+    pub fn is_member(&self, name: &Identifier) -> bool {
+        self.members.contains(name)
+    }
+
+    ///
+    /// Return the names of all members of this workspace, in name order.
+    ///
+    pub fn module_names(&self) -> impl Iterator<Item = &Identifier> {
+        self.members.iter()
+    }
+
+    ///
+    /// Return all members of this workspace, in name order.
+    ///
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.members.iter().filter_map(|name| self.store.get(name))
+    }
+
+    ///
+    /// Return the member module named `name`, if present.
+    ///
+    pub fn get(&self, name: &Identifier) -> Option<&Module> {
+        if self.is_member(name) {
+            self.store.get(name)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Return all definitions, with the module defining them, across all members.
+    ///
+    pub fn definitions(&self) -> impl Iterator<Item = (&Module, &Definition)> {
+        self.modules()
+            .flat_map(|module| module.body().definitions().map(move |defn| (module, defn)))
+    }
+
+    ///
+    /// Resolve the qualified name `name` to a definition in any module in the store.
+    ///
+    pub fn resolve(&self, name: &QualifiedIdentifier) -> Option<&Definition> {
+        self.store.resolve(name)
+    }
+
+    ///
+    /// Return all members that import the module named `name`, in name order.
+    ///
+    pub fn importers_of<'a>(&'a self, name: &'a Identifier) -> impl Iterator<Item = &'a Module> {
+        self.modules()
+            .filter(move |module| module.imported_modules().contains(name))
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Workspace :: Actions
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Validate all members of this workspace, see [`FsModuleLoader::validate_modules`].
+    ///
+    pub fn validate(&self, check_constraints: bool) -> Result<(), Error> {
+        let modules = self.modules().collect::<Vec<_>>();
+        self.loader
+            .validate_modules(&modules, &self.store, check_constraints)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+// Symbolic links to directories are not followed, so a link cycle under `directory` cannot
+// cause unbounded recursion.
+fn find_source_files(directory: &Path, files: &mut BTreeSet<PathBuf>) -> Result<(), Error> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            find_source_files(&path, files)?;
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext == SDML_FILE_EXTENSION || ext == SDML_FILE_EXTENSION_LONG)
+            .unwrap_or_default()
+        {
+            files.insert(path);
+        }
+    }
+    Ok(())
+}
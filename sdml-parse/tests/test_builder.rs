@@ -0,0 +1,247 @@
+use sdml_core::model::annotations::HasAnnotations;
+use sdml_core::model::builder::ModuleBuilder;
+use sdml_core::model::definitions::{Definition, HasMembers, HasVariants};
+use sdml_core::model::members::{Cardinality, MemberKind, Ordering, Uniqueness};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::LanguageString;
+use sdml_core::model::{HasBody, HasName, HasOptionalBody};
+use sdml_core::store::InMemoryModuleCache;
+use sdml_core::store::ModuleStore;
+use sdml_errors::diagnostics::ErrorCode;
+use sdml_errors::Error;
+use sdml_parse::load::FsModuleLoader;
+
+const SOURCE: &str = r#"module rentals <https://example.com/rentals#> is
+  import [ skos xsd ]
+  datatype vin <- xsd:string
+  property rentedBy -> Customer
+  entity Customer
+  entity Vehicle is
+    @skos:prefLabel = "vehicle"
+    identity id -> vin
+    kind -> VehicleKind
+    tags -> {ordered unique 0..} string
+    ref rentedBy
+  end
+  enum VehicleKind of
+    Car
+    Van
+  end
+  union Party of
+    Customer
+    Vehicle as Asset
+  end
+end
+"#;
+
+fn built() -> Module {
+    ModuleBuilder::new("rentals")
+        .with_base_uri("https://example.com/rentals#")
+        .import_module("skos")
+        .import_module("xsd")
+        .datatype("vin", "xsd:string", |datatype| datatype)
+        .property("rentedBy", "Customer", |member| member)
+        .entity("Customer", |entity| entity)
+        .entity("Vehicle", |entity| {
+            entity
+                .with_annotation("skos:prefLabel", LanguageString::from("vehicle"))
+                .identity("id", "vin")
+                .member("kind", "VehicleKind")
+                .member_with("tags", "string", |member| member.many().ordered().unique())
+                .member_ref("rentedBy")
+        })
+        .enumeration("VehicleKind", |kind| kind.variant("Car").variant("Van"))
+        .union("Party", |party| {
+            party.variant("Customer").variant_as("Vehicle", "Asset")
+        })
+        .build()
+        .unwrap()
+}
+
+fn parsed() -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut SOURCE.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+fn error_code(result: Result<Module, Error>) -> String {
+    match result {
+        Err(Error::LanguageValidationError { source }) => source.code.unwrap(),
+        other => panic!("expected a diagnostic, not {other:?}"),
+    }
+}
+
+#[test]
+fn test_builder_matches_parsed_module() {
+    let built = built();
+    let parsed = parsed();
+    assert_eq!(built, parsed);
+
+    assert_eq!(built.name(), parsed.name());
+    assert_eq!(built.base_uri(), parsed.base_uri());
+    assert_eq!(
+        built.body().imported_modules(),
+        parsed.body().imported_modules()
+    );
+    assert_eq!(
+        built
+            .body()
+            .definitions()
+            .map(|d| d.name().to_string())
+            .collect::<Vec<_>>(),
+        parsed
+            .body()
+            .definitions()
+            .map(|d| d.name().to_string())
+            .collect::<Vec<_>>(),
+    );
+
+    let Some(Definition::Entity(vehicle)) = built.body().definitions().nth(3) else {
+        panic!("expected the Vehicle entity");
+    };
+    let body = vehicle.body().unwrap();
+    assert_eq!(body.identity().name().as_ref(), "id");
+    assert_eq!(body.annotations_len(), 1);
+    let members = body.members().collect::<Vec<_>>();
+    assert_eq!(members.len(), 3);
+    let MemberKind::Definition(tags) = members[1].kind() else {
+        panic!("expected a member definition");
+    };
+    assert_eq!(tags.target_type().to_string(), "sdml:string");
+    assert_eq!(
+        tags.target_cardinality(),
+        &Cardinality::new_unbounded(0, Some(Ordering::Ordered), Some(Uniqueness::Unique))
+    );
+    assert!(matches!(members[2].kind(), MemberKind::Reference(_)));
+
+    let Some(Definition::Union(party)) = built.body().definitions().nth(5) else {
+        panic!("expected the Party union");
+    };
+    assert_eq!(
+        party
+            .body()
+            .unwrap()
+            .variants()
+            .map(|v| v.name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["Customer", "Asset"]
+    );
+}
+
+#[test]
+fn test_builder_invalid_identifiers() {
+    assert_eq!(
+        error_code(ModuleBuilder::new("not valid").build()),
+        ErrorCode::InvalidIdentifier.to_string()
+    );
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .structure("Address", |s| s.member("street-name", "string"))
+                .build()
+        ),
+        ErrorCode::InvalidIdentifier.to_string()
+    );
+    assert!(matches!(
+        ModuleBuilder::new("rentals")
+            .with_base_uri("not a url")
+            .build(),
+        Err(Error::UrlParseError { .. })
+    ));
+}
+
+#[test]
+fn test_builder_duplicate_names() {
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .structure("Address", |s| s)
+                .entity("Address", |e| e)
+                .build()
+        ),
+        ErrorCode::DuplicateDefinitionName.to_string()
+    );
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .entity("Vehicle", |e| e
+                    .identity("id", "string")
+                    .member("id", "integer"))
+                .build()
+        ),
+        ErrorCode::DuplicateMemberName.to_string()
+    );
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .enumeration("Kind", |e| e.variant("Car").variant("Car"))
+                .build()
+        ),
+        ErrorCode::DuplicateVariantName.to_string()
+    );
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .import_module("xsd")
+                .import_module("xsd")
+                .build()
+        ),
+        ErrorCode::DuplicateModuleImport.to_string()
+    );
+}
+
+#[test]
+fn test_builder_unresolved_references() {
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .structure("Address", |s| s.member("city", "City"))
+                .build()
+        ),
+        ErrorCode::TypeDefinitionNotFound.to_string()
+    );
+    assert_eq!(
+        error_code(
+            ModuleBuilder::new("rentals")
+                .datatype("code", "xsd:string", |d| d)
+                .build()
+        ),
+        ErrorCode::ImportedModuleNotFound.to_string()
+    );
+    assert!(ModuleBuilder::new("rentals")
+        .import_member("places:City")
+        .structure("Address", |s| s.member("city", "City"))
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_builder_cardinality_ranges() {
+    let module = ModuleBuilder::new("rentals")
+        .structure("Address", |s| {
+            s.member_with("city", "string", |m| m.cardinality(1, Some(1)))
+        })
+        .build()
+        .unwrap();
+    let Some(Definition::Structure(address)) = module.body().definitions().next() else {
+        panic!("expected the Address structure");
+    };
+    let members = address.body().unwrap().members().collect::<Vec<_>>();
+    let MemberKind::Definition(city) = members[0].kind() else {
+        panic!("expected a member definition");
+    };
+    assert_eq!(city.target_cardinality(), &Cardinality::one());
+
+    for (min, max) in [(2, 1), (0, 0)] {
+        assert!(matches!(
+            ModuleBuilder::new("rentals")
+                .structure("Address", |s| s
+                    .member_with("city", "string", |m| m.cardinality(min, Some(max))))
+                .build(),
+            Err(Error::GeneratorError { .. })
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::definitions::RdfDef;
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::modules::{Module, ModuleBody};
+use sdml_core::model::{HasBody, HasName};
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_parse::load::FsModuleLoader;
+use std::io::Cursor;
+use std::str::FromStr;
+use url::Url;
+
+const STAFF: &str = r#"module lib_staff <https://example.org/staff#> is
+  import lib_corporate
+end
+"#;
+
+fn corporate_library() -> Module {
+    let name = Identifier::from_str("lib_corporate").unwrap();
+    let mut module = Module::new_library(name, ModuleBody::default())
+        .with_base_uri(Url::parse("https://example.org/corporate#").unwrap());
+    module
+        .body_mut()
+        .add_to_definitions(RdfDef::class(Identifier::from_str("Employee").unwrap()))
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_library_definitions_not_allowed_in_user_module() {
+    let name = Identifier::from_str("lib_corporate").unwrap();
+    let mut module = Module::empty(name);
+    assert!(!module.is_library_module());
+    assert!(module
+        .body_mut()
+        .add_to_definitions(RdfDef::class(Identifier::from_str("Employee").unwrap()))
+        .is_err());
+}
+
+#[test]
+fn test_register_library_module() {
+    let library = corporate_library();
+    let name = library.name().clone();
+    assert!(library.is_library_module());
+
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    assert!(!cache.is_library_module(&name));
+    cache.insert_library_module(library);
+    assert!(cache.is_library_module(&name));
+    assert!(cache.library_module_names().any(|n| n == &name));
+
+    cache.remove(&name);
+    assert!(!cache.is_library_module(&name));
+}
+
+#[test]
+fn test_replace_library_module_with_user_module() {
+    let name = Identifier::from_str("lib_corporate").unwrap();
+    let mut cache = InMemoryModuleCache::default().with_library_module(corporate_library());
+    assert!(cache.is_library_module(&name));
+
+    cache.insert(Module::empty(name.clone()));
+    assert!(!cache.is_library_module(&name));
+    assert!(!cache.library_module_names().any(|n| n == &name));
+}
+
+#[test]
+fn test_loader_skips_library_module() {
+    let library_name = Identifier::from_str("lib_corporate").unwrap();
+    let mut cache = InMemoryModuleCache::default()
+        .with_stdlib()
+        .with_library_module(corporate_library());
+
+    let mut loader = FsModuleLoader::default();
+    let loaded = loader
+        .load_from_reader(&mut Cursor::new(STAFF), &mut cache, true)
+        .unwrap();
+    let staff = cache.get(&loaded).unwrap();
+    assert!(!staff.is_library_module());
+
+    let library = cache.get(&library_name).unwrap();
+    assert!(library.is_library_module());
+    assert_eq!(library.body().definitions_len(), 1);
+
+    let loaded = loader.load(&library_name, None, &mut cache, true).unwrap();
+    assert_eq!(loaded, library_name);
+    assert!(!cache.get(&library_name).unwrap().is_incomplete(&cache));
+}
+
+const CORPORATE: &str = r#"module lib_corporate <https://example.org/corporate#> is
+  import rdfs
+
+  rdf Employee type rdfs:Class is
+    @rdfs:label = "Employee"
+  end
+end
+"#;
+
+#[test]
+fn test_parse_library_definitions_in_registered_module() {
+    let name = Identifier::from_str("lib_corporate").unwrap();
+    let mut cache = InMemoryModuleCache::default()
+        .with_stdlib()
+        .with_library_module(Module::empty(name.clone()));
+
+    let mut loader = FsModuleLoader::default();
+    let loaded = loader
+        .load_from_reader(&mut Cursor::new(CORPORATE), &mut cache, false)
+        .unwrap();
+    assert_eq!(loaded, name);
+    assert!(cache.get(&name).unwrap().is_library_module());
+
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    assert!(loader
+        .load_from_reader(&mut Cursor::new(CORPORATE), &mut cache, false)
+        .is_err());
+}
@@ -0,0 +1,108 @@
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::HasBody;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_parse::load::{FsModuleLoader, FsModuleResolver};
+use sdml_parse::package::{ModulePackage, PackageBuilder};
+use std::io::Cursor;
+use std::str::FromStr;
+use url::Url;
+
+const VEHICLES: &str = r#"module pkg_vehicles <https://example.org/vehicles#> is
+  entity Vehicle
+end
+"#;
+
+const RENTALS: &str = r#"module pkg_rentals <https://example.org/rentals#> is
+  import pkg_vehicles:Vehicle
+  entity Rental is
+    identity id -> string
+    vehicle -> pkg_vehicles:Vehicle
+  end
+end
+"#;
+
+fn build_package(file_name: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    let mut loader = FsModuleLoader::default();
+    loader
+        .load_from_reader(&mut Cursor::new(VEHICLES), &mut cache, false)
+        .unwrap();
+    loader
+        .load_from_reader(&mut Cursor::new(RENTALS), &mut cache, false)
+        .unwrap();
+
+    let mut builder = PackageBuilder::new(Url::parse("https://example.org/").unwrap());
+    builder
+        .add_module_closure(
+            &Identifier::from_str("pkg_rentals").unwrap(),
+            &cache,
+            &loader,
+        )
+        .unwrap();
+    assert_eq!(builder.file_names().count(), 2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(file_name);
+    builder.write_to_file(&path).unwrap();
+    (dir, path)
+}
+
+fn load_from_package(package_file: &std::path::Path) {
+    let package = ModulePackage::open(package_file).unwrap();
+    assert!(package.catalog().is_some());
+
+    let mut resolver = FsModuleResolver::default();
+    resolver.append_package(package);
+
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    let mut loader = FsModuleLoader::default().with_resolver(resolver);
+    let name = Identifier::from_str("pkg_rentals").unwrap();
+    let loaded = loader.load(&name, None, &mut cache, true).unwrap();
+    assert_eq!(loaded, name);
+
+    let rentals = cache.get(&name).unwrap();
+    assert_eq!(rentals.body().definitions_len(), 1);
+    assert_eq!(
+        rentals.base_uri().map(|uri| uri.value().to_string()),
+        Some("https://example.org/rentals#".to_string())
+    );
+    assert!(rentals
+        .source_file()
+        .map(|file| file.starts_with(package_file))
+        .unwrap_or_default());
+
+    let vehicles = Identifier::from_str("pkg_vehicles").unwrap();
+    assert!(cache.contains(&vehicles));
+}
+
+#[test]
+fn test_load_from_zip_package() {
+    let (_dir, path) = build_package("rentals.zip");
+    load_from_package(&path);
+}
+
+#[test]
+fn test_load_from_tar_package() {
+    let (_dir, path) = build_package("rentals.tar");
+    load_from_package(&path);
+}
+
+#[test]
+fn test_load_from_tar_gz_package() {
+    let (_dir, path) = build_package("rentals.tar.gz");
+    load_from_package(&path);
+}
+
+#[test]
+fn test_read_module_source_from_package() {
+    let (_dir, path) = build_package("rentals.zip");
+
+    let mut resolver = FsModuleResolver::default();
+    resolver.append_package_file(&path).unwrap();
+
+    let loader = FsModuleLoader::default().with_resolver(resolver);
+    let name = Identifier::from_str("pkg_vehicles").unwrap();
+    let source = loader.read_module_source(&name, None).unwrap();
+    assert_eq!(source, VEHICLES);
+}
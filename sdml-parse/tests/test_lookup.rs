@@ -0,0 +1,133 @@
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::lookup::{source_offset, ModelElement, PositionEncoding};
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
+use sdml_core::store::InMemoryModuleCache;
+use sdml_core::store::ModuleStore;
+use sdml_parse::load::FsModuleLoader;
+
+const SOURCE: &str = r#"module rentals <https://example.com/rentals#> is
+  import xsd
+  datatype vin <- xsd:string
+  entity Vehicle is
+    @label = "véhicule"
+    identity id -> vin
+    kind -> VehicleKind
+  end
+  enum VehicleKind of
+    Car
+    Van
+  end
+end
+"#;
+
+fn load() -> (Module, FsModuleLoader) {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut SOURCE.as_bytes(), &mut cache, false)
+        .unwrap();
+    (cache.get(&name).unwrap().clone(), loader)
+}
+
+fn offset_of(text: &str) -> usize {
+    SOURCE.find(text).unwrap()
+}
+
+fn describe(element: Option<ModelElement<'_>>) -> String {
+    match element {
+        Some(ModelElement::Module(v)) => format!("module {}", v.name()),
+        Some(ModelElement::ModuleImport(v)) => format!("import {}", v.name()),
+        Some(ModelElement::Definition(v)) => format!("definition {}", v.name()),
+        Some(ModelElement::Member(v)) => format!("member {}", v.name()),
+        Some(ModelElement::ValueVariant(v)) => format!("variant {}", v.name()),
+        Some(ModelElement::Annotation(_)) => "annotation".to_string(),
+        Some(ModelElement::Value(v)) => format!("value {v}"),
+        Some(ModelElement::IdentifierReference(v)) => format!("reference {v}"),
+        other => format!("{other:?}"),
+    }
+}
+
+#[test]
+fn test_element_at_offset() {
+    let (module, _) = load();
+
+    assert_eq!(describe(module.element_at(0)), "module rentals");
+    assert_eq!(
+        describe(module.element_at(offset_of("xsd\n"))),
+        "import xsd"
+    );
+    assert_eq!(
+        describe(module.element_at(offset_of("datatype"))),
+        "definition vin"
+    );
+    assert_eq!(
+        describe(module.element_at(offset_of("xsd:string"))),
+        "reference xsd:string"
+    );
+    assert_eq!(describe(module.element_at(offset_of("id ->"))), "member id");
+    assert_eq!(
+        describe(module.element_at(offset_of("VehicleKind\n"))),
+        "reference VehicleKind"
+    );
+    assert_eq!(
+        describe(module.element_at(offset_of("@label"))),
+        "annotation"
+    );
+    assert_eq!(
+        describe(module.element_at(offset_of("\"véhicule\""))),
+        "value \"véhicule\""
+    );
+    assert_eq!(describe(module.element_at(offset_of("Van"))), "variant Van");
+    assert!(module.element_at(SOURCE.len() + 10).is_none());
+}
+
+#[test]
+fn test_element_at_position() {
+    let (module, loader) = load();
+    assert!(module
+        .file_id()
+        .and_then(|id| loader.get_source(*id))
+        .is_some());
+
+    assert_eq!(
+        describe(module.element_at_position(&loader, 3, 19, PositionEncoding::Utf8)),
+        "reference xsd:string"
+    );
+    assert_eq!(
+        describe(module.element_at_position(&loader, 10, 5, PositionEncoding::Utf16)),
+        "variant Car"
+    );
+    assert!(module
+        .element_at_position(&loader, 100, 1, PositionEncoding::Utf8)
+        .is_none());
+}
+
+#[test]
+fn test_source_offset_encodings() {
+    let line = 5;
+    let start = offset_of("    @label");
+    // the column of the closing quote, after the two byte 'é'.
+    let quote = offset_of("\"\n    identity");
+    let utf8_column = quote - start + 1;
+    assert_eq!(
+        source_offset(SOURCE, line, utf8_column, PositionEncoding::Utf8),
+        Some(quote)
+    );
+    assert_eq!(
+        source_offset(SOURCE, line, utf8_column - 1, PositionEncoding::Utf16),
+        Some(quote)
+    );
+    // the second byte of 'é' is not a character boundary.
+    let within = offset_of("éhicule") - start + 2;
+    assert_eq!(
+        source_offset(SOURCE, line, within, PositionEncoding::Utf8),
+        None
+    );
+    assert_eq!(
+        source_offset(SOURCE, 1, 1, PositionEncoding::Utf16),
+        Some(0)
+    );
+    assert_eq!(source_offset(SOURCE, 1, 200, PositionEncoding::Utf8), None);
+    assert_eq!(source_offset(SOURCE, 0, 1, PositionEncoding::Utf8), None);
+}
@@ -0,0 +1,129 @@
+use sdml_core::load::ModuleLoader;
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::model::modules::Module;
+use sdml_core::model::HasName;
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_errors::diagnostics::reporter::ReportCounters;
+use sdml_errors::diagnostics::SeverityFilter;
+use sdml_errors::{Diagnostic, Error, Reporter, SourceFiles};
+use sdml_parse::load::{FsModuleLoader, FsModuleResolver};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+const IMPORTED: [&str; 6] = ["par_a", "par_b", "par_c", "par_d", "par_e", "par_f"];
+
+#[derive(Debug, Default)]
+struct RecordingReporter {
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl Reporter for RecordingReporter {
+    fn emit(&self, diagnostic: &Diagnostic, sources: &SourceFiles) -> Result<(), Error> {
+        let file_name = diagnostic
+            .labels
+            .first()
+            .and_then(|label| sources.get(label.file_id).ok())
+            .map(|file| file.name().clone())
+            .unwrap_or_default();
+        self.messages.lock().unwrap().push(format!(
+            "{}: {}",
+            file_name.rsplit('/').next().unwrap(),
+            diagnostic.message
+        ));
+        Ok(())
+    }
+
+    fn counters(&self) -> ReportCounters {
+        Default::default()
+    }
+
+    fn done(&self, _: Option<String>) -> Result<ReportCounters, Error> {
+        Ok(Default::default())
+    }
+
+    fn severity_filter(&self) -> SeverityFilter {
+        SeverityFilter::Help
+    }
+
+    fn set_severity_filter(&mut self, _: SeverityFilter) {}
+}
+
+fn write_modules(dir: &std::path::Path) {
+    let imports = IMPORTED.join(" ");
+    std::fs::write(
+        dir.join("par_root.sdm"),
+        format!("module par_root is\n  import [ {imports} ]\nend\n"),
+    )
+    .unwrap();
+    for name in IMPORTED {
+        std::fs::write(
+            dir.join(format!("{name}.sdm")),
+            format!(
+                r#"module {name} is
+  structure Thing is
+    value -> Missing
+  end
+  structure Thing
+end
+"#
+            ),
+        )
+        .unwrap();
+    }
+}
+
+fn load_and_validate(dir: &std::path::Path) -> Vec<String> {
+    let reporter = RecordingReporter::default();
+    let messages = reporter.messages.clone();
+
+    let mut resolver = FsModuleResolver::default();
+    resolver.prepend_to_search_path(dir);
+    let mut loader = FsModuleLoader::default()
+        .with_resolver(resolver)
+        .with_reporter(Box::new(reporter));
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+
+    let root = Identifier::from_str("par_root").unwrap();
+    loader.load(&root, None, &mut cache, true).unwrap();
+    for name in IMPORTED {
+        assert!(cache.contains(&Identifier::from_str(name).unwrap()));
+    }
+
+    let mut modules: Vec<&Module> = cache
+        .modules()
+        .filter(|module| !module.is_library_module())
+        .collect();
+    modules.sort_by_key(|module| module.name().to_string());
+    loader.validate_modules(&modules, &cache, false).unwrap();
+
+    let messages = messages.lock().unwrap().clone();
+    messages
+}
+
+#[test]
+fn test_parallel_diagnostics_are_deterministic() {
+    let dir = tempfile::tempdir().unwrap();
+    write_modules(dir.path());
+
+    let first = load_and_validate(dir.path());
+    assert!(!first.is_empty());
+
+    // parse diagnostics are reported in import order, followed by validation diagnostics
+    // in the order modules were provided.
+    let files = first
+        .iter()
+        .map(|message| message.split(':').next().unwrap().to_string())
+        .collect::<Vec<_>>();
+    let mut expected = IMPORTED
+        .iter()
+        .map(|name| format!("{name}.sdm"))
+        .collect::<Vec<_>>();
+    expected.extend(expected.clone());
+    let mut deduplicated = files.clone();
+    deduplicated.dedup();
+    assert_eq!(deduplicated, expected);
+
+    for _ in 0..5 {
+        assert_eq!(load_and_validate(dir.path()), first);
+    }
+}
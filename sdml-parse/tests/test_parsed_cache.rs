@@ -0,0 +1,66 @@
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_parse::cache::ParsedModuleCache;
+use sdml_parse::load::FsModuleLoader;
+use std::path::PathBuf;
+
+const MANIFEST_PATH: &str = env!("CARGO_MANIFEST_DIR");
+const TEST_PATH: &str = "tests/examples";
+
+fn load_with_cache(file: PathBuf, parsed_cache: &ParsedModuleCache) -> serde_json::Value {
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    let mut loader = FsModuleLoader::default().with_parsed_cache(parsed_cache.clone());
+    let name = loader.load_from_file(file, &mut cache, false).unwrap();
+    let module = cache.get(&name).unwrap();
+    assert!(module.file_id().is_some());
+    serde_json::to_value(module).unwrap()
+}
+
+#[test]
+fn test_cached_modules_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let parsed_cache = ParsedModuleCache::new(dir.path());
+
+    let mut files = std::fs::read_dir(PathBuf::from(MANIFEST_PATH).join(TEST_PATH))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map(|ext| ext == "sdm").unwrap_or_default())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert!(!files.is_empty());
+
+    for file in files {
+        let source = std::fs::read_to_string(&file).unwrap();
+        let key = parsed_cache.key_for(&source);
+
+        let parsed = load_with_cache(file.clone(), &parsed_cache);
+        assert!(parsed_cache.contains(&key), "no cache entry for {file:?}");
+
+        let cached = load_with_cache(file.clone(), &parsed_cache);
+        assert_eq!(parsed, cached, "cached module differs for {file:?}");
+    }
+}
+
+#[test]
+fn test_cache_key_depends_on_source() {
+    let parsed_cache = ParsedModuleCache::new("unused");
+    let key = parsed_cache.key_for("module foo is end");
+    assert_eq!(key, parsed_cache.key_for("module foo is end"));
+    assert_ne!(key, parsed_cache.key_for("module foo is end\n"));
+}
+
+#[test]
+fn test_clear_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let parsed_cache = ParsedModuleCache::new(dir.path().join("cache"));
+    assert_eq!(parsed_cache.clear().unwrap(), 0);
+
+    let file = PathBuf::from(MANIFEST_PATH)
+        .join(TEST_PATH)
+        .join("module_empty.sdm");
+    let source = std::fs::read_to_string(&file).unwrap();
+    let _ = load_with_cache(file, &parsed_cache);
+    assert!(parsed_cache.contains(&parsed_cache.key_for(&source)));
+
+    assert_eq!(parsed_cache.clear().unwrap(), 1);
+    assert!(!parsed_cache.contains(&parsed_cache.key_for(&source)));
+}
@@ -0,0 +1,150 @@
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::store::{InMemoryModuleCache, ModuleStore, ReferenceIndex, ReferenceKind};
+use sdml_parse::load::FsModuleLoader;
+use std::str::FromStr;
+
+const REFERENCED: &str = r#"module idx_base is
+  import [ xsd skos ]
+  @skos:prefLabel = "Base"@en
+  structure Address is
+    street -> xsd:string
+  end
+  datatype Code <- xsd:string
+end
+"#;
+
+const REFERENCING: &str = r#"module idx_user is
+  import idx_base:Address
+  import [ idx_base skos ]
+  structure Customer is
+    @skos:prefLabel = "Customer"@en
+    home -> Address
+    work -> idx_base:Address
+  end
+  union Location of
+    idx_base:Address
+    idx_base:Code
+  end
+end
+"#;
+
+fn load(cache: &mut InMemoryModuleCache, source: &str) -> Identifier {
+    let mut loader = FsModuleLoader::default();
+    loader
+        .load_from_reader(&mut source.as_bytes(), cache, false)
+        .unwrap()
+}
+
+fn qualified(s: &str) -> QualifiedIdentifier {
+    QualifiedIdentifier::from_str(s).unwrap()
+}
+
+#[test]
+fn test_references_to_definition() {
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    load(&mut cache, REFERENCED);
+    load(&mut cache, REFERENCING);
+
+    let sites = cache
+        .references_to(&qualified("idx_base:Address"))
+        .map(|site| (site.kind(), site.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sites,
+        vec![
+            (ReferenceKind::Import, "idx_user".to_string()),
+            (ReferenceKind::Member, "idx_user:Customer.home".to_string()),
+            (ReferenceKind::Member, "idx_user:Customer.work".to_string()),
+            (
+                ReferenceKind::Variant,
+                "idx_user:Location.Address".to_string()
+            ),
+        ]
+    );
+    assert!(cache
+        .references_to(&qualified("idx_base:Address"))
+        .all(|site| site.source_span().is_some()));
+
+    let kinds = cache
+        .references_to(&qualified("xsd:string"))
+        .filter(|site| site.module().as_ref() == "idx_base")
+        .map(|site| site.kind())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![ReferenceKind::Member, ReferenceKind::Definition]
+    );
+
+    let annotated = cache
+        .references_to(&qualified("skos:prefLabel"))
+        .filter(|site| site.module().as_ref().starts_with("idx_"))
+        .map(|site| site.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(annotated, vec!["idx_base", "idx_user:Customer"]);
+}
+
+#[test]
+fn test_index_updates_on_remove() {
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    load(&mut cache, REFERENCED);
+    let user = load(&mut cache, REFERENCING);
+    let code = qualified("idx_base:Code");
+
+    assert_eq!(cache.references_to(&code).count(), 1);
+    assert!(cache.remove(&user));
+    assert_eq!(cache.references_to(&code).count(), 0);
+    assert!(!cache.reference_index().is_referenced(&code));
+
+    load(&mut cache, REFERENCING);
+    assert_eq!(cache.references_to(&code).count(), 1);
+
+    let rebuilt = ReferenceIndex::from_store(&cache);
+    assert_eq!(rebuilt.len(), cache.reference_index().len());
+}
+
+const REPEATED: &str = r#"module idx_repeat is
+  import [ dc xsd ]
+  datatype Code <- xsd:string
+  structure Lookup is
+    @dc:identifier = Code("abc")
+    codes -> {0..1} (Code -> Code)
+  end
+end
+"#;
+
+#[test]
+fn test_repeated_and_constructor_references() {
+    let mut cache = InMemoryModuleCache::default().with_stdlib();
+    load(&mut cache, REPEATED);
+
+    let sites = cache
+        .references_to(&qualified("idx_repeat:Code"))
+        .map(|site| {
+            (
+                site.kind(),
+                site.to_string(),
+                site.source_span().map(|span| span.start().byte()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sites,
+        vec![
+            (
+                ReferenceKind::Annotation,
+                "idx_repeat:Lookup".to_string(),
+                Some(114)
+            ),
+            (
+                ReferenceKind::Member,
+                "idx_repeat:Lookup.codes".to_string(),
+                Some(147)
+            ),
+            (
+                ReferenceKind::Member,
+                "idx_repeat:Lookup.codes".to_string(),
+                Some(155)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,294 @@
+use sdml_core::model::identifiers::Identifier;
+use sdml_core::refactor::relocate::move_definitions;
+use sdml_errors::diagnostics::ErrorCode;
+use sdml_errors::Error;
+use sdml_parse::Workspace;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const SOURCE: &str = r#"module mv_source is
+
+  import xsd
+
+  structure Customer is
+    home -> Address
+    code -> Code
+  end
+
+  ; Where a customer lives.
+  structure Address is
+    street -> string
+    code -> Code
+  end
+
+  datatype Code <- xsd:string
+
+end
+"#;
+
+const TARGET: &str = r#"module mv_target is
+
+  import mv_source:Address
+
+  structure Site is
+    address -> Address
+  end
+
+end
+"#;
+
+const USER: &str = r#"module mv_user is
+
+  import mv_source
+
+  structure Order is
+    deliver_to -> mv_source:Address
+  end
+
+end
+"#;
+
+fn workspace(root: &Path) -> Workspace {
+    fs::write(root.join("mv_source.sdm"), SOURCE).unwrap();
+    fs::write(root.join("mv_target.sdm"), TARGET).unwrap();
+    fs::write(root.join("mv_user.sdm"), USER).unwrap();
+    let mut workspace = Workspace::default().with_root(root);
+    workspace.load().unwrap();
+    workspace
+}
+
+fn id(s: &str) -> Identifier {
+    Identifier::from_str(s).unwrap()
+}
+
+fn reload(root: &Path) -> Workspace {
+    let workspace = workspace_only(root);
+    assert_eq!(workspace.failed_files().count(), 0);
+    workspace
+}
+
+fn workspace_only(root: &Path) -> Workspace {
+    let mut workspace = Workspace::default().with_root(root);
+    workspace.load().unwrap();
+    workspace
+}
+
+#[test]
+fn test_move_to_existing_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path());
+
+    let edits = move_definitions(
+        workspace.store(),
+        &id("mv_source"),
+        &[id("Address")],
+        &id("mv_target"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(edits.apply().unwrap(), 3);
+
+    let source = fs::read_to_string(dir.path().join("mv_source.sdm")).unwrap();
+    assert!(!source.contains("structure Address"));
+    assert!(!source.contains("Where a customer lives"));
+    assert!(source.contains("home -> mv_target:Address"));
+    assert!(source.contains("import mv_target"));
+
+    let target = fs::read_to_string(dir.path().join("mv_target.sdm")).unwrap();
+    assert!(!target.contains("import mv_source:Address"));
+    assert!(target.contains("  ; Where a customer lives.\n  structure Address is\n"));
+    assert!(target.contains("code -> mv_source:Code"));
+    assert!(target.contains("import mv_source\n"));
+
+    let user = fs::read_to_string(dir.path().join("mv_user.sdm")).unwrap();
+    assert!(user.contains("deliver_to -> mv_target:Address"));
+    assert!(user.contains("import mv_target"));
+
+    let workspace = reload(dir.path());
+    assert!(workspace
+        .store()
+        .reference_index()
+        .is_referenced(&"mv_target:Address".parse().unwrap()));
+}
+
+#[test]
+fn test_move_to_new_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path());
+    let new_file = dir.path().join("mv_codes.sdm");
+
+    let edits = move_definitions(
+        workspace.store(),
+        &id("mv_source"),
+        &[id("Code")],
+        &id("mv_codes"),
+        Some(&new_file),
+    )
+    .unwrap();
+    assert_eq!(edits.new_files().count(), 1);
+    edits.apply().unwrap();
+
+    let codes = fs::read_to_string(&new_file).unwrap();
+    assert_eq!(
+        codes,
+        "module mv_codes is\n\n  import xsd\n\n  datatype Code <- xsd:string\n\nend\n"
+    );
+    let source = fs::read_to_string(dir.path().join("mv_source.sdm")).unwrap();
+    assert!(source.contains("code -> mv_codes:Code"));
+    assert!(!source.contains("datatype Code"));
+
+    reload(dir.path());
+}
+
+#[test]
+fn test_move_to_missing_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path());
+
+    let result = move_definitions(
+        workspace.store(),
+        &id("mv_source"),
+        &[id("Code")],
+        &id("mv_missing"),
+        None,
+    );
+    match result {
+        Err(Error::LanguageValidationError { source }) => {
+            assert_eq!(source.code.unwrap(), ErrorCode::ModuleNotFound.to_string())
+        }
+        other => panic!("expected a diagnostic, not {other:?}"),
+    }
+}
+
+#[test]
+fn test_move_collision() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path());
+
+    let result = move_definitions(
+        workspace.store(),
+        &id("mv_target"),
+        &[id("Site")],
+        &id("mv_user"),
+        None,
+    );
+    assert!(result.is_ok());
+
+    fs::write(
+        dir.path().join("mv_other.sdm"),
+        "module mv_other is\n  structure Customer\nend\n",
+    )
+    .unwrap();
+    let workspace = workspace_only(dir.path());
+    let result = move_definitions(
+        workspace.store(),
+        &id("mv_source"),
+        &[id("Customer")],
+        &id("mv_other"),
+        None,
+    );
+    match result {
+        Err(Error::LanguageValidationError { source }) => assert_eq!(
+            source.code.unwrap(),
+            ErrorCode::DuplicateDefinitionName.to_string()
+        ),
+        other => panic!("expected a diagnostic, not {other:?}"),
+    }
+}
+
+#[test]
+fn test_move_after_source_changed() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path());
+    fs::write(
+        dir.path().join("mv_target.sdm"),
+        "module mv_target is end\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("mv_user.sdm"), "").unwrap();
+
+    let result = move_definitions(
+        workspace.store(),
+        &id("mv_source"),
+        &[id("Address")],
+        &id("mv_target"),
+        None,
+    );
+    assert!(result.is_err());
+}
+
+const LOOKUP: &str = r#"module mv_lookup is
+
+  import [ dc xsd ]
+
+  structure Lookup is
+    @dc:identifier = Key("abc")
+    keys -> {0..1} (Key -> Key)
+  end
+
+  datatype Key <- xsd:string
+
+end
+"#;
+
+#[test]
+fn test_move_mapping_and_constructor_references() {
+    let dir = tempfile::tempdir().unwrap();
+    workspace(dir.path());
+    fs::write(dir.path().join("mv_lookup.sdm"), LOOKUP).unwrap();
+    let workspace = reload(dir.path());
+
+    let edits = move_definitions(
+        workspace.store(),
+        &id("mv_lookup"),
+        &[id("Key")],
+        &id("mv_target"),
+        None,
+    )
+    .unwrap();
+    edits.apply().unwrap();
+
+    let lookup = fs::read_to_string(dir.path().join("mv_lookup.sdm")).unwrap();
+    assert!(lookup.contains("@dc:identifier = mv_target:Key(\"abc\")"));
+    assert!(lookup.contains("keys -> {0..1} (mv_target:Key -> mv_target:Key)"));
+
+    let workspace = reload(dir.path());
+    let edits = move_definitions(
+        workspace.store(),
+        &id("mv_lookup"),
+        &[id("Lookup")],
+        &id("mv_target"),
+        None,
+    )
+    .unwrap();
+    edits.apply().unwrap();
+
+    let target = fs::read_to_string(dir.path().join("mv_target.sdm")).unwrap();
+    assert!(target.contains("@dc:identifier = Key(\"abc\")"));
+    assert!(target.contains("keys -> {0..1} (Key -> Key)"));
+    reload(dir.path());
+}
+
+#[test]
+fn test_move_away_from_mapping_and_constructor_references() {
+    let dir = tempfile::tempdir().unwrap();
+    workspace(dir.path());
+    fs::write(dir.path().join("mv_lookup.sdm"), LOOKUP).unwrap();
+    let workspace = reload(dir.path());
+
+    let edits = move_definitions(
+        workspace.store(),
+        &id("mv_lookup"),
+        &[id("Lookup")],
+        &id("mv_target"),
+        None,
+    )
+    .unwrap();
+    edits.apply().unwrap();
+
+    let target = fs::read_to_string(dir.path().join("mv_target.sdm")).unwrap();
+    assert!(target.contains("@dc:identifier = mv_lookup:Key(\"abc\")"));
+    assert!(target.contains("keys -> {0..1} (mv_lookup:Key -> mv_lookup:Key)"));
+    reload(dir.path());
+}
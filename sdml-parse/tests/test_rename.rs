@@ -0,0 +1,169 @@
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::refactor::rename::rename_definition;
+use sdml_core::refactor::{apply_edits, TextEdit};
+use sdml_errors::diagnostics::ErrorCode;
+use sdml_errors::Error;
+use sdml_parse::Workspace;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const DEFINING: &str = r#"module ren_base is
+  structure Address is
+    street -> string
+  end
+  datatype Code <- string
+end
+"#;
+
+const IMPORTING: &str = r#"module ren_user is
+  import ren_base:Address
+  import ren_base
+  structure Customer is
+    home -> Address
+    work -> ren_base:Address
+  end
+end
+"#;
+
+fn workspace(root: &Path, extra: Option<&str>) -> Workspace {
+    fs::write(root.join("ren_base.sdm"), DEFINING).unwrap();
+    fs::write(root.join("ren_user.sdm"), IMPORTING).unwrap();
+    if let Some(extra) = extra {
+        fs::write(root.join("ren_extra.sdm"), extra).unwrap();
+    }
+    let mut workspace = Workspace::default().with_root(root);
+    workspace.load().unwrap();
+    workspace
+}
+
+fn error_code(result: Result<impl std::fmt::Debug, Error>) -> String {
+    match result {
+        Err(Error::LanguageValidationError { source }) => source.code.unwrap(),
+        other => panic!("expected a diagnostic, not {other:?}"),
+    }
+}
+
+#[test]
+fn test_rename_across_modules() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path(), None);
+
+    let edits = rename_definition(
+        workspace.store(),
+        &QualifiedIdentifier::from_str("ren_base:Address").unwrap(),
+        &Identifier::from_str("Location").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(edits.files().count(), 2);
+    assert_eq!(edits.len(), 4);
+    assert_eq!(edits.apply().unwrap(), 2);
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("ren_base.sdm")).unwrap(),
+        DEFINING.replace("Address", "Location")
+    );
+    assert_eq!(
+        fs::read_to_string(dir.path().join("ren_user.sdm")).unwrap(),
+        IMPORTING.replace("Address", "Location")
+    );
+}
+
+#[test]
+fn test_rename_to_same_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(dir.path(), None);
+
+    let edits = rename_definition(
+        workspace.store(),
+        &QualifiedIdentifier::from_str("ren_base:Address").unwrap(),
+        &Identifier::from_str("Address").unwrap(),
+    )
+    .unwrap();
+    assert!(edits.is_empty());
+}
+
+#[test]
+fn test_rename_collisions() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = workspace(
+        dir.path(),
+        Some("module ren_extra is\n  import ren_base:Address\n  structure Place\nend\n"),
+    );
+    let name = QualifiedIdentifier::from_str("ren_base:Address").unwrap();
+
+    assert_eq!(
+        error_code(rename_definition(
+            workspace.store(),
+            &name,
+            &Identifier::from_str("Code").unwrap()
+        )),
+        ErrorCode::DuplicateDefinitionName.to_string()
+    );
+    assert_eq!(
+        error_code(rename_definition(
+            workspace.store(),
+            &name,
+            &Identifier::from_str("Place").unwrap()
+        )),
+        ErrorCode::DuplicateDefinitionName.to_string()
+    );
+    assert_eq!(
+        error_code(rename_definition(
+            workspace.store(),
+            &QualifiedIdentifier::from_str("ren_base:Missing").unwrap(),
+            &Identifier::from_str("Place").unwrap()
+        )),
+        ErrorCode::DefinitionNotFound.to_string()
+    );
+}
+
+#[test]
+fn test_apply_edits() {
+    let edits = vec![
+        TextEdit::replace(6..11, "there"),
+        TextEdit::insert(0, ">> "),
+        TextEdit::delete(11..12),
+    ];
+    assert_eq!(
+        apply_edits("hello world!", &edits).unwrap(),
+        ">> hello there"
+    );
+    assert!(apply_edits(
+        "hello",
+        &[TextEdit::replace(2..4, ""), TextEdit::replace(3..5, "")]
+    )
+    .is_err());
+}
+
+#[test]
+fn test_rename_mapping_and_constructor_references() {
+    let dir = tempfile::tempdir().unwrap();
+    let extra = r#"module ren_extra is
+  import [ dc ren_base ]
+  structure Lookup is
+    @dc:identifier = ren_base:Code("abc")
+    codes -> {0..1} (ren_base:Code -> ren_base:Code)
+  end
+end
+"#;
+    let workspace = workspace(dir.path(), Some(extra));
+
+    let edits = rename_definition(
+        workspace.store(),
+        &QualifiedIdentifier::from_str("ren_base:Code").unwrap(),
+        &Identifier::from_str("Key").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(edits.len(), 4);
+    assert_eq!(edits.apply().unwrap(), 2);
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("ren_base.sdm")).unwrap(),
+        DEFINING.replace("Code", "Key")
+    );
+    assert_eq!(
+        fs::read_to_string(dir.path().join("ren_extra.sdm")).unwrap(),
+        extra.replace("Code", "Key")
+    );
+}
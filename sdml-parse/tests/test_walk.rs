@@ -0,0 +1,135 @@
+use sdml_core::error::Error;
+use sdml_core::model::annotations::Annotation;
+use sdml_core::model::constraints::{FormalConstraint, Term};
+use sdml_core::model::identifiers::{Identifier, IdentifierReference};
+use sdml_core::model::modules::Module;
+use sdml_core::model::values::SimpleValue;
+use sdml_core::model::walk::visit::{walk_module, walk_term, Visitor};
+use sdml_core::model::walk::visit_mut::{walk_module_mut, VisitorMut};
+use sdml_core::store::{InMemoryModuleCache, ModuleStore};
+use sdml_parse::load::FsModuleLoader;
+use std::str::FromStr;
+
+const SOURCE: &str = r#"module walk_example is
+  import [ skos xsd ]
+  structure Target
+  entity Campaign is
+    @skos:prefLabel = [ "campaign"@en "campagne"@fr ]
+    identity id -> xsd:string
+    target -> Target is
+      assert tagged_with_foo is
+        ∀ self, self.name = "foo"
+      end
+    end
+    kind -> {0..1} Kind
+  end
+  enum Kind of
+    Mail
+    Phone is
+      @skos:prefLabel = "phone"
+    end
+  end
+end
+"#;
+
+fn load() -> Module {
+    let mut cache = InMemoryModuleCache::default();
+    let mut loader = FsModuleLoader::default();
+    let name = loader
+        .load_from_reader(&mut SOURCE.as_bytes(), &mut cache, false)
+        .unwrap();
+    cache.get(&name).unwrap().clone()
+}
+
+#[derive(Debug, Default)]
+struct Counter {
+    references: Vec<String>,
+    simple_values: usize,
+    terms: usize,
+    in_formal: bool,
+    formal_terms: usize,
+}
+
+impl Visitor<'_> for Counter {
+    fn visit_identifier_reference(&mut self, node: &IdentifierReference) -> Result<(), Error> {
+        self.references.push(node.to_string());
+        Ok(())
+    }
+
+    fn visit_simple_value(&mut self, _node: &SimpleValue) -> Result<(), Error> {
+        self.simple_values += 1;
+        Ok(())
+    }
+
+    fn visit_formal_constraint(&mut self, node: &FormalConstraint) -> Result<(), Error> {
+        self.in_formal = true;
+        sdml_core::model::walk::visit::walk_formal_constraint(self, node)?;
+        self.in_formal = false;
+        Ok(())
+    }
+
+    fn visit_term(&mut self, node: &Term) -> Result<(), Error> {
+        self.terms += 1;
+        if self.in_formal {
+            self.formal_terms += 1;
+        }
+        walk_term(self, node)
+    }
+}
+
+#[test]
+fn test_visitor_reaches_every_node() {
+    let module = load();
+    let mut counter = Counter::default();
+    walk_module(&mut counter, &module).unwrap();
+
+    assert_eq!(
+        counter.references,
+        vec![
+            "xsd:string",
+            "skos:prefLabel",
+            "Target",
+            "Kind",
+            "skos:prefLabel",
+        ]
+    );
+    // two list members, one string in the variant annotation, and one in the constraint.
+    assert_eq!(counter.simple_values, 4);
+    assert_eq!(counter.terms, 2);
+    assert_eq!(counter.formal_terms, 2);
+}
+
+struct Renamer;
+
+impl VisitorMut for Renamer {
+    fn visit_identifier_reference_mut(
+        &mut self,
+        node: &mut IdentifierReference,
+    ) -> Result<(), Error> {
+        if matches!(node, IdentifierReference::Identifier(name) if name.as_ref() == "Kind") {
+            *node = Identifier::from_str("ContactKind").unwrap().into();
+        }
+        Ok(())
+    }
+
+    fn visit_annotation_mut(&mut self, node: &mut Annotation) -> Result<(), Error> {
+        if let Annotation::Property(property) = node {
+            property.set_value(SimpleValue::from(true).into());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_visitor_mut_rewrites_in_place() {
+    let mut module = load();
+    walk_module_mut(&mut Renamer, &mut module).unwrap();
+
+    let mut counter = Counter::default();
+    walk_module(&mut counter, &module).unwrap();
+    assert!(counter.references.contains(&"ContactKind".to_string()));
+    assert!(!counter.references.contains(&"Kind".to_string()));
+    // annotation values were replaced, the constraint is unchanged.
+    assert_eq!(counter.simple_values, 3);
+    assert!(counter.references.contains(&"skos:prefLabel".to_string()));
+}
@@ -0,0 +1,90 @@
+use sdml_core::model::identifiers::{Identifier, QualifiedIdentifier};
+use sdml_core::model::HasName;
+use sdml_parse::Workspace;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+fn write_module(path: &Path, source: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, source).unwrap();
+}
+
+fn id(s: &str) -> Identifier {
+    Identifier::from_str(s).unwrap()
+}
+
+#[test]
+fn test_workspace_loads_all_members() {
+    let dir = tempfile::tempdir().unwrap();
+    write_module(
+        &dir.path().join("ws_one.sdm"),
+        "module ws_one is\n  import ws_two\n  structure Thing is\n    value -> ws_two:Other\n  end\nend\n",
+    );
+    write_module(
+        &dir.path().join("nested/ws_two.sdm"),
+        "module ws_two is\n  structure Other\nend\n",
+    );
+    write_module(&dir.path().join("notes.txt"), "not a module");
+
+    let mut workspace = Workspace::default().with_root(dir.path());
+    assert_eq!(workspace.discover().unwrap().len(), 2);
+    assert_eq!(workspace.load().unwrap(), 2);
+    assert_eq!(workspace.failed_files().count(), 0);
+
+    let names = workspace
+        .modules()
+        .map(|module| module.name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["ws_one", "ws_two"]);
+
+    assert!(workspace.is_member(&id("ws_two")));
+    assert!(!workspace.is_member(&id("xsd")));
+    assert_eq!(workspace.definitions().count(), 2);
+    assert!(workspace
+        .resolve(&QualifiedIdentifier::new(id("ws_two"), id("Other")))
+        .is_some());
+
+    let ws_two = id("ws_two");
+    let importers = workspace
+        .importers_of(&ws_two)
+        .map(|module| module.name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(importers, vec!["ws_one"]);
+
+    assert!(workspace.validate(false).is_ok());
+
+    // loading again does not duplicate members
+    assert_eq!(workspace.load().unwrap(), 2);
+}
+
+#[test]
+fn test_workspace_records_failed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    write_module(
+        &dir.path().join("ws_good.sdm"),
+        "module ws_good is\n  structure Good\nend\n",
+    );
+    write_module(&dir.path().join("ws_bad.sdm"), "structure ws_bad\n");
+
+    let mut workspace = Workspace::default().with_root(dir.path());
+    assert_eq!(workspace.load().unwrap(), 1);
+    assert_eq!(
+        workspace.failed_files().collect::<Vec<_>>(),
+        vec![&dir.path().join("ws_bad.sdm")]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_workspace_ignores_symlink_cycles() {
+    let dir = tempfile::tempdir().unwrap();
+    write_module(
+        &dir.path().join("nested/ws_cycle.sdm"),
+        "module ws_cycle is\nend\n",
+    );
+    std::os::unix::fs::symlink(dir.path(), dir.path().join("nested/loop")).unwrap();
+
+    let workspace = Workspace::default().with_root(dir.path());
+    assert_eq!(workspace.discover().unwrap().len(), 1);
+}
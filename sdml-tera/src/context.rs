@@ -51,7 +51,6 @@ use sdml_core::model::values::{
     ValueConstructor,
 };
 use sdml_core::model::{HasBody, HasName, HasNameReference, HasOptionalBody, HasSourceSpan};
-use sdml_core::stdlib::is_library_module;
 use sdml_core::store::ModuleStore;
 use tera::{Map, Value};
 
@@ -108,7 +107,7 @@ pub fn module_to_value(module: &Module, _cache: &impl ModuleStore) -> (String, V
     value.insert(KEY_NAME.into(), module.name().to_string().into());
     value.insert(
         KEY_IS_LIBRARY_MODULE.into(),
-        is_library_module(module.name()).into(),
+        module.is_library_module().into(),
     );
 
     add_source_span(module, &mut value);